@@ -52,6 +52,7 @@ run_test "API Invariants Test" "uv run test/test_api_invariants.py"
 printf "\n=== Serialization Tests ===\n"
 run_test "InputBuilder Test" "uv run test/test_input_builder.py"
 run_test "CBOR Serialization Test" "uv run test/test_cbor_serialization.py"
+run_test "Codec Test" "uv run test/test_codec.py"
 
 # Composition tests
 printf "\n=== Composition Tests ===\n"
@@ -0,0 +1,86 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyIOError;
+use risc0_zkvm::sha::Sha256;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use crate::receipt::{rfc3339_utc_now, Receipt};
+
+/// Build the audit record for one receipt
+fn audit_record(receipt: &Receipt, image_id: Option<&Bound<'_, PyAny>>) -> PyResult<serde_json::Value> {
+    let exit = receipt.exit()?;
+    let journal_digest = risc0_zkvm::sha::Impl::hash_bytes(&receipt.inner.journal.bytes);
+
+    let (verified, verification_error) = match image_id {
+        Some(id) => match receipt.verify(id, None, None) {
+            Ok(()) => (Some(true), None),
+            Err(e) => (Some(false), Some(e.to_string())),
+        },
+        None => (None, None),
+    };
+
+    Ok(serde_json::json!({
+        "timestamp": rfc3339_utc_now(),
+        "claim_digest": hex::encode(receipt.claim_digest()?.as_bytes()),
+        "claimed_image_id": receipt.claimed_image_id_hex()?,
+        "kind": receipt.kind()?.as_str(),
+        "exit_kind": exit.kind.as_str(),
+        "exit_code": exit.user_code,
+        "journal_digest": hex::encode(journal_digest.as_bytes()),
+        "journal_len": receipt.inner.journal.bytes.len(),
+        "seal_size": receipt.inner.seal_size(),
+        "verified": verified,
+        "verification_error": verification_error,
+    }))
+}
+
+/// Write an NDJSON audit trail with one record per receipt
+///
+/// Records are streamed to disk as the iterable is consumed, so large
+/// batches never have to be held in memory at once. Each line contains the
+/// claim digest, claimed image ID, receipt kind, exit status, journal digest,
+/// verification result and a UTC timestamp.
+///
+/// Args:
+///     receipts: Iterable of Receipt objects
+///     path: Output file path
+///     image_id: Trusted image ID to verify each receipt against. If None,
+///               receipts are not verified and "verified" is null.
+///     append: If True, append to an existing log instead of truncating
+///
+/// Returns:
+///     int: Number of records written
+///
+/// Raises:
+///     IOError: If the file can't be written
+#[pyfunction]
+#[pyo3(signature = (receipts, path, image_id=None, append=false))]
+pub fn export_audit_log(
+    receipts: &Bound<'_, PyAny>,
+    path: PathBuf,
+    image_id: Option<&Bound<'_, PyAny>>,
+    append: bool,
+) -> PyResult<usize> {
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(&path)
+        .map_err(|e| PyErr::new::<PyIOError, _>(format!("Failed to open {}: {}", path.display(), e)))?;
+    let mut writer = BufWriter::new(file);
+    let io_err = |e: std::io::Error| PyErr::new::<PyIOError, _>(format!("Failed to write audit log: {}", e));
+
+    let mut count = 0;
+    for item in receipts.try_iter()? {
+        let receipt: PyRef<Receipt> = item?.extract()?;
+        let record = audit_record(&receipt, image_id)?;
+        serde_json::to_writer(&mut writer, &record).map_err(std::io::Error::from).map_err(io_err)?;
+        writer.write_all(b"\n").map_err(io_err)?;
+        count += 1;
+    }
+    writer.flush().map_err(io_err)?;
+
+    Ok(count)
+}
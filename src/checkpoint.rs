@@ -0,0 +1,51 @@
+use pyo3::prelude::*;
+use std::io::Write;
+
+/// Guest file descriptor that progress checkpoints are written to
+///
+/// Each checkpoint is a 12-byte record: a u32 marker chosen by the guest,
+/// then env::cycle_count() as a u64, both little-endian. In the guest
+/// (with risc0_zkvm::guest::env::Write in scope):
+///
+///     let mut record = [0u8; 12];
+///     record[..4].copy_from_slice(&marker.to_le_bytes());
+///     record[4..].copy_from_slice(&env::cycle_count().to_le_bytes());
+///     env::FdWriter::new(CHECKPOINT_FD, |_| {}).write_slice(&record);
+pub const CHECKPOINT_FD: u32 = 100;
+
+const RECORD_LEN: usize = 12;
+
+/// std::io::Write for CHECKPOINT_FD that calls on_checkpoint(marker, cycles)
+///
+/// Records may arrive split across writes, so partial records are buffered.
+/// Each callback briefly re-acquires the GIL, so execution can still run
+/// with the GIL released. An exception in the callback aborts execution.
+pub(crate) struct CheckpointWriter {
+    callback: Py<PyAny>,
+    pending: Vec<u8>,
+}
+
+impl CheckpointWriter {
+    pub(crate) fn new(callback: Py<PyAny>) -> Self {
+        Self { callback, pending: Vec::with_capacity(RECORD_LEN) }
+    }
+}
+
+impl Write for CheckpointWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        let complete = self.pending.len() / RECORD_LEN * RECORD_LEN;
+        for record in self.pending[..complete].chunks_exact(RECORD_LEN) {
+            let marker = u32::from_le_bytes(record[..4].try_into().unwrap());
+            let cycles = u64::from_le_bytes(record[4..].try_into().unwrap());
+            Python::with_gil(|py| self.callback.call1(py, (marker, cycles)).map(drop))
+                .map_err(|e| std::io::Error::other(format!("on_checkpoint callback failed: {e}")))?;
+        }
+        self.pending.drain(..complete);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 use pyo3::prelude::*;
-use risc0_zkvm::sha::Digestible;
+use risc0_zkvm::sha::{Digest, Digestible};
+use risc0_zkvm::{ExitCode, MaybePruned, Output, ReceiptClaim, SystemState};
+
+use crate::receipt::{digest_from_bytes, ExitKind, ExitStatus};
 
 /// A claim represents what a receipt proves: an image ID executed with a specific journal
 /// 
@@ -24,6 +27,27 @@ pub struct Claim {
     /// The exit code of the program execution
     #[pyo3(get)]
     pub exit_code: u32,
+    
+    /// Digest of the SystemState before execution (32 bytes)
+    /// For a fresh execution this is the image ID
+    #[pyo3(get)]
+    pub pre_state_digest: Vec<u8>,
+    
+    /// Digest of the SystemState after execution (32 bytes)
+    /// None when the claim was constructed by hand and the post-state is unknown.
+    /// For paused sessions this is the state a continuation must resume from.
+    #[pyo3(get)]
+    pub post_state_digest: Option<Vec<u8>>,
+
+    /// Exit code as risc0 records it (exit_code flattens paused and system exits)
+    exit: ExitCode,
+
+    /// Digest of the claim's input (zero when there is none)
+    input_digest: Digest,
+
+    /// Digest of the claim's output, when it isn't just this journal with no
+    /// assumptions (conditional receipts, exits without output)
+    output_digest: Option<Digest>,
 }
 
 #[pymethods]
@@ -42,13 +66,141 @@ impl Claim {
         let journal_digest = risc0_zkvm::sha::Impl::hash_bytes(&journal);
         
         Ok(Claim {
+            pre_state_digest: image_id.clone(),
             image_id,
             journal: journal.clone(),
             journal_digest: journal_digest.as_bytes().to_vec(),
             exit_code,
+            post_state_digest: None,
+            exit: ExitCode::Halted(exit_code),
+            input_digest: Digest::ZERO,
+            output_digest: None,
         })
     }
     
+    /// Claim for a run that halted successfully (exit code 0)
+    /// 
+    /// This is what a guest's env::verify(image_id, journal) checks for.
+    /// 
+    /// Args:
+    ///     image_id: 32-byte image ID of the program
+    ///     journal: Expected journal bytes
+    #[staticmethod]
+    pub fn for_success(image_id: Vec<u8>, journal: Vec<u8>) -> PyResult<Self> {
+        Self::new(image_id, journal, 0)
+    }
+    
+    /// Claim for a run that halted with a specific exit code
+    /// 
+    /// Use this to describe claims checked with env::verify_integrity(), where
+    /// the guest accepts a non-zero exit.
+    /// 
+    /// Args:
+    ///     image_id: 32-byte image ID of the program
+    ///     journal: Expected journal bytes
+    ///     code: Expected user exit code
+    #[staticmethod]
+    pub fn for_exit(image_id: Vec<u8>, journal: Vec<u8>, code: u32) -> PyResult<Self> {
+        Self::new(image_id, journal, code)
+    }
+    
+    /// Claim assembled from every part of a risc0 ReceiptClaim
+    /// 
+    /// For recursion workflows that need the exact claim risc0 uses, e.g.
+    /// a paused segment's claim or one whose output carries assumptions.
+    /// Parts left as None take the values Claim.for_exit() would use.
+    /// 
+    /// Args:
+    ///     pre_state_digest: Digest of the starting SystemState; for a fresh
+    ///                       execution this is the image ID (32 bytes)
+    ///     journal: Journal bytes
+    ///     exit_kind: How execution stopped (HALTED, PAUSED, SYSTEM_SPLIT
+    ///                or SESSION_LIMIT)
+    ///     exit_code: User exit code, for HALTED and PAUSED
+    ///     post_state_digest: Digest of the final SystemState (32 bytes)
+    ///     input_digest: Digest of the claim's input (32 bytes, default zero)
+    ///     output_digest: Digest of the claim's output (32 bytes). Default:
+    ///                    journal's digest with no assumptions
+    /// 
+    /// Raises:
+    ///     ValueError: If a digest isn't 32 bytes or exit_kind is UNKNOWN
+    /// 
+    /// Example:
+    ///     claim = Claim.from_parts(image_id, journal, exit_kind=ExitKind.PAUSED,
+    ///                              post_state_digest=post)
+    #[staticmethod]
+    #[pyo3(signature = (
+        pre_state_digest,
+        journal,
+        exit_kind=ExitKind::Halted,
+        exit_code=0,
+        post_state_digest=None,
+        input_digest=None,
+        output_digest=None,
+    ))]
+    pub fn from_parts(
+        pre_state_digest: Vec<u8>,
+        journal: Vec<u8>,
+        exit_kind: ExitKind,
+        exit_code: u32,
+        post_state_digest: Option<Vec<u8>>,
+        input_digest: Option<Vec<u8>>,
+        output_digest: Option<Vec<u8>>,
+    ) -> PyResult<Self> {
+        let (exit, exit_code) = match exit_kind {
+            ExitKind::Halted => (ExitCode::Halted(exit_code), exit_code),
+            ExitKind::Paused => (ExitCode::Paused(exit_code), exit_code),
+            ExitKind::SystemSplit => (ExitCode::SystemSplit, u32::MAX),
+            ExitKind::SessionLimit => (ExitCode::SessionLimit, u32::MAX),
+            ExitKind::Unknown => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "exit_kind UNKNOWN has no ReceiptClaim equivalent",
+                ))
+            }
+        };
+        let pre = part_digest(&pre_state_digest, "pre_state_digest")?;
+        let post = post_state_digest
+            .map(|bytes| part_digest(&bytes, "post_state_digest"))
+            .transpose()?;
+        let input = input_digest
+            .map(|bytes| part_digest(&bytes, "input_digest"))
+            .transpose()?;
+        let output = output_digest
+            .map(|bytes| part_digest(&bytes, "output_digest"))
+            .transpose()?;
+        
+        let mut claim = Self::new(pre.as_bytes().to_vec(), journal, exit_code)?;
+        claim.post_state_digest = post.map(|digest| digest.as_bytes().to_vec());
+        claim.exit = exit;
+        claim.input_digest = input.unwrap_or(Digest::ZERO);
+        claim.output_digest = output;
+        Ok(claim)
+    }
+    
+    /// How execution stopped, including whether it halted or paused
+    #[getter]
+    pub fn exit(&self) -> ExitStatus {
+        ExitStatus::from_risc0(self.exit)
+    }
+    
+    /// How the guest stopped (same as exit.kind)
+    #[getter]
+    pub fn exit_kind(&self) -> ExitKind {
+        ExitStatus::from_risc0(self.exit).kind
+    }
+    
+    /// Digest of the claim's input (32 bytes; zero when there is none)
+    #[getter]
+    pub fn input_digest(&self) -> Vec<u8> {
+        self.input_digest.as_bytes().to_vec()
+    }
+    
+    /// Digest of the claim's output: the journal digest plus any assumptions (32 bytes)
+    #[getter]
+    pub fn output_digest(&self) -> PyResult<Vec<u8>> {
+        Ok(self.risc0_output_digest()?.as_bytes().to_vec())
+    }
+    
     /// Get the image ID as a hex string
     #[getter]
     pub fn image_id_hex(&self) -> String {
@@ -61,6 +213,31 @@ impl Claim {
         hex::encode(&self.journal_digest)
     }
     
+    /// Get the pre-state digest as a hex string
+    #[getter]
+    pub fn pre_state_digest_hex(&self) -> String {
+        hex::encode(&self.pre_state_digest)
+    }
+    
+    /// Get the post-state digest as a hex string (None if unknown)
+    #[getter]
+    pub fn post_state_digest_hex(&self) -> Option<String> {
+        self.post_state_digest.as_ref().map(hex::encode)
+    }
+    
+    /// The canonical 32-byte digest of this claim
+    /// 
+    /// Same as risc0's ReceiptClaim digest: what a receipt's seal commits
+    /// to and what another receipt's assumptions list refers to. A hand-built
+    /// claim with no post-state digest gets the one risc0 uses for such
+    /// claims, so Claim.for_success(image_id, journal).digest() is the
+    /// digest env::verify(image_id, journal) looks for.
+    /// 
+    /// Two claims are equal, and hash equally, exactly when their digests match.
+    pub fn digest(&self) -> PyResult<Vec<u8>> {
+        Ok(self.risc0_digest()?.as_bytes().to_vec())
+    }
+    
     /// Check if this claim matches an expected image ID and journal
     pub fn matches(&self, image_id: Vec<u8>, journal: Vec<u8>) -> bool {
         self.image_id == image_id && self.journal == journal
@@ -72,6 +249,28 @@ impl Claim {
         self.exit_code == 0
     }
     
+    /// Stable JSON representation (see pyr0.schema("Claim"))
+    /// 
+    /// Byte strings are lowercase hex and keys are sorted.
+    /// 
+    /// Args:
+    ///     pretty: Indent the output for humans
+    #[pyo3(signature = (pretty=false))]
+    pub fn to_json(&self, pretty: bool) -> PyResult<String> {
+        json_to_string(&self.to_json_value()?, pretty)
+    }
+    
+    pub fn __eq__(&self, other: &Self) -> PyResult<bool> {
+        Ok(self.risc0_digest()? == other.risc0_digest()?)
+    }
+    
+    pub fn __hash__(&self) -> PyResult<u64> {
+        let digest = self.risc0_digest()?;
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&digest.as_bytes()[..8]);
+        Ok(u64::from_le_bytes(prefix))
+    }
+    
     pub fn __repr__(&self) -> String {
         format!(
             "Claim(image_id={}, journal_len={}, exit_code={})",
@@ -97,6 +296,55 @@ impl Claim {
 }
 
 impl Claim {
+    /// JSON form described by schema("Claim")
+    ///
+    /// Carries every part of the digest, so HALTED(0) and PAUSED(0) differ
+    /// and the JSON can be checked against Claim.digest().
+    pub(crate) fn to_json_value(&self) -> PyResult<serde_json::Value> {
+        Ok(serde_json::json!({
+            "image_id": hex::encode(&self.image_id),
+            "journal": hex::encode(&self.journal),
+            "journal_digest": hex::encode(&self.journal_digest),
+            "exit_code": self.exit_code,
+            "exit_kind": self.exit_kind().as_str(),
+            "input_digest": hex::encode(self.input_digest.as_bytes()),
+            "output_digest": hex::encode(self.risc0_output_digest()?.as_bytes()),
+            "pre_state_digest": hex::encode(&self.pre_state_digest),
+            "post_state_digest": self.post_state_digest.as_ref().map(hex::encode),
+        }))
+    }
+
+    /// Digest of the equivalent risc0 ReceiptClaim
+    /// 
+    /// Every part is pruned to its digest, which doesn't change the result.
+    pub(crate) fn risc0_digest(&self) -> PyResult<Digest> {
+        let post = match &self.post_state_digest {
+            Some(bytes) => digest_from_bytes(bytes)?,
+            // What ReceiptClaim::ok() puts there
+            None => SystemState { pc: 0, merkle_root: Digest::ZERO }.digest(),
+        };
+        let claim = ReceiptClaim {
+            pre: MaybePruned::Pruned(digest_from_bytes(&self.pre_state_digest)?),
+            post: MaybePruned::Pruned(post),
+            exit_code: self.exit,
+            input: MaybePruned::Pruned(self.input_digest),
+            output: MaybePruned::Pruned(self.risc0_output_digest()?),
+        };
+        Ok(claim.digest())
+    }
+
+    /// Digest of the claim's output, derived from the journal unless given
+    fn risc0_output_digest(&self) -> PyResult<Digest> {
+        match self.output_digest {
+            Some(digest) => Ok(digest),
+            None => Ok(Output {
+                journal: MaybePruned::Pruned(digest_from_bytes(&self.journal_digest)?),
+                assumptions: MaybePruned::Pruned(Digest::ZERO),
+            }
+            .digest()),
+        }
+    }
+
     /// Create a Claim from a RISC Zero claim
     pub fn from_risc0_claim(
         claim: &risc0_zkvm::ReceiptClaim,
@@ -108,6 +356,9 @@ impl Claim {
             risc0_zkvm::MaybePruned::Pruned(digest) => digest.clone(),
         };
         
+        // Digest of the post-execution state (pruned or not)
+        let post_state_digest = claim.post.digest();
+        
         // Extract exit code
         let exit_code = match claim.exit_code {
             risc0_zkvm::ExitCode::Halted(code) => code,
@@ -124,6 +375,28 @@ impl Claim {
             journal: journal_bytes,
             journal_digest: journal_digest.as_bytes().to_vec(),
             exit_code,
+            pre_state_digest: image_id.as_bytes().to_vec(),
+            post_state_digest: Some(post_state_digest.as_bytes().to_vec()),
+            exit: claim.exit_code,
+            input_digest: claim.input.digest(),
+            output_digest: Some(claim.output.digest()),
         })
     }
-}
\ No newline at end of file
+}
+
+/// Parse one 32-byte digest argument of Claim.from_parts()
+fn part_digest(bytes: &[u8], name: &str) -> PyResult<Digest> {
+    Digest::try_from(bytes).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{name} must be 32 bytes, got {}", bytes.len()))
+    })
+}
+
+/// Encode a to_json() document, compact or indented
+pub(crate) fn json_to_string(value: &serde_json::Value, pretty: bool) -> PyResult<String> {
+    let text = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    text.map_err(|e| crate::errors::serialization_error(format!("Failed to encode JSON: {e}")))
+}
@@ -0,0 +1,351 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::types::PyTuple;
+use pyo3::IntoPyObjectExt;
+
+/// A value type in RISC Zero's word-based serde format
+///
+/// This is the format used by env::read() and env::commit() in the guest:
+/// every primitive occupies at least one little-endian u32 word.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum WireType {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    Bool,
+    F32,
+    F64,
+    /// String: length word, then UTF-8 bytes packed into zero-padded words
+    Str,
+    /// Vec<u8>: length word, then one word per byte
+    VecU8,
+    /// [u8; N]: N words, one per byte, no length prefix
+    ArrayU8(usize),
+}
+
+impl WireType {
+    /// Parse a type name, accepting Rust spellings ("Vec<u8>", "[u8; 32]")
+    /// and Python-friendly aliases ("bytes", "str")
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        let compact: String = name.chars().filter(|c| !c.is_whitespace()).collect();
+        Ok(match compact.as_str() {
+            "u8" => WireType::U8,
+            "u16" => WireType::U16,
+            "u32" => WireType::U32,
+            "u64" => WireType::U64,
+            "i8" => WireType::I8,
+            "i16" => WireType::I16,
+            "i32" => WireType::I32,
+            "i64" => WireType::I64,
+            "bool" => WireType::Bool,
+            "f32" => WireType::F32,
+            "f64" => WireType::F64,
+            "str" | "String" => WireType::Str,
+            "bytes" | "Vec<u8>" => WireType::VecU8,
+            _ => {
+                let len = compact
+                    .strip_prefix("[u8;")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .and_then(|n| n.parse::<usize>().ok());
+                match len {
+                    Some(n) => WireType::ArrayU8(n),
+                    None => return Err(PyErr::new::<PyValueError, _>(format!(
+                        "Unknown serde type '{}'. Use u8/u16/u32/u64, i8/i16/i32/i64, bool, \
+                         f32/f64, str, bytes, or [u8; N]",
+                        name
+                    ))),
+                }
+            }
+        })
+    }
+
+    /// The Rust type a guest reads this value as
+    pub(crate) fn rust_name(&self) -> String {
+        match self {
+            WireType::U8 => "u8".to_string(),
+            WireType::U16 => "u16".to_string(),
+            WireType::U32 => "u32".to_string(),
+            WireType::U64 => "u64".to_string(),
+            WireType::I8 => "i8".to_string(),
+            WireType::I16 => "i16".to_string(),
+            WireType::I32 => "i32".to_string(),
+            WireType::I64 => "i64".to_string(),
+            WireType::Bool => "bool".to_string(),
+            WireType::F32 => "f32".to_string(),
+            WireType::F64 => "f64".to_string(),
+            WireType::Str => "String".to_string(),
+            WireType::VecU8 => "Vec<u8>".to_string(),
+            WireType::ArrayU8(n) => format!("[u8; {}]", n),
+        }
+    }
+
+    /// Infer the type of an untagged Python value (ints are ambiguous and rejected)
+    fn infer(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if value.is_instance_of::<pyo3::types::PyBool>() {
+            Ok(WireType::Bool)
+        } else if value.is_instance_of::<pyo3::types::PyString>() {
+            Ok(WireType::Str)
+        } else if value.is_instance_of::<pyo3::types::PyBytes>()
+            || value.is_instance_of::<pyo3::types::PyByteArray>()
+        {
+            Ok(WireType::VecU8)
+        } else if value.is_instance_of::<pyo3::types::PyFloat>() {
+            Ok(WireType::F64)
+        } else {
+            Err(PyErr::new::<PyTypeError, _>(format!(
+                "Cannot infer serde type for {} - pass a (type, value) tuple such as (\"u32\", 5)",
+                value.get_type().name()?
+            )))
+        }
+    }
+}
+
+fn push_u64(out: &mut Vec<u32>, v: u64) {
+    out.push((v & 0xFFFF_FFFF) as u32);
+    out.push((v >> 32) as u32);
+}
+
+/// Append a Vec<u8>: length word, then one word per byte
+pub(crate) fn push_vec_u8(out: &mut Vec<u32>, bytes: &[u8]) {
+    out.push(bytes.len() as u32);
+    out.extend(bytes.iter().map(|&b| b as u32));
+}
+
+/// Append the serde encoding of one Python value
+pub(crate) fn encode_value(ty: WireType, value: &Bound<'_, PyAny>, out: &mut Vec<u32>) -> PyResult<()> {
+    match ty {
+        WireType::U8 => out.push(value.extract::<u8>()? as u32),
+        WireType::U16 => out.push(value.extract::<u16>()? as u32),
+        WireType::U32 => out.push(value.extract::<u32>()?),
+        WireType::U64 => push_u64(out, value.extract::<u64>()?),
+        WireType::I8 => out.push(value.extract::<i8>()? as i32 as u32),
+        WireType::I16 => out.push(value.extract::<i16>()? as i32 as u32),
+        WireType::I32 => out.push(value.extract::<i32>()? as u32),
+        WireType::I64 => push_u64(out, value.extract::<i64>()? as u64),
+        WireType::Bool => out.push(value.extract::<bool>()? as u32),
+        WireType::F32 => out.push(value.extract::<f32>()?.to_bits()),
+        WireType::F64 => push_u64(out, value.extract::<f64>()?.to_bits()),
+        WireType::Str => {
+            let text: String = value.extract()?;
+            out.push(text.len() as u32);
+            for chunk in text.as_bytes().chunks(4) {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                out.push(u32::from_le_bytes(word));
+            }
+        }
+        WireType::VecU8 => push_vec_u8(out, &value.extract::<Vec<u8>>()?),
+        WireType::ArrayU8(n) => {
+            let bytes: Vec<u8> = value.extract()?;
+            if bytes.len() != n {
+                return Err(PyErr::new::<PyValueError, _>(
+                    format!("[u8; {}] requires exactly {} bytes, got {}", n, n, bytes.len())
+                ));
+            }
+            out.extend(bytes.iter().map(|&b| b as u32));
+        }
+    }
+    Ok(())
+}
+
+/// Serialize words as little-endian bytes
+pub(crate) fn words_to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|w| w.to_le_bytes()).collect()
+}
+
+/// Encode a sequence of values, each either a (type, value) tuple or an
+/// untagged bool/str/bytes/float
+pub(crate) fn encode_values(values: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
+    Ok(encode_items(values)?.into_iter().flat_map(|(_, bytes)| bytes).collect())
+}
+
+/// Like encode_values(), but keeps each value's type and encoding separate
+pub(crate) fn encode_items(values: &Bound<'_, PyAny>) -> PyResult<Vec<(WireType, Vec<u8>)>> {
+    let mut items = Vec::new();
+    for item in values.try_iter()? {
+        let item = item?;
+        if let Ok(pair) = item.downcast::<PyTuple>() {
+            if pair.len() != 2 {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Typed values must be (type, value) tuples"
+                ));
+            }
+            let ty = WireType::parse(&pair.get_item(0)?.extract::<String>()?)?;
+            let mut words = Vec::new();
+            encode_value(ty, &pair.get_item(1)?, &mut words)?;
+            items.push((ty, words_to_bytes(&words)));
+        } else {
+            let ty = WireType::infer(&item)?;
+            let mut words = Vec::new();
+            encode_value(ty, &item, &mut words)?;
+            items.push((ty, words_to_bytes(&words)));
+        }
+    }
+    Ok(items)
+}
+
+/// Reads little-endian words from a byte buffer
+pub(crate) struct WordReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WordReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Number of bytes consumed so far
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    fn word(&mut self) -> PyResult<u32> {
+        let end = self.pos + 4;
+        if end > self.data.len() {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "Unexpected end of data at byte {} (have {} bytes)",
+                self.pos,
+                self.data.len()
+            )));
+        }
+        let word = u32::from_le_bytes(self.data[self.pos..end].try_into().unwrap());
+        self.pos = end;
+        Ok(word)
+    }
+
+    fn u64(&mut self) -> PyResult<u64> {
+        let lo = self.word()? as u64;
+        let hi = self.word()? as u64;
+        Ok(lo | (hi << 32))
+    }
+
+    fn byte_words(&mut self, n: usize) -> PyResult<Vec<u8>> {
+        (0..n)
+            .map(|_| {
+                let w = self.word()?;
+                u8::try_from(w).map_err(|_| PyErr::new::<PyValueError, _>(
+                    format!("Byte word out of range: {:#x}", w)
+                ))
+            })
+            .collect()
+    }
+
+    /// Decode one value of the given type
+    pub(crate) fn read(&mut self, py: Python<'_>, ty: WireType) -> PyResult<PyObject> {
+        fn narrow<T: TryFrom<i64>>(v: i64, name: &str) -> PyResult<T> {
+            T::try_from(v).map_err(|_| PyErr::new::<PyValueError, _>(
+                format!("Value {} out of range for {}", v, name)
+            ))
+        }
+        match ty {
+            WireType::U8 => narrow::<u8>(self.word()? as i64, "u8")?.into_py_any(py),
+            WireType::U16 => narrow::<u16>(self.word()? as i64, "u16")?.into_py_any(py),
+            WireType::U32 => self.word()?.into_py_any(py),
+            WireType::U64 => self.u64()?.into_py_any(py),
+            WireType::I8 => narrow::<i8>(self.word()? as i32 as i64, "i8")?.into_py_any(py),
+            WireType::I16 => narrow::<i16>(self.word()? as i32 as i64, "i16")?.into_py_any(py),
+            WireType::I32 => (self.word()? as i32).into_py_any(py),
+            WireType::I64 => (self.u64()? as i64).into_py_any(py),
+            WireType::Bool => match self.word()? {
+                0 => false.into_py_any(py),
+                1 => true.into_py_any(py),
+                w => Err(PyErr::new::<PyValueError, _>(format!("Invalid bool word: {}", w))),
+            },
+            WireType::F32 => f32::from_bits(self.word()?).into_py_any(py),
+            WireType::F64 => f64::from_bits(self.u64()?).into_py_any(py),
+            WireType::Str => {
+                let len = self.word()? as usize;
+                // The length word is untrusted, so check it before allocating
+                let padded = len.div_ceil(4) * 4;
+                if padded > self.data.len() - self.pos {
+                    return Err(PyErr::new::<PyValueError, _>(format!(
+                        "String of {} bytes at byte {} runs past the end of data (have {} bytes)",
+                        len,
+                        self.pos,
+                        self.data.len()
+                    )));
+                }
+                let mut bytes = Vec::with_capacity(len);
+                for _ in 0..len.div_ceil(4) {
+                    bytes.extend_from_slice(&self.word()?.to_le_bytes());
+                }
+                bytes.truncate(len);
+                let text = String::from_utf8(bytes)
+                    .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid UTF-8 string: {}", e)))?;
+                text.into_py_any(py)
+            }
+            WireType::VecU8 => {
+                let len = self.word()? as usize;
+                let bytes = self.byte_words(len)?;
+                pyo3::types::PyBytes::new(py, &bytes).into_py_any(py)
+            }
+            WireType::ArrayU8(n) => {
+                let bytes = self.byte_words(n)?;
+                pyo3::types::PyBytes::new(py, &bytes).into_py_any(py)
+            }
+        }
+    }
+}
+
+/// Decode values in order according to a list of type names
+pub(crate) fn decode_values(py: Python<'_>, data: &[u8], fmt: &[String], strict: bool) -> PyResult<Vec<PyObject>> {
+    let (values, consumed) = decode_prefix(py, data, fmt)?;
+    if strict && consumed != data.len() {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "{} trailing bytes after decoding {} values",
+            data.len() - consumed,
+            fmt.len()
+        )));
+    }
+    Ok(values)
+}
+
+/// Decode values from a journal zero-padded to a constant size (see pyr0.codec.pad_journal_to)
+///
+/// Like strict decoding, except the bytes after the last value may be
+/// padding, which must be all zero.
+pub(crate) fn decode_padded(py: Python<'_>, data: &[u8], fmt: &[String]) -> PyResult<Vec<PyObject>> {
+    let (values, consumed) = decode_prefix(py, data, fmt)?;
+    if let Some(at) = data[consumed..].iter().position(|&b| b != 0) {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "non-zero byte at offset {} in padding after decoding {} values",
+            consumed + at,
+            fmt.len()
+        )));
+    }
+    Ok(values)
+}
+
+/// Decode fmt from the start of data, returning the values and bytes consumed
+fn decode_prefix(py: Python<'_>, data: &[u8], fmt: &[String]) -> PyResult<(Vec<PyObject>, usize)> {
+    let types = fmt.iter().map(|t| WireType::parse(t)).collect::<PyResult<Vec<_>>>()?;
+    let mut reader = WordReader::new(data);
+    let values = types
+        .into_iter()
+        .map(|ty| reader.read(py, ty))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok((values, reader.position()))
+}
+
+/// Encode values in RISC Zero serde format (see pyr0.codec.commit_encode)
+#[pyfunction]
+#[pyo3(name = "_serde_encode")]
+pub fn serde_encode(values: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
+    encode_values(values)
+}
+
+/// Decode values from RISC Zero serde format (see pyr0.codec.commit_decode)
+#[pyfunction]
+#[pyo3(name = "_serde_decode", signature = (data, fmt, strict=true, padded=false))]
+pub fn serde_decode(py: Python<'_>, data: Vec<u8>, fmt: Vec<String>, strict: bool, padded: bool) -> PyResult<Vec<PyObject>> {
+    if padded {
+        return decode_padded(py, &data, &fmt);
+    }
+    decode_values(py, &data, &fmt, strict)
+}
@@ -1,11 +1,300 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::{PyValueError, PyRuntimeError};
-use risc0_zkvm::{ExecutorEnv, ProverOpts};
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyDict, PyTuple};
+use risc0_zkvm::{ExecutorEnv, MaybePruned, NullSegmentRef, ProverOpts, ReceiptClaim};
 use risc0_zkvm::sha::{Digestible, Sha256, Digest};
+use crate::claim::Claim;
+use crate::errors;
 use crate::image::Image;
 use crate::receipt::Receipt;
+use crate::segment::SuccinctReceipt;
+use crate::session::SessionInfo;
 use crate::input_builder::InputBuilder;
+use crate::manifest::GuestManifest;
 use std::collections::{HashSet, HashMap};
+use std::sync::Mutex;
+use zeroize::Zeroize;
+
+/// Domain separator for Composer.fingerprint()
+const FINGERPRINT_DOMAIN: &[u8] = b"pyr0.composer.fingerprint.v1";
+
+/// Parse the `kind` argument accepted by Composer methods (default: SUCCINCT)
+pub(crate) fn parse_kind(kind: Option<&Bound<'_, PyAny>>) -> PyResult<crate::receipt::ReceiptKind> {
+    use crate::receipt::ReceiptKind;
+    
+    let Some(k) = kind else {
+        return Ok(ReceiptKind::Succinct);  // Default
+    };
+    
+    // Try to extract ReceiptKind enum
+    if let Ok(receipt_kind) = k.extract::<ReceiptKind>() {
+        Ok(receipt_kind)
+    } else if let Ok(kind_str) = k.extract::<String>() {
+        // Fallback to string for compatibility
+        match kind_str.to_lowercase().as_str() {
+            "composite" => Ok(ReceiptKind::Composite),
+            "succinct" => Ok(ReceiptKind::Succinct),
+            "groth16" => Ok(ReceiptKind::Groth16),
+            _ => Err(PyErr::new::<PyValueError, _>(
+                format!("Invalid proof kind '{}'. Use ReceiptKind.SUCCINCT, ReceiptKind.COMPOSITE, or ReceiptKind.GROTH16", kind_str)
+            )),
+        }
+    } else {
+        Err(PyErr::new::<PyValueError, _>(
+            "kind must be a ReceiptKind enum value or string"
+        ))
+    }
+}
+
+/// Digest of the claim a receipt proves once its assumptions are resolved
+///
+/// This is the claim an outer guest's env::verify() checks, whether the
+/// receipt is already succinct or still composite.
+pub(crate) fn unconditional_claim_digest(receipt: &risc0_zkvm::Receipt) -> anyhow::Result<Digest> {
+    let mut claim = receipt.claim()?;
+    if let Ok(Some(output)) = claim.as_value_mut().and_then(|c| c.output.as_value_mut()) {
+        output.assumptions = risc0_zkvm::Assumptions::default().into();
+    }
+    Ok(claim.digest())
+}
+
+/// What Composer does when write methods from incompatible input patterns are mixed
+#[derive(Clone, Copy, PartialEq)]
+enum MixingPolicy {
+    Error,
+    Warn,
+    Ignore,
+}
+
+impl MixingPolicy {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "error" => Ok(MixingPolicy::Error),
+            "warn" => Ok(MixingPolicy::Warn),
+            "ignore" => Ok(MixingPolicy::Ignore),
+            _ => Err(PyErr::new::<PyValueError, _>(format!(
+                "input_mixing must be 'error', 'warn' or 'ignore', got '{}'",
+                name
+            ))),
+        }
+    }
+}
+
+/// One Composer.assert_journal() expectation
+struct JournalAssertion {
+    prefix: Option<Vec<u8>>,
+    schema: Option<Vec<String>>,
+    values: Option<Vec<PyObject>>,
+    strict: bool,
+    size: Option<usize>,
+}
+
+impl JournalAssertion {
+    /// Describe every way the journal falls short of this expectation
+    fn mismatches(&self, py: Python<'_>, journal: &[u8], out: &mut Vec<String>) -> PyResult<()> {
+        if let Some(prefix) = &self.prefix {
+            if !journal.starts_with(prefix) {
+                let at = journal.iter().zip(prefix).take_while(|(a, b)| a == b).count();
+                let window = |bytes: &[u8]| {
+                    let end = bytes.len().min(at + 16);
+                    format!("{}{}", hex::encode(&bytes[at.min(end)..end]), if end < bytes.len() { "..." } else { "" })
+                };
+                out.push(format!(
+                    "prefix differs at byte {} (journal is {} bytes, prefix {}):\n  expected: {}\n  actual:   {}",
+                    at,
+                    journal.len(),
+                    prefix.len(),
+                    window(prefix),
+                    window(journal)
+                ));
+            }
+        }
+        
+        if let Some(size) = self.size {
+            if journal.len() != size {
+                out.push(format!(
+                    "journal is {} bytes, expected a constant-size journal of {} bytes",
+                    journal.len(),
+                    size
+                ));
+            }
+        }
+        
+        let Some(schema) = &self.schema else { return Ok(()) };
+        // A constant-size journal ends in zero padding, not trailing values
+        let decoded = match self.size {
+            Some(_) => crate::codec::decode_padded(py, journal, schema),
+            None => crate::codec::decode_values(py, journal, schema, self.strict),
+        };
+        let decoded = match decoded {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                out.push(format!("journal does not decode as {:?}: {}", schema, e.value(py)));
+                return Ok(());
+            }
+        };
+        for (i, (actual, expected)) in decoded.iter().zip(self.values.iter().flatten()).enumerate() {
+            let (actual, expected) = (actual.bind(py), expected.bind(py));
+            if !actual.eq(expected)? {
+                out.push(format!(
+                    "value {} ({}):\n  expected: {}\n  actual:   {}",
+                    i,
+                    schema[i],
+                    expected.repr()?,
+                    actual.repr()?
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reject receipts that can never back an assumption (fake or failed)
+fn check_assumable(receipt: &Receipt) -> PyResult<()> {
+    use crate::receipt::ReceiptKind;
+    
+    // Reject fake receipts
+    if receipt.kind()? == ReceiptKind::Fake {
+        return Err(errors::assumption_error(
+            "Cannot use fake receipt as assumption - fake receipts are for testing only"
+        ));
+    }
+    
+    // Check exit status
+    let exit_status = receipt.exit()?;
+    if !exit_status.ok() {
+        return Err(errors::assumption_error(
+            format!("Cannot use failed receipt as assumption - exit code was {}", 
+                    exit_status.user_code.unwrap_or(u32::MAX))
+        ));
+    }
+    Ok(())
+}
+
+/// Claim-only assumption for a successful run, passed to the executor by digest
+/// 
+/// The executor only checks claims; a zero control root stands for the
+/// recursion circuit the receipt will eventually be resolved with.
+fn claim_only_assumption(image_id: Digest, journal_digest: Digest) -> risc0_zkvm::Assumption {
+    let claim = ReceiptClaim::ok(image_id, MaybePruned::<Vec<u8>>::Pruned(journal_digest));
+    risc0_zkvm::Assumption { claim: claim.digest(), control_root: Digest::ZERO }
+}
+
+/// (image_id, journal_digest) key used to deduplicate assumptions
+fn dedup_key(receipt: &risc0_zkvm::Receipt) -> PyResult<(Digest, Digest)> {
+    let claim = receipt.claim()
+        .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
+    let claim_value = claim.as_value()
+        .map_err(|_| errors::composition_error("Claim is pruned"))?;
+    
+    // MaybePruned<T> implements Digestible, so we can call digest() directly
+    let image_digest = claim_value.pre.digest();
+    let journal_digest = *risc0_zkvm::sha::Impl::hash_bytes(&receipt.journal.bytes);
+    Ok((image_digest, journal_digest))
+}
+
+/// A proven sub-composition shared by several outer Composers
+/// 
+/// Wraps a successful receipt - typically a COMPOSITE receipt from an inner
+/// Composer - so it can be passed to Composer.assume_shared() on any number
+/// of outer Composers. The first prove() that needs it compresses it to
+/// SUCCINCT; every later prove() reuses that result, so a batch pays for
+/// resolving the shared subtree once. Concurrent prove_async() calls wait
+/// for the in-flight compression instead of starting their own.
+/// 
+/// Example:
+///     shared = pyr0.SharedAssumption(sub.prove(kind="composite"))
+///     for comp in outer_composers:
+///         comp.assume_shared(shared)
+///     receipts = [comp.prove() for comp in outer_composers]
+#[pyclass(module = "pyr0", frozen)]
+pub struct SharedAssumption {
+    source: risc0_zkvm::Receipt,
+    resolved: Mutex<Option<risc0_zkvm::Receipt>>,
+}
+
+#[pymethods]
+impl SharedAssumption {
+    /// Register a receipt for sharing
+    /// 
+    /// Succinct and Groth16 receipts are used as-is. Composite receipts are
+    /// compressed on first use.
+    /// 
+    /// Raises:
+    ///     AssumptionError: If the receipt is fake or the guest didn't exit successfully
+    #[new]
+    pub fn new(receipt: &Receipt) -> PyResult<Self> {
+        check_assumable(receipt)?;
+        let resolved = if receipt.is_unconditional()? {
+            Some(receipt.inner.clone())
+        } else {
+            None
+        };
+        Ok(SharedAssumption {
+            source: receipt.inner.clone(),
+            resolved: Mutex::new(resolved),
+        })
+    }
+    
+    /// The claim this assumption satisfies (image ID, journal, exit code)
+    pub fn claim(&self) -> PyResult<Claim> {
+        Receipt::from_risc0(self.source.clone()).claim()
+    }
+    
+    /// True once an unconditional receipt is available
+    /// 
+    /// False while another thread is still compressing it; this never waits
+    /// for that to finish.
+    #[getter]
+    pub fn is_resolved(&self) -> bool {
+        match self.resolved.try_lock() {
+            Ok(resolved) => resolved.is_some(),
+            Err(std::sync::TryLockError::Poisoned(poisoned)) => poisoned.into_inner().is_some(),
+            // Held only by resolved_receipt() while it compresses
+            Err(std::sync::TryLockError::WouldBlock) => false,
+        }
+    }
+    
+    /// Compress now if needed and return the unconditional receipt
+    /// 
+    /// Composer.prove() does this automatically; call it directly to pay the
+    /// compression cost up front, e.g. before fanning out prove_async() calls.
+    /// 
+    /// Raises:
+    ///     ProvingError: If compression fails
+    pub fn resolve(&self, py: Python<'_>) -> PyResult<Receipt> {
+        let receipt = crate::reservation::allow_threads(py, || self.resolved_receipt())
+            .map_err(|e| errors::proving_error(format!("Failed to resolve shared assumption: {}", e)))?;
+        Ok(Receipt::from_risc0(receipt))
+    }
+    
+    pub fn __repr__(&self) -> String {
+        let image_id = dedup_key(&self.source)
+            .map(|(image_id, _)| hex::encode(&image_id.as_bytes()[..8]))
+            .unwrap_or_else(|_| "?".to_string());
+        format!("SharedAssumption(image_id={}..., resolved={})", image_id, self.is_resolved())
+    }
+}
+
+impl SharedAssumption {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<risc0_zkvm::Receipt>> {
+        self.resolved.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    /// The unconditional receipt, compressing the source on first call
+    /// 
+    /// The lock is held while compressing so concurrent callers wait for
+    /// the result rather than compressing the same receipt again.
+    pub(crate) fn resolved_receipt(&self) -> anyhow::Result<risc0_zkvm::Receipt> {
+        let mut resolved = self.lock();
+        if let Some(receipt) = resolved.as_ref() {
+            return Ok(receipt.clone());
+        }
+        let compressed = risc0_zkvm::default_prover().compress(&ProverOpts::succinct(), &self.source)?;
+        *resolved = Some(compressed.clone());
+        Ok(compressed)
+    }
+}
 
 /// A builder for composing proofs with type-safe inputs and assumptions
 /// 
@@ -22,23 +311,42 @@ use std::collections::{HashSet, HashMap};
 pub struct Composer {
     image: Py<Image>,
     assumptions: Vec<risc0_zkvm::Receipt>,
+    shared_assumptions: Vec<Py<SharedAssumption>>,
+    claim_assumptions: Vec<(Digest, Digest)>,  // assume_claim(): (image_id, journal_digest)
     assumption_digests: HashSet<(Digest, Digest)>, // (image_id, journal_digest) for dedup
     input_builder: InputBuilder,  // Use InputBuilder for consistent API
-    expected_verifications: Vec<(Vec<u8>, Vec<u8>)>, // (image_id, journal)
+    expected_verifications: Vec<Claim>,
+    journal_assertions: Vec<JournalAssertion>,
+    input_mixing: MixingPolicy,
+    last_write: Option<&'static str>,  // write method used most recently
 }
 
 #[pymethods]
 impl Composer {
     /// Create a new Composer for the given image
+    /// 
+    /// Args:
+    ///     image: The outer guest's Image
+    ///     input_mixing: What to do when write_cbor() (Pattern A, the whole
+    ///                   input is one CBOR object) is combined with any other
+    ///                   write: "error" (default) raises InputPatternError,
+    ///                   "warn" emits a warning, "ignore" allows it. Use
+    ///                   write_cbor_frame() to mix CBOR with raw fields.
     #[new]
-    pub fn new(image: Py<Image>) -> Self {
-        Composer {
+    #[pyo3(signature = (image, input_mixing="error"))]
+    pub fn new(image: Py<Image>, input_mixing: &str) -> PyResult<Self> {
+        Ok(Composer {
             image,
             assumptions: Vec::new(),
+            shared_assumptions: Vec::new(),
+            claim_assumptions: Vec::new(),
             assumption_digests: HashSet::new(),
             input_builder: InputBuilder::new(),
             expected_verifications: Vec::new(),
-        }
+            journal_assertions: Vec::new(),
+            input_mixing: MixingPolicy::parse(input_mixing)?,
+            last_write: None,
+        })
     }
     
     /// Add multiple receipts as assumptions at once
@@ -50,7 +358,7 @@ impl Composer {
     ///     receipts: List of unconditional receipts from successful proofs
     /// 
     /// Raises:
-    ///     ValueError: If any receipt is invalid for composition
+    ///     AssumptionError: If any receipt is invalid for composition
     pub fn assume_many(&mut self, receipts: Vec<PyRef<Receipt>>) -> PyResult<()> {
         for receipt in receipts {
             self.assume(&receipt)?;
@@ -71,46 +379,20 @@ impl Composer {
     ///     receipt: An unconditional receipt from a successful proof
     /// 
     /// Raises:
-    ///     ValueError: If receipt is invalid for composition
+    ///     AssumptionError: If receipt is invalid for composition
     pub fn assume(&mut self, receipt: &Receipt) -> PyResult<()> {
-        use crate::receipt::ReceiptKind;
-        
         // Check if receipt is unconditional
         if !receipt.is_unconditional()? {
-            return Err(PyErr::new::<PyValueError, _>(
+            return Err(errors::assumption_error(
                 "Cannot use composite receipt as assumption - it has unresolved assumptions. \
-                 Use a succinct or groth16 receipt instead."
-            ));
-        }
-        
-        // Reject fake receipts
-        if receipt.kind()? == ReceiptKind::Fake {
-            return Err(PyErr::new::<PyValueError, _>(
-                "Cannot use fake receipt as assumption - fake receipts are for testing only"
-            ));
-        }
-        
-        // Check exit status
-        let exit_status = receipt.exit()?;
-        if !exit_status.ok() {
-            return Err(PyErr::new::<PyValueError, _>(
-                format!("Cannot use failed receipt as assumption - exit code was {}", 
-                        exit_status.user_code.unwrap_or(u32::MAX))
+                 Use a succinct or groth16 receipt instead, or wrap it in SharedAssumption."
             ));
         }
         
-        // Get claim digest for deduplication
-        let claim = receipt.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to get claim: {}", e)))?;
-        let claim_value = claim.as_value()
-            .map_err(|_| PyErr::new::<PyRuntimeError, _>("Claim is pruned"))?;
-        
-        // MaybePruned<T> implements Digestible, so we can call digest() directly
-        let image_digest = claim_value.pre.digest();
-        let journal_digest = *risc0_zkvm::sha::Impl::hash_bytes(&receipt.inner.journal.bytes);
+        check_assumable(receipt)?;
         
         // Check for duplicate (dedup by claim digest)
-        let claim_key = (image_digest, journal_digest);
+        let claim_key = dedup_key(&receipt.inner)?;
         if self.assumption_digests.contains(&claim_key) {
             // Already added, skip to avoid duplicate resolution cost
             return Ok(());
@@ -122,6 +404,61 @@ impl Composer {
         Ok(())
     }
     
+    /// Add a shared sub-composition as an assumption
+    /// 
+    /// Unlike assume(), the receipt may still be composite: it is compressed
+    /// when this Composer (or any other Composer sharing it) first proves,
+    /// and the result is reused by all of them. Deduplicated against
+    /// assume() by claim, like assume().
+    /// 
+    /// Args:
+    ///     shared: A SharedAssumption, usually registered once per batch
+    pub fn assume_shared(&mut self, py: Python<'_>, shared: Py<SharedAssumption>) -> PyResult<()> {
+        let claim_key = dedup_key(&shared.get().source)?;
+        if self.assumption_digests.contains(&claim_key) {
+            return Ok(());
+        }
+        
+        self.shared_assumptions.push(shared.clone_ref(py));
+        self.assumption_digests.insert(claim_key);
+        Ok(())
+    }
+    
+    /// Add an assumption by claim only, without a receipt
+    /// 
+    /// For compositions whose inner proof doesn't exist yet, e.g. because
+    /// another party will supply it. The guest's env::verify() succeeds
+    /// against the claim, but the result stays conditional: prove() refuses
+    /// such a Composer, and prove_conditional() returns a receipt that must
+    /// be resolve()d with the inner receipt before it proves anything.
+    /// Deduplicated against assume() by claim, like assume().
+    /// 
+    /// Args:
+    ///     image_id: Image ID of the inner guest (32 bytes)
+    ///     journal_digest: SHA-256 of the inner journal (32 bytes); the inner
+    ///                     run is assumed to have exited with code 0
+    /// 
+    /// Raises:
+    ///     ValueError: If either digest isn't 32 bytes
+    pub fn assume_claim(&mut self, image_id: Vec<u8>, journal_digest: Vec<u8>) -> PyResult<()> {
+        let image_id = crate::receipt::digest_from_bytes(&image_id)?;
+        if journal_digest.len() != 32 {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "Journal digest must be 32 bytes, got {}",
+                journal_digest.len()
+            )));
+        }
+        let journal_digest = crate::receipt::digest_from_bytes(&journal_digest)?;
+        
+        let claim_key = (image_id, journal_digest);
+        if self.assumption_digests.contains(&claim_key) {
+            return Ok(());
+        }
+        self.claim_assumptions.push(claim_key);
+        self.assumption_digests.insert(claim_key);
+        Ok(())
+    }
+    
     /// Write CBOR-encoded data WITHOUT frame (Pattern A: CBOR-only)
     /// 
     /// ⚠️ Use this ONLY if your entire input is a single CBOR object.
@@ -130,6 +467,7 @@ impl Composer {
     /// Delegates to the internal InputBuilder.
     /// See InputBuilder.write_cbor() for full documentation.
     pub fn write_cbor(&mut self, cbor_bytes: Vec<u8>) -> PyResult<()> {
+        self.track_write("write_cbor")?;
         self.input_builder.write_cbor_internal(cbor_bytes);
         Ok(())
     }
@@ -142,6 +480,7 @@ impl Composer {
     /// Delegates to the internal InputBuilder.
     /// See InputBuilder.write_cbor_frame() for full documentation.
     pub fn write_cbor_frame(&mut self, cbor_bytes: Vec<u8>) -> PyResult<()> {
+        self.track_write("write_cbor_frame")?;
         self.input_builder.write_cbor_frame_internal(cbor_bytes);
         Ok(())
     }
@@ -151,6 +490,7 @@ impl Composer {
     /// Delegates to the internal InputBuilder.
     /// See InputBuilder.write_u32() for full documentation.
     pub fn write_u32(&mut self, value: u32) -> PyResult<()> {
+        self.track_write("write_u32")?;
         self.input_builder.write_u32_internal(value);
         Ok(())
     }
@@ -160,15 +500,90 @@ impl Composer {
     /// Delegates to the internal InputBuilder.
     /// See InputBuilder.write_u64() for full documentation.
     pub fn write_u64(&mut self, value: u64) -> PyResult<()> {
+        self.track_write("write_u64")?;
         self.input_builder.write_u64_internal(value);
         Ok(())
     }
     
+    /// Write an i32 value (4 bytes, little-endian)
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_i32() for full documentation.
+    pub fn write_i32(&mut self, value: i32) -> PyResult<()> {
+        self.track_write("write_i32")?;
+        self.input_builder.write_i32_internal(value);
+        Ok(())
+    }
+    
+    /// Write an i64 value (8 bytes, little-endian)
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_i64() for full documentation.
+    pub fn write_i64(&mut self, value: i64) -> PyResult<()> {
+        self.track_write("write_i64")?;
+        self.input_builder.write_i64_internal(value);
+        Ok(())
+    }
+    
+    /// Write an f32 value (4 bytes, little-endian IEEE 754)
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_f32() for full documentation.
+    pub fn write_f32(&mut self, value: f32) -> PyResult<()> {
+        self.track_write("write_f32")?;
+        self.input_builder.write_f32_internal(value);
+        Ok(())
+    }
+    
+    /// Write an f64 value (8 bytes, little-endian IEEE 754)
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_f64() for full documentation.
+    pub fn write_f64(&mut self, value: f64) -> PyResult<()> {
+        self.track_write("write_f64")?;
+        self.input_builder.write_f64_internal(value);
+        Ok(())
+    }
+    
+    /// Write a bool as a single byte (0 or 1)
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_bool() for full documentation.
+    pub fn write_bool(&mut self, value: bool) -> PyResult<()> {
+        self.track_write("write_bool")?;
+        self.input_builder.write_bool_internal(value);
+        Ok(())
+    }
+    
+    /// Write a string as UTF-8 with a length frame (Pattern C)
+    /// 
+    /// Writes: [u64 byte length][UTF-8 bytes]
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_str_frame() for full documentation.
+    pub fn write_str_frame(&mut self, text: &str) -> PyResult<()> {
+        self.track_write("write_str_frame")?;
+        self.input_builder.write_str_frame_internal(text);
+        Ok(())
+    }
+    
+    /// Write values in RISC Zero serde format, for guests using env::read()
+    /// 
+    /// Delegates to the internal InputBuilder.
+    /// See InputBuilder.write_serde() for full documentation.
+    pub fn write_serde(&mut self, values: &Bound<'_, PyAny>) -> PyResult<()> {
+        let items = crate::codec::encode_items(values)?;
+        self.track_write("write_serde")?;
+        self.input_builder.write_serde_internal(items);
+        Ok(())
+    }
+    
     /// Write raw bytes without any encoding (ADVANCED)
     /// 
     /// Delegates to the internal InputBuilder.
     /// See InputBuilder.write_raw_bytes() for full documentation.
     pub fn write_raw_bytes(&mut self, data: Vec<u8>) -> PyResult<()> {
+        self.track_write("write_raw_bytes")?;
         self.input_builder.write_raw_bytes_internal(data);
         Ok(())
     }
@@ -180,6 +595,7 @@ impl Composer {
     /// Delegates to the internal InputBuilder.
     /// See InputBuilder.write_frame() for full documentation.
     pub fn write_frame(&mut self, data: Vec<u8>) -> PyResult<()> {
+        self.track_write("write_frame")?;
         self.input_builder.write_frame_internal(data);
         Ok(())
     }
@@ -201,10 +617,59 @@ impl Composer {
                 format!("write_bytes32 requires exactly 32 bytes, got {}", data.len())
             ));
         }
+        self.track_write("write_bytes32")?;
         self.input_builder.write_raw_bytes_internal(data);
         Ok(())
     }
     
+    /// Write the standard composition input: (receipt, image ID, extra data)
+    /// 
+    /// Three Vec<u8> values in RISC Zero serde framing (a length word, then
+    /// one word per byte), as test_composition_guest reads them. The receipt
+    /// is bincode-encoded, like Receipt.to_bytes(). This only writes input;
+    /// call assume() as well so the guest's env::verify() can be resolved.
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let receipt_bytes: Vec<u8> = env::read();
+    /// let image_id: Vec<u8> = env::read();
+    /// let extra: Vec<u8> = env::read();
+    /// ```
+    /// 
+    /// Args:
+    ///     receipt: The inner receipt
+    ///     expected_image_id: Image ID the guest should verify against (32 bytes)
+    ///     extra: Additional application data (default: empty)
+    /// 
+    /// Raises:
+    ///     ValueError: If expected_image_id isn't 32 bytes
+    #[pyo3(signature = (receipt, expected_image_id, extra=Vec::new()))]
+    pub fn write_composition_input(
+        &mut self,
+        receipt: &Receipt,
+        expected_image_id: Vec<u8>,
+        extra: Vec<u8>,
+    ) -> PyResult<()> {
+        if expected_image_id.len() != 32 {
+            return Err(PyErr::new::<PyValueError, _>(
+                format!("expected_image_id must be 32 bytes, got {}", expected_image_id.len())
+            ));
+        }
+        let receipt_bytes = receipt.to_bytes("bincode")?;
+        self.track_write("write_composition_input")?;
+        
+        let items = [&receipt_bytes, &expected_image_id, &extra]
+            .into_iter()
+            .map(|field| {
+                let mut words = Vec::new();
+                crate::codec::push_vec_u8(&mut words, field);
+                (crate::codec::WireType::VecU8, crate::codec::words_to_bytes(&words))
+            })
+            .collect();
+        self.input_builder.write_serde_internal(items);
+        Ok(())
+    }
+    
     /// Write an image ID (alias for write_bytes32)
     /// 
     /// **Guest code (Rust):**
@@ -222,12 +687,110 @@ impl Composer {
     /// This helps catch mismatches between what the guest will verify
     /// and what assumptions were added.
     pub fn expect_verification(&mut self, image_id: Vec<u8>, journal: Vec<u8>) -> PyResult<()> {
-        if image_id.len() != 32 {
+        self.expect_claim(Claim::for_success(image_id, journal)?);
+        Ok(())
+    }
+    
+    /// Like expect_verification(), but with only the journal's SHA-256 digest
+    /// 
+    /// For journals too large to hold in memory; compute the digest
+    /// incrementally with pyr0.codec.JournalHasher.
+    /// 
+    /// Raises:
+    ///     ValueError: If image_id or journal_digest isn't 32 bytes
+    pub fn expect_verification_digest(&mut self, image_id: Vec<u8>, journal_digest: Vec<u8>) -> PyResult<()> {
+        if journal_digest.len() != 32 {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "Journal digest must be 32 bytes, got {}",
+                journal_digest.len()
+            )));
+        }
+        // Preflight only compares digests, so the journal itself isn't needed
+        let mut claim = Claim::for_success(image_id, Vec::new())?;
+        claim.journal_digest = journal_digest;
+        self.expect_claim(claim);
+        Ok(())
+    }
+    
+    /// Register an expected assumption claim for preflight checking
+    /// 
+    /// Like expect_verification(), but takes a Claim so the expected exit
+    /// code can be given too (see Claim.for_success() and Claim.for_exit()).
+    /// A claim taken from an existing receipt works as well.
+    /// 
+    /// Example:
+    ///     comp.expect_claim(Claim.for_success(inner_image.id, journal))
+    ///     comp.expect_claim(inner_receipt.claim())
+    pub fn expect_claim(&mut self, claim: Claim) {
+        self.expected_verifications.push(claim);
+    }
+    
+    /// Declare what the proven journal must contain
+    /// 
+    /// Checked after every prove() (and dry_run()) of this Composer, so a
+    /// guest that commits the wrong thing fails at the proving boundary
+    /// instead of somewhere downstream. Calls accumulate; all expectations
+    /// must hold.
+    /// 
+    /// Args:
+    ///     prefix: Bytes the journal must start with
+    ///     schema: Type names (as for pyr0.codec.commit_decode) the journal
+    ///             must decode as
+    ///     values: Expected decoded values, one per schema entry
+    ///     strict: If True (default), trailing bytes after the schema fail
+    ///     size: Exact journal length in bytes. For guests that pad their
+    ///           journal to a constant size (see pyr0.codec.pad_journal_to)
+    ///           so its length reveals nothing; a journal of any other
+    ///           length fails. With a schema, the bytes after the values
+    ///           must be zero padding.
+    /// 
+    /// Raises:
+    ///     ValueError: If no expectation is given, a type name is unknown,
+    ///                 or values don't line up with schema
+    /// 
+    /// Note:
+    ///     A failed expectation raises JournalAssertionError from prove();
+    ///     its .mismatches holds one diff per failure and .receipt the
+    ///     receipt that was produced.
+    /// 
+    /// Example:
+    ///     comp.assert_journal(prefix=b"\x2a\x00\x00\x00")
+    ///     comp.assert_journal(schema=["u32", "[u8; 32]"], values=[42, digest])
+    ///     comp.assert_journal(schema=["u32"], values=[42], size=256)
+    #[pyo3(signature = (prefix=None, schema=None, values=None, strict=true, size=None))]
+    pub fn assert_journal(
+        &mut self,
+        prefix: Option<Vec<u8>>,
+        schema: Option<Vec<String>>,
+        values: Option<Vec<PyObject>>,
+        strict: bool,
+        size: Option<usize>,
+    ) -> PyResult<()> {
+        if prefix.is_none() && schema.is_none() && size.is_none() {
             return Err(PyErr::new::<PyValueError, _>(
-                format!("Image ID must be 32 bytes, got {}", image_id.len())
+                "assert_journal() needs a prefix, a schema or a size"
             ));
         }
-        self.expected_verifications.push((image_id, journal));
+        if let Some(schema) = &schema {
+            for name in schema {
+                crate::codec::WireType::parse(name)?;
+            }
+        }
+        match (&schema, &values) {
+            (None, Some(_)) => {
+                return Err(PyErr::new::<PyValueError, _>("values requires a schema"));
+            }
+            (Some(schema), Some(values)) if schema.len() != values.len() => {
+                return Err(PyErr::new::<PyValueError, _>(format!(
+                    "Got {} values for a schema of {} types",
+                    values.len(),
+                    schema.len()
+                )));
+            }
+            _ => {}
+        }
+        
+        self.journal_assertions.push(JournalAssertion { prefix, schema, values, strict, size });
         Ok(())
     }
     
@@ -238,59 +801,69 @@ impl Composer {
     /// - Each assumption has a matching expected verification (no unused)
     /// - No duplicate expectations
     /// 
+    /// With execute=True the guest is also run on the executor (no proving)
+    /// with the current input, and the claims it actually env::verify()s are
+    /// compared with the assumptions and expectations. That catches what the
+    /// digest comparison can't: a guest verifying a different journal than
+    /// expected, or never reaching a verification at all.
+    /// 
     /// Args:
     ///     raise_on_error: If True (default), raises on any mismatch
+    ///     execute: If True, also execute the guest and check its
+    ///              env::verify() calls
+    ///     max_cycles: Optional session limit in cycles for execute=True
     /// 
     /// Returns:
     ///     List of issues found (empty if all checks pass)
     /// 
     /// Raises:
-    ///     RuntimeError: If raise_on_error=True and issues are found
-    #[pyo3(signature = (raise_on_error=true))]
-    pub fn preflight_check(&self, raise_on_error: bool) -> PyResult<Vec<String>> {
+    ///     PreflightError: If raise_on_error=True and issues are found
+    ///                     (the list is available as e.issues)
+    ///     SessionLimitExceeded: If execute=True and the guest runs past max_cycles
+    #[pyo3(signature = (raise_on_error=true, execute=false, max_cycles=None))]
+    pub fn preflight_check(
+        &self,
+        py: Python<'_>,
+        raise_on_error: bool,
+        execute: bool,
+        max_cycles: Option<u64>,
+    ) -> PyResult<Vec<String>> {
         let mut issues = Vec::new();
         
         // Build map of assumption claims (for better error messages)
         let mut assumption_claims = HashMap::new();
-        for assumption in &self.assumptions {
+        let shared = self.shared_assumptions.iter().map(|s| &s.get().source);
+        for assumption in self.assumptions.iter().chain(shared) {
             if let Ok(claim) = assumption.claim() {
                 if let Ok(claim_value) = claim.as_value() {
-                    // MaybePruned<T> implements Digestible, so we can call digest() directly
-                    let image_id = claim_value.pre.digest();
-                    let journal_digest = *risc0_zkvm::sha::Impl::hash_bytes(&assumption.journal.bytes);
-                    let key = (image_id, journal_digest);
+                    let claim = Claim::from_risc0_claim(claim_value, assumption.journal.bytes.clone())?;
+                    let key = (claim.image_id, claim.journal_digest, claim.exit_code);
                     *assumption_claims.entry(key).or_insert(0) += 1;
                 }
             }
         }
+        for (image_id, journal_digest) in &self.claim_assumptions {
+            let key = (image_id.as_bytes().to_vec(), journal_digest.as_bytes().to_vec(), 0);
+            *assumption_claims.entry(key).or_insert(0) += 1;
+        }
         
-        // Build map of expected verifications
+        // Build map of expected claims (Claim construction already validated them)
         let mut expected_claims = HashMap::new();
-        for (image_id, journal) in &self.expected_verifications {
-            // Validate image ID is 32 bytes
-            if image_id.len() != 32 {
-                issues.push(format!(
-                    "Invalid image ID length in expected verification: {} bytes (must be 32)",
-                    image_id.len()
-                ));
-                continue;
-            }
-            
-            let image_digest = risc0_zkvm::sha::Digest::try_from(image_id.as_slice())
-                .map_err(|_| PyErr::new::<PyValueError, _>("Invalid image ID"))?;
-            let journal_digest = *risc0_zkvm::sha::Impl::hash_bytes(journal);
-            let key = (image_digest.clone(), journal_digest.clone());
+        for claim in &self.expected_verifications {
+            let key = (claim.image_id.clone(), claim.journal_digest.clone(), claim.exit_code);
             *expected_claims.entry(key).or_insert(0) += 1;
         }
         
         // Check for missing assumptions (expected but not provided)
-        for ((image_id, journal_digest), count) in &expected_claims {
-            match assumption_claims.get(&(*image_id, *journal_digest)) {
+        for (key, count) in &expected_claims {
+            let (image_id, journal_digest, exit_code) = key;
+            match assumption_claims.get(key) {
                 None => {
                     issues.push(format!(
-                        "Missing assumption for expected verification: image_id={}, journal_digest={}",
-                        &hex::encode(&image_id.as_bytes()[..8]),
-                        &hex::encode(&journal_digest.as_bytes()[..8])
+                        "Missing assumption for expected verification: image_id={}, journal_digest={}, exit_code={}",
+                        &hex::encode(&image_id[..8]),
+                        &hex::encode(&journal_digest[..8]),
+                        exit_code
                     ));
                 }
                 Some(assumption_count) if assumption_count < count => {
@@ -304,16 +877,22 @@ impl Composer {
         }
         
         // Check for unused assumptions (provided but not expected)
-        for ((image_id, journal_digest), _) in &assumption_claims {
-            if !expected_claims.contains_key(&(*image_id, *journal_digest)) {
+        for (key, _) in &assumption_claims {
+            if !expected_claims.contains_key(key) {
+                let (image_id, journal_digest, exit_code) = key;
                 issues.push(format!(
-                    "Unused assumption: image_id={}, journal_digest={} (no matching env::verify expected)",
-                    &hex::encode(&image_id.as_bytes()[..8]),
-                    &hex::encode(&journal_digest.as_bytes()[..8])
+                    "Unused assumption: image_id={}, journal_digest={}, exit_code={} (no matching env::verify expected)",
+                    &hex::encode(&image_id[..8]),
+                    &hex::encode(&journal_digest[..8]),
+                    exit_code
                 ));
             }
         }
         
+        if execute {
+            issues.extend(self.execution_issues(py, max_cycles)?);
+        }
+        
         // Raise if requested and there are issues
         if raise_on_error && !issues.is_empty() {
             let message = format!(
@@ -321,18 +900,16 @@ impl Composer {
                 issues.len(),
                 issues.join("\n")
             );
-            return Err(PyErr::new::<PyRuntimeError, _>(message));
+            return Err(errors::preflight_error(message, issues));
         }
         
         // If not raising but there are issues, emit Python warnings
         if !issues.is_empty() && !raise_on_error {
-            Python::with_gil(|py| {
-                if let Ok(warnings) = py.import("warnings") {
-                    for issue in &issues {
-                        let _ = warnings.call_method1("warn", (issue, ));
-                    }
+            if let Ok(warnings) = py.import("warnings") {
+                for issue in &issues {
+                    let _ = warnings.call_method1("warn", (issue, ));
                 }
-            });
+            }
         }
         
         Ok(issues)
@@ -346,67 +923,65 @@ impl Composer {
     ///           COMPOSITE leaves assumptions unresolved (conditional).
     ///           GROTH16 generates final proof for on-chain verification.
     ///     preflight: If True (default), run preflight checks before proving
+    ///     max_cycles: Optional session limit in cycles (see pyr0.prove)
+    ///     zeroize_input: If True, the copy of the input buffer made for
+    ///                    proving is zeroed once proving finishes. The
+    ///                    Composer keeps its own buffer for re-proving, and
+    ///                    copies inside RISC Zero are freed, not wiped (see
+    ///                    pyr0.prove).
+    ///     manifest: Optional GuestManifest the input must match. Checked
+    ///               even with preflight=False; every typed write must also
+    ///               line up with a declared field of the same type.
     /// 
     /// Returns:
     ///     Receipt: The generated proof (type depends on 'kind' parameter)
     /// 
     /// Raises:
-    ///     RuntimeError: If preflight checks fail or proof generation fails
+    ///     PreflightError: If preflight checks fail or the input doesn't
+    ///                     match the manifest
+    ///     CompositionError: If proving fails on a claim mismatch, or an
+    ///                       assumption was added with assume_claim()
+    ///     ProvingError: If proof generation fails for any other reason
+    ///     SessionLimitExceeded: If the guest runs past max_cycles
     /// 
     /// Example:
     ///     receipt = comp.prove()  # defaults to SUCCINCT
     ///     receipt = comp.prove(kind=ReceiptKind.COMPOSITE)
-    #[pyo3(signature = (kind=None, preflight=true))]
-    pub fn prove(&self, py: Python<'_>, kind: Option<&Bound<'_, PyAny>>, preflight: bool) -> PyResult<Receipt> {
-        // Run preflight checks if requested
-        if preflight {
-            self.preflight_check(true)?;  // Will raise on issues
+    #[pyo3(signature = (kind=None, preflight=true, max_cycles=None, zeroize_input=false, manifest=None))]
+    pub fn prove(
+        &self,
+        py: Python<'_>,
+        kind: Option<&Bound<'_, PyAny>>,
+        preflight: bool,
+        max_cycles: Option<u64>,
+        zeroize_input: bool,
+        manifest: Option<PyRef<GuestManifest>>,
+    ) -> PyResult<Receipt> {
+        if !self.claim_assumptions.is_empty() {
+            return Err(errors::composition_error(format!(
+                "{} assumption(s) were added with assume_claim() and have no receipt; \
+                 use prove_conditional() and resolve them later",
+                self.claim_assumptions.len()
+            )));
         }
         
-        // Build ExecutorEnv
-        let mut builder = ExecutorEnv::builder();
-        
-        // Add assumptions
-        for assumption in &self.assumptions {
-            builder.add_assumption(assumption.clone());
-        }
-        
-        // Add input data
-        let input_data = self.input_builder.build();
-        if !input_data.is_empty() {
-            builder.write_slice(&input_data);
+        // Run preflight checks if requested
+        if preflight {
+            self.preflight_check(py, true, false, None)?;  // Will raise on issues
         }
         
-        let env = builder.build()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to build environment: {}", e)))?;
-        
         // Get the image
         let image = self.image.borrow(py);
+        let elf = image.get_elf();
+        
+        if let Some(manifest) = &manifest {
+            let input = self.input_builder.build();
+            manifest.check_input(py, &input, Some(self.input_builder.fields()), Some(&image.id()?))?;
+        }
         
         // Determine proof kind (default to SUCCINCT)
         use crate::receipt::ReceiptKind;
-        let proof_kind = if let Some(k) = kind {
-            // Try to extract ReceiptKind enum
-            if let Ok(receipt_kind) = k.extract::<ReceiptKind>() {
-                receipt_kind
-            } else if let Ok(kind_str) = k.extract::<String>() {
-                // Fallback to string for compatibility
-                match kind_str.to_lowercase().as_str() {
-                    "composite" => ReceiptKind::Composite,
-                    "succinct" => ReceiptKind::Succinct,
-                    "groth16" => ReceiptKind::Groth16,
-                    _ => return Err(PyErr::new::<PyValueError, _>(
-                        format!("Invalid proof kind '{}'. Use ReceiptKind.SUCCINCT, ReceiptKind.COMPOSITE, or ReceiptKind.GROTH16", kind_str)
-                    )),
-                }
-            } else {
-                return Err(PyErr::new::<PyValueError, _>(
-                    "kind must be a ReceiptKind enum value or string"
-                ));
-            }
-        } else {
-            ReceiptKind::Succinct  // Default
-        };
+        let proof_kind = parse_kind(kind)?;
         
         // Choose prover options based on kind
         let opts = match proof_kind {
@@ -418,43 +993,502 @@ impl Composer {
             )),
         };
         
-        // Generate proof
-        let receipt = risc0_zkvm::default_prover()
-            .prove_with_opts(env, image.get_elf(), &opts)
-            .map_err(|e| {
-                // Try to provide better error messages for composition failures
-                if e.to_string().contains("assumption") || e.to_string().contains("verify") {
-                    PyErr::new::<PyRuntimeError, _>(format!(
-                        "Proof generation failed - likely claim mismatch:\n{}\n\
-                         Check that env::verify() calls match the assumptions provided.",
-                        e
-                    ))
-                } else {
-                    PyErr::new::<PyRuntimeError, _>(format!("Proof generation failed: {}", e))
-                }
-            })?
-            .receipt;
+        let assumptions = &self.assumptions;
+        let shared_assumptions = &self.shared_assumptions;
+        let mut input_data = self.input_builder.build();
         
+        // Release the GIL for the duration of proving. ExecutorEnv isn't Send,
+        // so it's built inside the closure.
+        let receipt = crate::reservation::allow_threads(py, || -> anyhow::Result<_> {
+            let mut builder = ExecutorEnv::builder();
+            
+            // Add assumptions
+            for assumption in assumptions {
+                builder.add_assumption(assumption.clone());
+            }
+            
+            // Shared subtrees are compressed at most once across all Composers
+            for shared in shared_assumptions {
+                builder.add_assumption(shared.get().resolved_receipt()?);
+            }
+            
+            // The guest reads the input in place, so input_data is the only copy
+            builder.stdin(input_data.as_slice());
+            
+            let env = builder.session_limit(max_cycles).build()
+                .map_err(|e| anyhow::anyhow!("Failed to build environment: {}", e))?;
+            
+            // Generate proof
+            Ok(risc0_zkvm::default_prover().prove_with_opts(env, elf, &opts)?.receipt)
+        });
+        if zeroize_input {
+            input_data.zeroize();
+        }
+        let receipt = receipt.map_err(|e| self.composition_failure(py, e, max_cycles, "Proof generation"))?;
+        
+        let mismatches = self.journal_mismatches(py, &receipt.journal.bytes)?;
+        if !mismatches.is_empty() {
+            let journal = receipt.journal.bytes.clone();
+            return Err(errors::journal_assertion_error(py, mismatches, journal, Some(Receipt::from_risc0(receipt))));
+        }
         Ok(Receipt::from_risc0(receipt))
     }
     
+    /// Execute the composition without proving
+    /// 
+    /// Runs the guest with the same input and assumptions prove() would use,
+    /// so input layout mistakes and env::verify() mismatches show up in
+    /// seconds instead of after a full proof. Shared assumptions are passed
+    /// by claim, so nothing is compressed.
+    /// 
+    /// Args:
+    ///     preflight: If True (default), run preflight checks first
+    ///     max_cycles: Optional session limit in cycles (see pyr0.prove)
+    ///     capture_output: If True, collect guest stdout/stderr into the
+    ///                     SessionInfo (see pyr0.dry_run)
+    /// 
+    /// Returns:
+    ///     SessionInfo: Journal, cycle counts and exit status of the run
+    /// 
+    /// Raises:
+    ///     PreflightError: If preflight checks fail
+    ///     CompositionError: If execution fails on a claim mismatch
+    ///     ProvingError: If execution fails for any other reason
+    ///     SessionLimitExceeded: If the guest runs past max_cycles
+    /// 
+    /// Example:
+    ///     info = comp.dry_run()
+    ///     print(info.total_cycles, info.journal)
+    ///     receipt = comp.prove()
+    #[pyo3(signature = (preflight=true, max_cycles=None, capture_output=false))]
+    pub fn dry_run(
+        &self,
+        py: Python<'_>,
+        preflight: bool,
+        max_cycles: Option<u64>,
+        capture_output: bool,
+    ) -> PyResult<SessionInfo> {
+        if preflight {
+            self.preflight_check(py, true, false, None)?;
+        }
+        
+        let memory_image = self.image.borrow(py).get_image();
+        let assumptions = &self.assumptions;
+        let shared_claims = self.shared_assumptions
+            .iter()
+            .map(|s| unconditional_claim_digest(&s.get().source))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
+        let claim_assumptions = &self.claim_assumptions;
+        let input_data = self.input_builder.build();
+        
+        let info = py.allow_threads(|| -> anyhow::Result<SessionInfo> {
+            let mut stdout = Vec::new();
+            let mut stderr = Vec::new();
+            let info = {
+                let mut builder = ExecutorEnv::builder();
+                for assumption in assumptions {
+                    builder.add_assumption(assumption.clone());
+                }
+                // The executor only checks claims, so an unresolved assumption will do
+                for claim in shared_claims {
+                    builder.add_assumption(risc0_zkvm::Assumption { claim, control_root: Digest::ZERO });
+                }
+                for &(image_id, journal_digest) in claim_assumptions {
+                    builder.add_assumption(claim_only_assumption(image_id, journal_digest));
+                }
+                if !input_data.is_empty() {
+                    builder.write_slice(&input_data);
+                }
+                if capture_output {
+                    builder.stdout(&mut stdout).stderr(&mut stderr);
+                }
+                let env = builder.session_limit(max_cycles).build()?;
+                
+                let session = risc0_zkvm::ExecutorImpl::new(env, memory_image)?.run()?;
+                SessionInfo::new(&session)?
+            };
+            Ok(if capture_output { info.with_output(stdout, stderr) } else { info })
+        })
+            .map_err(|e| self.composition_failure(py, e, max_cycles, "Execution"))?;
+        
+        let journal = info.journal()?;
+        let mismatches = self.journal_mismatches(py, &journal)?;
+        if !mismatches.is_empty() {
+            return Err(errors::journal_assertion_error(py, mismatches, journal, None));
+        }
+        Ok(info)
+    }
+    
+    /// Prove the composition while leaving its assumptions unresolved
+    /// 
+    /// For Composers with assume_claim() assumptions, whose receipts aren't
+    /// available yet. Every segment is proven and joined into one
+    /// conditional SuccinctReceipt. Its claim still carries ALL of the
+    /// guest's env::verify() assumptions, including those added with
+    /// assume() or assume_shared(). Discharge them with pyr0.resolve(), one
+    /// SUCCINCT receipt per assumption in the order the guest verified them,
+    /// then call into_receipt() with the returned journal.
+    /// 
+    /// Args:
+    ///     preflight: If True (default), run preflight checks first
+    ///     max_cycles: Optional session limit in cycles (see pyr0.prove)
+    /// 
+    /// Returns:
+    ///     Tuple[SuccinctReceipt, bytes]: The conditional receipt and the journal
+    /// 
+    /// Raises:
+    ///     PreflightError: If preflight checks fail
+    ///     CompositionError: If execution fails on a claim mismatch
+    ///     ProvingError: If proving fails for any other reason
+    ///     SessionLimitExceeded: If the guest runs past max_cycles
+    /// 
+    /// Example:
+    ///     comp.assume_claim(inner_image.id, inner_journal_digest)
+    ///     conditional, journal = comp.prove_conditional()
+    ///     # later, once the inner SUCCINCT receipt exists:
+    ///     receipt = pyr0.resolve(conditional, inner_receipt).into_receipt(journal)
+    #[pyo3(signature = (preflight=true, max_cycles=None))]
+    pub fn prove_conditional(
+        &self,
+        py: Python<'_>,
+        preflight: bool,
+        max_cycles: Option<u64>,
+    ) -> PyResult<(SuccinctReceipt, Vec<u8>)> {
+        if preflight {
+            self.preflight_check(py, true, false, None)?;
+        }
+        
+        let memory_image = self.image.borrow(py).get_image();
+        let shared = self.shared_assumptions.iter().map(|s| &s.get().source);
+        let mut claims = self.assumptions
+            .iter()
+            .chain(shared)
+            .map(unconditional_claim_digest)
+            .map(|claim| claim.map(|claim| risc0_zkvm::Assumption { claim, control_root: Digest::ZERO }))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
+        claims.extend(self.claim_assumptions.iter().map(|&(id, journal)| claim_only_assumption(id, journal)));
+        let input_data = self.input_builder.build();
+        
+        let (conditional, journal) = crate::reservation::allow_threads(py, || -> anyhow::Result<_> {
+            let mut builder = ExecutorEnv::builder();
+            for claim in claims {
+                builder.add_assumption(claim);
+            }
+            if !input_data.is_empty() {
+                builder.write_slice(&input_data);
+            }
+            let env = builder.session_limit(max_cycles).build()?;
+            
+            let mut segments = Vec::new();
+            let session = risc0_zkvm::ExecutorImpl::new(env, memory_image)?.run_with_callback(|segment| {
+                segments.push(segment);
+                Ok(Box::new(NullSegmentRef {}))
+            })?;
+            let journal = session.journal.map(|journal| journal.bytes).unwrap_or_default();
+            Ok((SuccinctReceipt::prove_segments(&segments)?, journal))
+        })
+            .map_err(|e| self.composition_failure(py, e, max_cycles, "Conditional proof generation"))?;
+        
+        let mismatches = self.journal_mismatches(py, &journal)?;
+        if !mismatches.is_empty() {
+            return Err(errors::journal_assertion_error(py, mismatches, journal, None));
+        }
+        Ok((conditional, journal))
+    }
+    
+    /// Awaitable version of prove() for asyncio code
+    /// 
+    /// Runs prove() on the event loop's default executor with the GIL released.
+    /// Don't modify this Composer until the future resolves - it stays borrowed
+    /// while proving. Takes the same arguments as prove().
+    /// 
+    /// Returns:
+    ///     asyncio.Future[Receipt]: Resolves to the generated proof
+    /// 
+    /// Example:
+    ///     receipt = await comp.prove_async(kind="succinct")
+    #[pyo3(signature = (kind=None, preflight=true, max_cycles=None, zeroize_input=false, manifest=None))]
+    pub fn prove_async<'py>(
+        slf: &Bound<'py, Self>,
+        kind: Option<&Bound<'py, PyAny>>,
+        preflight: bool,
+        max_cycles: Option<u64>,
+        zeroize_input: bool,
+        manifest: Option<Py<GuestManifest>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("kind", kind)?;
+        kwargs.set_item("preflight", preflight)?;
+        kwargs.set_item("max_cycles", max_cycles)?;
+        kwargs.set_item("zeroize_input", zeroize_input)?;
+        kwargs.set_item("manifest", manifest)?;
+        crate::futures::run_in_executor(&slf.getattr("prove")?, &PyTuple::empty(py), Some(&kwargs))
+    }
+    
+    /// Stable fingerprint of this composition request
+    /// 
+    /// SHA-256 over (image ID, input bytes, assumption claim digests, kind).
+    /// Assumption digests are sorted, so the order in which receipts were
+    /// assumed does not change the fingerprint. Two Composers with the same
+    /// fingerprint will produce receipts for the same claim.
+    /// 
+    /// Args:
+    ///     kind: Receipt kind the request will be proved as (default: SUCCINCT)
+    /// 
+    /// Returns:
+    ///     bytes: 32-byte fingerprint, suitable as a cache or dedup key
+    #[pyo3(signature = (kind=None))]
+    pub fn fingerprint(&self, py: Python<'_>, kind: Option<&Bound<'_, PyAny>>) -> PyResult<Vec<u8>> {
+        let proof_kind = parse_kind(kind)?;
+        let image_id = self.image.borrow(py).id()?;
+        let input_data = self.input_builder.build();
+        
+        let shared = self.shared_assumptions.iter().map(|s| &s.get().source);
+        let mut claim_digests = self.assumptions
+            .iter()
+            .chain(shared)
+            .map(unconditional_claim_digest)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
+        claim_digests.extend(
+            self.claim_assumptions.iter().map(|&(id, journal)| claim_only_assumption(id, journal).claim)
+        );
+        claim_digests.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+        
+        let mut buf = Vec::with_capacity(
+            FINGERPRINT_DOMAIN.len() + 32 + 8 + input_data.len() + 8 + 32 * claim_digests.len() + 16
+        );
+        buf.extend_from_slice(FINGERPRINT_DOMAIN);
+        buf.extend_from_slice(&image_id);
+        buf.extend_from_slice(&(input_data.len() as u64).to_le_bytes());
+        buf.extend_from_slice(&input_data);
+        buf.extend_from_slice(&(claim_digests.len() as u64).to_le_bytes());
+        for digest in &claim_digests {
+            buf.extend_from_slice(digest.as_bytes());
+        }
+        buf.extend_from_slice(proof_kind.as_str().as_bytes());
+        
+        Ok(risc0_zkvm::sha::Impl::hash_bytes(&buf).as_bytes().to_vec())
+    }
+    
     /// Get the current size of the input data buffer
     #[getter]
     pub fn input_size(&self) -> usize {
         self.input_builder.size()
     }
     
-    /// Get the number of assumptions added
+    /// The exact bytes the guest will read from stdin (read-only copy)
+    #[getter]
+    pub fn input_bytes(&self) -> Vec<u8> {
+        self.input_builder.build()
+    }
+    
+    /// Hex dump of the input, for debugging env::read_slice() misalignment
+    /// 
+    /// One xxd-style line per `width` bytes: offset, hex, ASCII, and the
+    /// writes (field number, kind and offset, as in input_layout()) that
+    /// start on that line.
+    /// 
+    /// Example:
+    ///     >>> print(comp.input_hexdump())
+    ///     00000000  08 00 00 00 61 62 63 64 ...  |....abcd...|  <- field0 u32 @0, field1 bytes32 @4
+    /// 
+    /// Raises:
+    ///     ValueError: If width is 0
+    #[pyo3(signature = (width=16))]
+    pub fn input_hexdump(&self, width: usize) -> PyResult<String> {
+        if width == 0 {
+            return Err(PyErr::new::<PyValueError, _>("width must be at least 1"));
+        }
+        Ok(self.input_builder.hexdump(width))
+    }
+    
+    /// Describe every input write so far (see InputBuilder.layout())
+    pub fn input_layout<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.input_builder.layout(py)
+    }
+    
+    /// Generate guest code reading the input back (see InputBuilder.generate_guest_snippet())
+    pub fn generate_guest_snippet(&self) -> String {
+        self.input_builder.generate_guest_snippet()
+    }
+    
+    /// Get the number of assumptions added (including shared and claim-only ones)
     #[getter]
     pub fn assumption_count(&self) -> usize {
-        self.assumptions.len()
+        self.assumptions.len() + self.shared_assumptions.len() + self.claim_assumptions.len()
     }
     
     pub fn __repr__(&self) -> String {
         format!(
             "Composer(assumptions={}, input_size={} bytes)",
-            self.assumptions.len(),
+            self.assumption_count(),
             self.input_builder.size()
         )
     }
-}
\ No newline at end of file
+}
+
+impl Composer {
+    /// Record a write, flagging unframed CBOR mixed with anything else
+    ///
+    /// The guest reads a Pattern A input with read_to_end() and decodes it
+    /// as a single CBOR object, so any other bytes before or after it
+    /// corrupt the input without an obvious error.
+    fn track_write(&mut self, method: &'static str) -> PyResult<()> {
+        let conflict = match self.last_write {
+            Some(previous) if method == "write_cbor" || previous == "write_cbor" => Some(previous),
+            _ => None,
+        };
+        if let Some(previous) = conflict {
+            let message = format!(
+                "{}() after {}(): write_cbor() must be the only write (Pattern A). \
+                 Use write_cbor_frame() to mix CBOR with other fields (Pattern C)",
+                method, previous
+            );
+            match self.input_mixing {
+                MixingPolicy::Error => return Err(errors::input_pattern_error(message, method, previous)),
+                MixingPolicy::Warn => Python::with_gil(|py| -> PyResult<()> {
+                    py.import("warnings")?.call_method1("warn", (message,))?;
+                    Ok(())
+                })?,
+                MixingPolicy::Ignore => {}
+            }
+        }
+        self.last_write = Some(method);
+        Ok(())
+    }
+
+    /// Claim digest of every registered assumption, described for preflight issues
+    fn assumption_claims(&self) -> PyResult<Vec<(Digest, String)>> {
+        let describe = |(image_id, journal_digest): (Digest, Digest)| {
+            format!(
+                "image_id={}, journal_digest={}",
+                hex::encode(&image_id.as_bytes()[..8]),
+                hex::encode(&journal_digest.as_bytes()[..8])
+            )
+        };
+        let shared = self.shared_assumptions.iter().map(|s| &s.get().source);
+        let mut claims = Vec::new();
+        for receipt in self.assumptions.iter().chain(shared) {
+            let claim = unconditional_claim_digest(receipt)
+                .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
+            claims.push((claim, describe(dedup_key(receipt)?)));
+        }
+        for &(image_id, journal_digest) in &self.claim_assumptions {
+            let claim = claim_only_assumption(image_id, journal_digest).claim;
+            claims.push((claim, describe((image_id, journal_digest))));
+        }
+        Ok(claims)
+    }
+
+    /// Execute the guest and diff the claims it env::verify()s against the
+    /// registered assumptions and expected verifications
+    ///
+    /// Expected verifications are added to the executor by claim alongside
+    /// the assumptions, so execution runs past them and the session records
+    /// every claim the guest verified. The outer Err is a Python-side
+    /// failure; the inner one means the guest didn't run to completion.
+    fn verification_issues(&self, py: Python<'_>, max_cycles: Option<u64>) -> PyResult<anyhow::Result<Vec<String>>> {
+        let assumed = self.assumption_claims()?;
+        let expected = self.expected_verifications
+            .iter()
+            .map(|claim| {
+                let label = format!(
+                    "image_id={}, journal_digest={}, exit_code={}",
+                    hex::encode(&claim.image_id[..8]),
+                    hex::encode(&claim.journal_digest[..8]),
+                    claim.exit_code
+                );
+                Ok((claim.risc0_digest()?, label))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        
+        let memory_image = self.image.borrow(py).get_image();
+        let claims: Vec<Digest> = assumed.iter().chain(&expected).map(|(claim, _)| *claim).collect();
+        let input_data = self.input_builder.build();
+        let result = py.allow_threads(|| -> anyhow::Result<Vec<Digest>> {
+            let mut builder = ExecutorEnv::builder();
+            // The executor only checks claims, so every claim goes in by digest
+            for claim in claims {
+                builder.add_assumption(risc0_zkvm::Assumption { claim, control_root: Digest::ZERO });
+            }
+            if !input_data.is_empty() {
+                builder.write_slice(&input_data);
+            }
+            let env = builder.session_limit(max_cycles).build()?;
+            
+            let session = risc0_zkvm::ExecutorImpl::new(env, memory_image)?.run()?;
+            Ok(session.assumptions.iter().map(|(assumption, _)| assumption.claim).collect())
+        });
+        let verified = match result {
+            Ok(verified) => verified,
+            Err(e) => return Ok(Err(e)),
+        };
+        
+        let mut issues = Vec::new();
+        for (claim, label) in &assumed {
+            if !verified.contains(claim) {
+                issues.push(format!("Assumption never verified by the guest during execution: {}", label));
+            }
+        }
+        for (claim, label) in &expected {
+            if !verified.contains(claim) {
+                issues.push(format!("Expected verification did not happen during execution: {}", label));
+            } else if !assumed.iter().any(|(assumed, _)| assumed == claim) {
+                issues.push(format!(
+                    "Guest called env::verify() with no matching assumption during execution: {}",
+                    label
+                ));
+            }
+        }
+        Ok(Ok(issues))
+    }
+
+    /// verification_issues() for preflight_check(execute=True)
+    fn execution_issues(&self, py: Python<'_>, max_cycles: Option<u64>) -> PyResult<Vec<String>> {
+        match self.verification_issues(py, max_cycles)? {
+            Ok(issues) => Ok(issues),
+            Err(e) => {
+                if let Some(limit) = max_cycles.filter(|_| errors::is_session_limit(&e)) {
+                    return Err(errors::session_limit_error(py, limit));
+                }
+                // Includes a guest env::verify() of a claim nothing registered matches
+                Ok(vec![format!(
+                    "Preflight execution failed before the guest's env::verify() calls could be checked: {}",
+                    e
+                )])
+            }
+        }
+    }
+
+    /// Map an executor/prover error, singling out session limits and claim mismatches
+    ///
+    /// Anything else re-executes the guest and, if its env::verify() calls
+    /// don't line up with the assumptions, lists where they differ.
+    fn composition_failure(&self, py: Python<'_>, err: anyhow::Error, max_cycles: Option<u64>, action: &str) -> PyErr {
+        if let Some(limit) = max_cycles.filter(|_| errors::is_session_limit(&err)) {
+            return errors::session_limit_error(py, limit);
+        }
+        match self.verification_issues(py, max_cycles) {
+            Ok(Ok(issues)) if !issues.is_empty() => errors::composition_error(format!(
+                "{} failed: {}\nThe guest's env::verify() calls don't match the assumptions:\n{}",
+                action,
+                err,
+                issues.join("\n")
+            )),
+            _ => errors::proving_error(format!("{} failed: {}", action, err)),
+        }
+    }
+
+    /// Check a journal against every assert_journal() expectation
+    fn journal_mismatches(&self, py: Python<'_>, journal: &[u8]) -> PyResult<Vec<String>> {
+        let mut mismatches = Vec::new();
+        for assertion in &self.journal_assertions {
+            assertion.mismatches(py, journal, &mut mismatches)?;
+        }
+        Ok(mismatches)
+    }
+}
@@ -0,0 +1,63 @@
+use pyo3::prelude::*;
+#[cfg(feature = "unstable")]
+use pyo3::types::{PyBytes, PyDict};
+
+/// Forwards the guest's accelerator proof requests to a Python callable
+///
+/// Each request is passed as a dict. Keccak requests have "kind": "keccak",
+/// "claim_digest", "control_root", "po2" and "input" (the batched keccak
+/// states as little-endian u64s); ZKR requests have "kind": "zkr",
+/// "claim_digest", "control_id" and "input". An exception in the callback
+/// aborts execution. Each callback briefly re-acquires the GIL.
+///
+/// RISC Zero only supports coprocessor callbacks behind its `unstable`
+/// feature, so this needs PyR0 built with `--features unstable`.
+#[cfg(feature = "unstable")]
+pub(crate) struct PyCoprocessor(pub(crate) Py<PyAny>);
+
+#[cfg(feature = "unstable")]
+impl PyCoprocessor {
+    fn call(&self, build: impl FnOnce(&Bound<'_, PyDict>) -> PyResult<()>) -> anyhow::Result<()> {
+        Python::with_gil(|py| -> PyResult<()> {
+            let request = PyDict::new(py);
+            build(&request)?;
+            self.0.call1(py, (request,))?;
+            Ok(())
+        })
+        .map_err(|e| anyhow::anyhow!("coprocessor callback failed: {e}"))
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl risc0_zkvm::CoprocessorCallback for PyCoprocessor {
+    fn prove_zkr(&mut self, request: risc0_zkvm::ProveZkrRequest) -> anyhow::Result<()> {
+        self.call(|dict| {
+            let py = dict.py();
+            dict.set_item("kind", "zkr")?;
+            dict.set_item("claim_digest", PyBytes::new(py, request.claim_digest.as_bytes()))?;
+            dict.set_item("control_id", PyBytes::new(py, request.control_id.as_bytes()))?;
+            dict.set_item("input", PyBytes::new(py, &request.input))
+        })
+    }
+
+    fn prove_keccak(&mut self, request: risc0_zkvm::ProveKeccakRequest) -> anyhow::Result<()> {
+        let input: Vec<u8> = request.input.iter().flatten().flat_map(|word| word.to_le_bytes()).collect();
+        self.call(|dict| {
+            let py = dict.py();
+            dict.set_item("kind", "keccak")?;
+            dict.set_item("claim_digest", PyBytes::new(py, request.claim_digest.as_bytes()))?;
+            dict.set_item("control_root", PyBytes::new(py, request.control_root.as_bytes()))?;
+            dict.set_item("po2", request.po2)?;
+            dict.set_item("input", PyBytes::new(py, &input))
+        })
+    }
+}
+
+/// Reject a coprocessor callback on builds without RISC Zero's unstable APIs
+#[cfg(not(feature = "unstable"))]
+pub(crate) fn unsupported() -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "coprocessor callbacks need PyR0 built with the 'unstable' feature \
+         (maturin develop --features unstable)",
+    )
+}
@@ -0,0 +1,110 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::types::PyTuple;
+
+use crate::receipt::{ExitStatus, Receipt};
+
+/// Build an exception from the class hierarchy in pyr0/exceptions.py
+///
+/// The hierarchy is defined in Python so classes can carry extra attributes.
+/// If the module can't be imported (e.g. _rust used standalone), this falls
+/// back to RuntimeError with the same message.
+pub(crate) fn pyr0_error(py: Python<'_>, class: &str, message: impl Into<String>) -> PyErr {
+    let message = message.into();
+    instantiate(py, class, (message.clone(),), message)
+}
+
+/// pyr0.ProvingError: execution or proof generation failed
+pub(crate) fn proving_error(message: impl Into<String>) -> PyErr {
+    Python::with_gil(|py| pyr0_error(py, "ProvingError", message))
+}
+
+/// pyr0.ImageError: an ELF couldn't be loaded or its image ID computed
+pub(crate) fn image_error(message: impl Into<String>) -> PyErr {
+    Python::with_gil(|py| pyr0_error(py, "ImageError", message))
+}
+
+/// pyr0.CompositionError: a Composer was set up or used incorrectly
+pub(crate) fn composition_error(message: impl Into<String>) -> PyErr {
+    Python::with_gil(|py| pyr0_error(py, "CompositionError", message))
+}
+
+/// pyr0.AssumptionError: a receipt can't be used as an assumption
+pub(crate) fn assumption_error(message: impl Into<String>) -> PyErr {
+    Python::with_gil(|py| pyr0_error(py, "AssumptionError", message))
+}
+
+/// pyr0.InputPatternError naming the two write methods that were mixed
+pub(crate) fn input_pattern_error(message: impl Into<String>, method: &str, previous: &str) -> PyErr {
+    let message = message.into();
+    Python::with_gil(|py| instantiate(py, "InputPatternError", (message.clone(), method, previous), message))
+}
+
+/// pyr0.PreflightError carrying the individual issues found
+pub(crate) fn preflight_error(message: impl Into<String>, issues: Vec<String>) -> PyErr {
+    let message = message.into();
+    Python::with_gil(|py| instantiate(py, "PreflightError", (message.clone(), issues), message))
+}
+
+/// pyr0.SerializationError: bytes couldn't be encoded or decoded
+pub(crate) fn serialization_error(message: impl Into<String>) -> PyErr {
+    Python::with_gil(|py| pyr0_error(py, "SerializationError", message))
+}
+
+/// pyr0.JournalAssertionError listing each failed journal expectation
+pub(crate) fn journal_assertion_error(
+    py: Python<'_>,
+    mismatches: Vec<String>,
+    journal: Vec<u8>,
+    receipt: Option<Receipt>,
+) -> PyErr {
+    let message = format!(
+        "Journal assertion failed with {} mismatch(es):\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+    instantiate(py, "JournalAssertionError", (message.clone(), mismatches, journal, receipt), message)
+}
+
+/// Build a pyr0.VerificationError carrying a VerificationStage code
+pub(crate) fn verification_error(py: Python<'_>, code: &str, message: impl Into<String>) -> PyErr {
+    let message = message.into();
+    instantiate(py, "VerificationError", (message.clone(), code), message)
+}
+
+/// True if proving failed because the executor hit its session (cycle) limit
+///
+/// RISC Zero reports this only as an untyped error whose message starts
+/// with "Session limit exceeded", so the root cause is checked for exactly that.
+pub(crate) fn is_session_limit(err: &anyhow::Error) -> bool {
+    err.root_cause().to_string().starts_with("Session limit exceeded")
+}
+
+/// Build a pyr0.SessionLimitExceeded for a guest that ran past max_cycles
+pub(crate) fn session_limit_error(py: Python<'_>, max_cycles: u64) -> PyErr {
+    let message = format!("Guest exceeded max_cycles={max_cycles} (ExitKind.SESSION_LIMIT)");
+    let exit = ExitStatus::from_risc0(risc0_zkvm::ExitCode::SessionLimit);
+    instantiate(py, "SessionLimitExceeded", (message.clone(), max_cycles, exit), message)
+}
+
+/// Map a prover/executor error, recognising session limit hits
+pub(crate) fn prove_failure(py: Python<'_>, err: anyhow::Error, max_cycles: Option<u64>) -> PyErr {
+    match max_cycles {
+        Some(limit) if is_session_limit(&err) => session_limit_error(py, limit),
+        _ => proving_error(format!("Proof generation failed: {}", err)),
+    }
+}
+
+fn instantiate<'py, A>(py: Python<'py>, class: &str, args: A, message: String) -> PyErr
+where
+    A: IntoPyObject<'py, Target = PyTuple>,
+{
+    let instance = py
+        .import("pyr0.exceptions")
+        .and_then(|module| module.getattr(class))
+        .and_then(|cls| cls.call1(args));
+    match instance {
+        Ok(exc) => PyErr::from_value(exc),
+        Err(_) => PyErr::new::<PyRuntimeError, _>(message),
+    }
+}
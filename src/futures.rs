@@ -0,0 +1,28 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+/// Schedule a blocking call on the running asyncio loop's default executor
+///
+/// Returns the asyncio.Future from loop.run_in_executor(), so the caller gets
+/// an awaitable without any async runtime on the Rust side. The proving
+/// functions release the GIL, so several of these can run concurrently.
+///
+/// Raises RuntimeError (from asyncio) if there is no running event loop.
+pub(crate) fn run_in_executor<'py>(
+    func: &Bound<'py, PyAny>,
+    args: &Bound<'py, PyTuple>,
+    kwargs: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = func.py();
+
+    // run_in_executor() takes no kwargs, so bind everything with functools.partial
+    let mut partial_args = vec![func.clone()];
+    partial_args.extend(args.iter());
+    let job = py
+        .import("functools")?
+        .getattr("partial")?
+        .call(PyTuple::new(py, partial_args)?, kwargs)?;
+
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    event_loop.call_method1("run_in_executor", (py.None(), job))
+}
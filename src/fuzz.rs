@@ -0,0 +1,43 @@
+use pyo3::prelude::*;
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::errors;
+use crate::image::Image;
+use crate::receipt::{DeserializationPolicy, Receipt};
+
+/// Text of a caught panic payload (panic!() carries &str or String)
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// Run a parser, turning any panic inside it into the given PyR0 error
+fn no_panic<T>(
+    what: &str,
+    to_error: fn(String) -> PyErr,
+    parse: impl FnOnce() -> PyResult<T>,
+) -> PyResult<T> {
+    catch_unwind(AssertUnwindSafe(parse))
+        .unwrap_or_else(|payload| Err(to_error(format!("{what} panicked: {}", panic_message(&*payload)))))
+}
+
+/// Deserialize a receipt without panicking (see pyr0.fuzz.receipt_from_bytes)
+#[pyfunction]
+#[pyo3(name = "_fuzz_receipt_from_bytes", signature = (data, policy=None))]
+pub fn receipt_from_bytes(data: &[u8], policy: Option<DeserializationPolicy>) -> PyResult<Receipt> {
+    no_panic("Receipt deserialization", errors::serialization_error, || {
+        let inner = policy.unwrap_or_default().decode(data)?;
+        Ok(Receipt::from_risc0(inner))
+    })
+}
+
+/// Parse an ELF into an Image without panicking (see pyr0.fuzz.image_from_bytes)
+#[pyfunction]
+#[pyo3(name = "_fuzz_image_from_bytes")]
+pub fn image_from_bytes(data: &[u8]) -> PyResult<Image> {
+    no_panic("ELF parsing", errors::image_error, || crate::load_elf(data))
+}
@@ -0,0 +1,137 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyMemoryView};
+use risc0_zkvm::ExecutorEnvBuilder;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use zeroize::Zeroize;
+
+/// Guest input as accepted by prove() and dry_run()
+///
+/// - bytes objects are immutable, so the guest reads them in place (no copy)
+/// - file-like objects (anything with read(), including io.BytesIO, open
+///   files and mmap) are streamed to the guest as it reads stdin
+/// - paths (os.PathLike, e.g. pathlib.Path) are opened and streamed from
+///   Rust, so the guest can read_to_end() a multi-GB file without it ever
+///   passing through Python or sitting in memory
+/// - any other buffer (bytearray, memoryview, numpy arrays) is copied once,
+///   so later mutation can't race the prover. Borrowing it instead would
+///   need the buffer protocol, which abi3-py38's limited API lacks.
+/// - anything else raises TypeError, rather than going through bytes(),
+///   which would turn an int n into n zero bytes
+pub(crate) enum GuestInput<'a> {
+    Borrowed(&'a [u8]),
+    Owned(Vec<u8>),
+    /// A buffer copied into a bytes object only PyR0 holds
+    Copied(Py<PyBytes>),
+    Stream(PyReader),
+    File(File),
+}
+
+impl<'a> GuestInput<'a> {
+    pub(crate) fn extract(input: &'a Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(bytes) = input.downcast::<PyBytes>() {
+            return Ok(GuestInput::Borrowed(bytes.as_bytes()));
+        }
+        if let Ok(array) = input.downcast::<PyByteArray>() {
+            return Ok(GuestInput::Owned(array.to_vec()));
+        }
+        if input.hasattr("read")? {
+            return Ok(GuestInput::Stream(PyReader(input.clone().unbind())));
+        }
+        if input.hasattr("__fspath__")? {
+            let path: PathBuf = input.extract()?;
+            return Ok(GuestInput::File(File::open(path)?));
+        }
+        let view = PyMemoryView::from(input).map_err(|_| PyErr::new::<PyTypeError, _>(format!(
+            "Guest input must be bytes, a buffer, a file-like object or a path, not {}",
+            input.get_type().name()?
+        )))?;
+        // bytes() copies the buffer in one pass; the guest then reads that copy in place
+        let copy = input.py().get_type::<PyBytes>().call1((view,))?;
+        Ok(GuestInput::Copied(copy.downcast_into::<PyBytes>()?.unbind()))
+    }
+
+    /// Overwrite PyR0's copy of the input (bytearray or other buffer input)
+    /// with zeros
+    ///
+    /// For zeroize_input, once the environment from attach() is dropped.
+    /// The caller's own objects, including a borrowed bytes object, can't be
+    /// wiped from here.
+    pub(crate) fn wipe(&mut self) {
+        match self {
+            GuestInput::Owned(data) => data.zeroize(),
+            GuestInput::Copied(copy) => Python::with_gil(|py| {
+                // Only while nothing else holds it: bytes() may have
+                // returned a shared object (e.g. the empty bytes singleton)
+                if copy.get_refcnt(py) == 1 {
+                    // SAFETY: the bytes object is only referenced here, and no
+                    // slice of it outlives attach()'s environment
+                    unsafe {
+                        let data = pyo3::ffi::PyBytes_AsString(copy.as_ptr()).cast::<u8>();
+                        std::slice::from_raw_parts_mut(data, copy.as_bytes(py).len()).zeroize();
+                    }
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    /// The input bytes, unless they're streamed from a file-like object or path
+    pub(crate) fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            GuestInput::Borrowed(data) => Some(data),
+            GuestInput::Owned(data) => Some(data),
+            GuestInput::Copied(copy) => Some(Python::with_gil(|py| copy.as_bytes(py))),
+            GuestInput::Stream(_) | GuestInput::File(_) => None,
+        }
+    }
+
+    /// Connect the input to the guest's stdin
+    ///
+    /// Every kind of input is read in place, so the builder holds no copy of
+    /// its own and wipe() afterwards clears the only one PyR0 made.
+    pub(crate) fn attach<'b>(&'b mut self, builder: &mut ExecutorEnvBuilder<'b>)
+    where
+        'a: 'b,
+    {
+        match self {
+            GuestInput::Borrowed(data) => {
+                builder.stdin(*data);
+            }
+            GuestInput::Owned(data) => {
+                builder.stdin(data.as_slice());
+            }
+            GuestInput::Copied(copy) => {
+                builder.stdin(Python::with_gil(|py| copy.as_bytes(py)));
+            }
+            GuestInput::Stream(reader) => {
+                builder.stdin(reader);
+            }
+            GuestInput::File(file) => {
+                builder.stdin(&*file);
+            }
+        }
+    }
+}
+
+/// std::io::Read over a Python file-like object
+///
+/// Each read() briefly re-acquires the GIL, so proving can still run with
+/// the GIL released.
+pub(crate) struct PyReader(pub(crate) Py<PyAny>);
+
+impl Read for PyReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Python::with_gil(|py| {
+            let chunk = self.0
+                .call_method1(py, "read", (buf.len(),))
+                .and_then(|chunk| chunk.extract::<Vec<u8>>(py))
+                .map_err(|e| std::io::Error::other(format!("Reading guest input failed: {e}")))?;
+            let n = chunk.len().min(buf.len());
+            buf[..n].copy_from_slice(&chunk[..n]);
+            Ok(n)
+        })
+    }
+}
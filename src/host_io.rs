@@ -0,0 +1,184 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyBytes;
+use risc0_zkvm::{Bytes, ExecutorEnvBuilder};
+use std::io::{BufReader, Write};
+
+use crate::checkpoint::CHECKPOINT_FD;
+use crate::guest_input::PyReader;
+
+/// File descriptors the zkVM itself uses (stdin, stdout, stderr, journal)
+const RESERVED_FDS: [u32; 4] = [0, 1, 2, 3];
+
+/// Where a guest read fd gets its data
+enum ReadSource {
+    Bytes(Vec<u8>),
+    Stream(Py<PyAny>),
+}
+
+/// Host calls a guest can make while it runs
+///
+/// Registers Python handlers the guest reaches through RISC Zero's host I/O
+/// syscalls, so it can e.g. fetch a value by key mid-execution:
+///
+/// - channel(name, handler): handler(request: bytes) -> bytes answers each
+///   env::send_recv_slice() on that channel. Guests name channels with
+///   risc0_zkvm_platform::declare_syscall!, which prefixes the module path,
+///   so `declare_syscall!(pub KV_GET)` in the guest's crate root is
+///   channel "<crate name>::KV_GET".
+/// - read_fd(fd, source): the guest's reads of fd (env::FdReader) come from
+///   source, bytes or a file-like object with read()
+/// - write_fd(fd, sink): whatever the guest writes to fd (env::FdWriter) is
+///   passed to sink, a callable taking bytes or an object with write()
+///
+/// Pass it as host_io= to prove(), prove_with_opts() or dry_run(). Each
+/// handler call briefly re-acquires the GIL; an exception aborts execution.
+/// Anything the guest learns this way is not part of the proof's input
+/// commitments unless the guest commits it, so a verifier must trust the
+/// handlers' answers only as far as the guest checks them.
+///
+/// Example:
+///     io = pyr0.HostIO().channel("kv_guest::KV_GET", lambda key: store[key])
+///     receipt = pyr0.prove(image, data, host_io=io)
+#[pyclass(module = "pyr0")]
+#[derive(Default)]
+pub struct HostIO {
+    channels: Vec<(String, Py<PyAny>)>,
+    reads: Vec<(u32, ReadSource)>,
+    writes: Vec<(u32, Py<PyAny>)>,
+}
+
+#[pymethods]
+impl HostIO {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer the guest's send_recv_slice() calls on a named channel
+    ///
+    /// Raises:
+    ///     ValueError: If the channel is already registered or handler isn't callable
+    pub fn channel(mut slf: PyRefMut<'_, Self>, name: String, handler: Bound<'_, PyAny>) -> PyResult<PyRefMut<'_, Self>> {
+        if !handler.is_callable() {
+            return Err(PyErr::new::<PyValueError, _>(format!("Handler for channel '{name}' must be callable")));
+        }
+        if slf.channels.iter().any(|(existing, _)| *existing == name) {
+            return Err(PyErr::new::<PyValueError, _>(format!("Channel '{name}' is already registered")));
+        }
+        slf.channels.push((name, handler.unbind()));
+        Ok(slf)
+    }
+
+    /// Feed a guest read fd from bytes or a file-like object
+    ///
+    /// bytes are replayed from the start on every run; a file-like object
+    /// is read as the guest reads, so it's consumed by the first run.
+    ///
+    /// Raises:
+    ///     ValueError: If fd is reserved or already registered
+    pub fn read_fd(mut slf: PyRefMut<'_, Self>, fd: u32, source: Bound<'_, PyAny>) -> PyResult<PyRefMut<'_, Self>> {
+        slf.check_fd(fd)?;
+        let source = if source.hasattr("read")? {
+            ReadSource::Stream(source.unbind())
+        } else {
+            ReadSource::Bytes(source.extract()?)
+        };
+        slf.reads.push((fd, source));
+        Ok(slf)
+    }
+
+    /// Pass everything the guest writes to fd to a callable or writable object
+    ///
+    /// Raises:
+    ///     ValueError: If fd is reserved or already registered, or sink is
+    ///                 neither callable nor has write()
+    pub fn write_fd(mut slf: PyRefMut<'_, Self>, fd: u32, sink: Bound<'_, PyAny>) -> PyResult<PyRefMut<'_, Self>> {
+        slf.check_fd(fd)?;
+        let sink = if sink.hasattr("write")? {
+            sink.getattr("write")?
+        } else if sink.is_callable() {
+            sink
+        } else {
+            return Err(PyErr::new::<PyValueError, _>(format!("Sink for fd {fd} must be callable or have write()")));
+        };
+        slf.writes.push((fd, sink.unbind()));
+        Ok(slf)
+    }
+
+    /// Names of the registered channels
+    #[getter]
+    pub fn channels(&self) -> Vec<String> {
+        self.channels.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    pub fn __repr__(&self) -> String {
+        let fds = |list: Vec<u32>| format!("{list:?}");
+        format!(
+            "HostIO(channels={:?}, read_fds={}, write_fds={})",
+            self.channels(),
+            fds(self.reads.iter().map(|(fd, _)| *fd).collect()),
+            fds(self.writes.iter().map(|(fd, _)| *fd).collect())
+        )
+    }
+}
+
+impl HostIO {
+    fn check_fd(&self, fd: u32) -> PyResult<()> {
+        if RESERVED_FDS.contains(&fd) || fd == CHECKPOINT_FD {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "fd {fd} is reserved (0-3 are stdin, stdout, stderr and the journal; {CHECKPOINT_FD} is CHECKPOINT_FD)"
+            )));
+        }
+        let mut taken = self.reads.iter().map(|(fd, _)| fd).chain(self.writes.iter().map(|(fd, _)| fd));
+        if taken.any(|&existing| existing == fd) {
+            return Err(PyErr::new::<PyValueError, _>(format!("fd {fd} is already registered")));
+        }
+        Ok(())
+    }
+
+    /// Install the handlers on an executor environment
+    ///
+    /// Called with the GIL released; the handlers re-acquire it per call.
+    pub(crate) fn attach<'a>(&'a self, builder: &mut ExecutorEnvBuilder<'a>) {
+        for (name, handler) in &self.channels {
+            builder.io_callback(name, move |request: Bytes| -> anyhow::Result<Bytes> {
+                Python::with_gil(|py| -> PyResult<Vec<u8>> {
+                    handler.call1(py, (PyBytes::new(py, &request),))?.extract(py)
+                })
+                .map(Bytes::from)
+                .map_err(|e| anyhow::anyhow!("HostIO channel '{name}' failed: {e}"))
+            });
+        }
+        for (fd, source) in &self.reads {
+            match source {
+                ReadSource::Bytes(data) => builder.read_fd(*fd, data.as_slice()),
+                ReadSource::Stream(reader) => {
+                    let reader = Python::with_gil(|py| reader.clone_ref(py));
+                    builder.read_fd(*fd, BufReader::new(PyReader(reader)))
+                }
+            };
+        }
+        for (fd, sink) in &self.writes {
+            builder.write_fd(*fd, PyWriter { fd: *fd, sink });
+        }
+    }
+}
+
+/// std::io::Write that hands each write to a Python callable
+struct PyWriter<'a> {
+    fd: u32,
+    sink: &'a Py<PyAny>,
+}
+
+impl Write for PyWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Python::with_gil(|py| self.sink.call1(py, (PyBytes::new(py, buf),)).map(drop))
+            .map_err(|e| std::io::Error::other(format!("HostIO sink for fd {} failed: {e}", self.fd)))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -32,6 +32,122 @@ impl Image {
     pub fn get_elf(&self) -> &[u8] {
         &self.elf_bytes
     }
+
+    /// End address of the highest PT_LOAD segment in the ELF (code, data, bss)
+    ///
+    /// The guest heap starts above this and may grow up to GUEST_MAX_MEM.
+    fn program_end(&self) -> Result<u64> {
+        let elf = elf32(&self.elf_bytes)?;
+        let phoff = u32_at(elf, 0x1C)? as usize;
+        let phentsize = u16_at(elf, 0x2A)? as usize;
+        let phnum = u16_at(elf, 0x2C)? as usize;
+
+        let mut end = 0u64;
+        for i in 0..phnum {
+            let ph = phoff + i * phentsize;
+            const PT_LOAD: u32 = 1;
+            if u32_at(elf, ph)? == PT_LOAD {
+                let vaddr = u32_at(elf, ph + 0x08)? as u64;
+                let memsz = u32_at(elf, ph + 0x14)? as u64;
+                end = end.max(vaddr + memsz);
+            }
+        }
+        Ok(end)
+    }
+
+    /// Function and data symbols from the ELF's .symtab, sorted by address
+    ///
+    /// Names are demangled without the trailing hash. Stripped ELFs have no
+    /// .symtab and yield an empty list.
+    fn symbols(&self) -> Result<Vec<Symbol>> {
+        let elf = elf32(&self.elf_bytes)?;
+        let shoff = u32_at(elf, 0x20)? as usize;
+        let shentsize = u16_at(elf, 0x2E)? as usize;
+        let shnum = u16_at(elf, 0x30)? as usize;
+        let section = |i: usize| shoff + i * shentsize;
+
+        let mut symbols = Vec::new();
+        for i in 0..shnum {
+            const SHT_SYMTAB: u32 = 2;
+            let sh = section(i);
+            if u32_at(elf, sh + 0x04)? != SHT_SYMTAB {
+                continue;
+            }
+            let (offset, size) = (u32_at(elf, sh + 0x10)? as usize, u32_at(elf, sh + 0x14)? as usize);
+            let strtab = section(u32_at(elf, sh + 0x18)? as usize);
+            let (str_offset, str_size) = (u32_at(elf, strtab + 0x10)? as usize, u32_at(elf, strtab + 0x14)? as usize);
+            let strings = elf.get(str_offset..str_offset + str_size)
+                .ok_or_else(|| anyhow::anyhow!("Truncated string table"))?;
+
+            // Elf32_Sym: name, value, size (u32 each), info, other, shndx
+            for sym in (offset..offset + size).step_by(16) {
+                const STT_OBJECT: u8 = 1;
+                const STT_FUNC: u8 = 2;
+                let info = *elf.get(sym + 12).ok_or_else(|| anyhow::anyhow!("Truncated symbol table"))?;
+                if !matches!(info & 0xF, STT_OBJECT | STT_FUNC) {
+                    continue;
+                }
+                let name_off = u32_at(elf, sym)? as usize;
+                let Some(raw) = strings.get(name_off..).and_then(|s| s.split(|&b| b == 0).next()) else {
+                    continue;
+                };
+                symbols.push(Symbol {
+                    address: u32_at(elf, sym + 4)?,
+                    size: u32_at(elf, sym + 8)?,
+                    name: format!("{:#}", rustc_demangle::demangle(&String::from_utf8_lossy(raw))),
+                });
+            }
+        }
+        symbols.sort_by_key(|s| s.address);
+        Ok(symbols)
+    }
+}
+
+/// An ELF symbol covering [address, address + size)
+struct Symbol {
+    address: u32,
+    size: u32,
+    name: String,
+}
+
+/// Name of the symbol containing `address`, if any
+fn lookup(symbols: &[Symbol], address: u32) -> Option<&str> {
+    let candidates = &symbols[..symbols.partition_point(|s| s.address <= address)];
+    // Later symbols can be zero-sized labels; take the closest one that covers the address
+    candidates
+        .iter()
+        .rev()
+        .find(|s| address < s.address.saturating_add(s.size.max(1)))
+        .map(|s| s.name.as_str())
+}
+
+/// Image ID of a memory image, recomputed from its page contents
+///
+/// The root hash only covers the page table, so every page is first checked
+/// against the digest the page table holds for it.
+fn recompute_id(image: &MemoryImage) -> Result<Digest> {
+    for &page_idx in image.pages.keys() {
+        image.check(image.info.get_page_addr(page_idx))?;
+    }
+    image.compute_id()
+}
+
+/// Check for a 32-bit little-endian ELF, as produced for riscv32im guests
+fn elf32(elf: &[u8]) -> Result<&[u8]> {
+    if elf.get(0..6) != Some(&[0x7f, b'E', b'L', b'F', 1, 1][..]) {
+        anyhow::bail!("Not a 32-bit little-endian ELF");
+    }
+    Ok(elf)
+}
+
+fn u16_at(elf: &[u8], off: usize) -> Result<u16> {
+    let bytes = elf.get(off..off + 2).ok_or_else(|| anyhow::anyhow!("Truncated ELF header"))?;
+    Ok(u16::from_le_bytes(bytes.try_into()?))
+}
+
+fn u32_at(elf: &[u8], off: usize) -> Result<u32> {
+    let bytes = elf.get(off..off + 4).ok_or_else(|| anyhow::anyhow!("Truncated ELF header"))?;
+    Ok(u32::from_le_bytes(bytes.try_into()?))
 }
 
 
@@ -67,5 +183,146 @@ impl Image {
             ))
         }
     }
+    
+    /// Size of the ELF this image was loaded from, in bytes
+    #[getter]
+    pub fn elf_size(&self) -> usize {
+        self.elf_bytes.len()
+    }
+    
+    /// Entry point address (e_entry) from the ELF header
+    /// 
+    /// Raises:
+    ///     ValueError: If the image has no parseable ELF
+    #[getter]
+    pub fn entry_point(&self) -> PyResult<u32> {
+        elf32(&self.elf_bytes)
+            .and_then(|elf| u32_at(elf, 0x18))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to read ELF header: {}", e)))
+    }
+    
+    /// Load an image from an ELF file (same as pyr0.load_image_from_path())
+    /// 
+    /// Raises:
+    ///     ImageError: If the file can't be opened or isn't a valid guest ELF
+    #[staticmethod]
+    pub fn from_file(path: std::path::PathBuf) -> PyResult<Self> {
+        crate::load_elf_file(&path)
+    }
+    
+    /// Check whether the guest's static footprint plus an estimate fits in guest memory
+    /// 
+    /// The ELF's loaded segments (code, data, bss) are fixed; the heap grows
+    /// from their end up to GUEST_MAX_MEM. Use this to budget inputs the guest
+    /// will read into memory before running a long proof.
+    /// 
+    /// Args:
+    ///     estimate: Additional bytes the guest is expected to allocate at runtime
+    /// 
+    /// Returns:
+    ///     bool: True if program_end + estimate <= GUEST_MAX_MEM
+    /// 
+    /// Raises:
+    ///     ValueError: If the image has no parseable ELF
+    pub fn fits_memory(&self, estimate: u64) -> PyResult<bool> {
+        let end = self.program_end()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to read ELF segments: {}", e)))?;
+        Ok(end.saturating_add(estimate) <= GUEST_MAX_MEM as u64)
+    }
+
+    /// Serialize the loaded image, including its parsed memory image
+    /// 
+    /// Image.from_bytes() restores it, checking both the memory image and
+    /// the ELF against the stored image ID.
+    /// 
+    /// Raises:
+    ///     ValueError: If the image wasn't loaded from an ELF
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        if self.memory_image.is_none() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Image has no ELF loaded"));
+        }
+        bincode::serialize(self)
+            .map_err(|e| crate::errors::serialization_error(format!("Failed to serialize image: {e}")))
+    }
+    
+    /// Restore an image saved with to_bytes()
+    /// 
+    /// The image ID is recomputed from both the restored memory image (what
+    /// dry_run() executes) and the ELF (what prove() proves), and each must
+    /// match the one stored with them, so a blob with either swapped out is
+    /// rejected. That hashes the program twice, so restoring is no faster
+    /// than loading the ELF; what it saves is reading the ELF from its source.
+    /// 
+    /// Raises:
+    ///     SerializationError: If the data is malformed, or its memory image
+    ///                         or ELF doesn't hash to its stored image ID
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        use bincode::Options;
+        
+        let image: Image = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(data.len() as u64)
+            .deserialize(data)
+            .map_err(|e| crate::errors::serialization_error(format!("Failed to deserialize image: {e}")))?;
+        let (Some(memory_image), Some(image_id)) = (&image.memory_image, image.image_id) else {
+            return Err(crate::errors::serialization_error("Serialized image has no ELF loaded"));
+        };
+        let actual = recompute_id(memory_image)
+            .map_err(|e| crate::errors::serialization_error(format!("Serialized image is corrupt: {e}")))?;
+        if actual != image_id {
+            return Err(crate::errors::serialization_error(format!(
+                "Serialized image claims ID {} but its memory image hashes to {}",
+                hex::encode(image_id.as_bytes()),
+                hex::encode(actual.as_bytes())
+            )));
+        }
+        let elf_id = risc0_binfmt::compute_image_id(&image.elf_bytes)
+            .map_err(|e| crate::errors::serialization_error(format!("Serialized image has a corrupt ELF: {e}")))?;
+        if elf_id != image_id {
+            return Err(crate::errors::serialization_error(format!(
+                "Serialized image claims ID {} but its ELF hashes to {}",
+                hex::encode(image_id.as_bytes()),
+                hex::encode(elf_id.as_bytes())
+            )));
+        }
+        Ok(image)
+    }
+    
+    /// Name of the guest function (or static) containing an address
+    /// 
+    /// Turns raw addresses from diagnostics, such as a faulting PC, into
+    /// names like "my_guest::verify_merkle_path". Names come from the ELF
+    /// symbol table, so code inlined into a caller reports the caller.
+    /// 
+    /// Args:
+    ///     address: Guest virtual address
+    /// 
+    /// Returns:
+    ///     Optional[str]: Demangled symbol name, or None if no symbol covers
+    ///     the address (or the ELF is stripped)
+    /// 
+    /// Raises:
+    ///     ValueError: If the image has no parseable ELF
+    /// 
+    /// Example:
+    ///     image.symbolize(0x0020_1a4c)  # 'my_guest::verify_merkle_path'
+    pub fn symbolize(&self, address: u32) -> PyResult<Option<String>> {
+        Ok(self.symbolize_many(vec![address])?.remove(0))
+    }
+
+    /// symbolize() for many addresses, reading the symbol table once
+    /// 
+    /// Args:
+    ///     addresses: Guest virtual addresses
+    /// 
+    /// Returns:
+    ///     List[Optional[str]]: One name (or None) per address, in order
+    pub fn symbolize_many(&self, addresses: Vec<u32>) -> PyResult<Vec<Option<String>>> {
+        let symbols = self.symbols()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to read ELF symbols: {}", e)))?;
+        Ok(addresses.into_iter().map(|a| lookup(&symbols, a).map(str::to_string)).collect())
+    }
 
 }
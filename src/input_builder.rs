@@ -1,5 +1,64 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
+use pyo3::types::PyDict;
+use zeroize::Zeroize;
+
+use crate::codec::{self, WireType};
+
+/// One write recorded by InputBuilder (see InputBuilder.layout())
+#[derive(Clone, Debug)]
+pub(crate) struct Field {
+    /// What wrote it: "u32", "frame", "serde", "raw", ...
+    pub(crate) kind: &'static str,
+    /// Rust type the guest ends up with
+    pub(crate) rust_type: String,
+    pub(crate) offset: usize,
+    pub(crate) size: usize,
+}
+
+impl Field {
+    /// Guest code reading this field into a variable called `name`
+    fn guest_read(&self, name: &str) -> String {
+        match self.kind {
+            "cbor" => format!(
+                "let mut {name} = Vec::new();\n\
+                 env::stdin().read_to_end(&mut {name}).unwrap();  // needs std::io::Read\n\
+                 // decode with minicbor::decode(&{name})\n"
+            ),
+            "frame" | "cbor_frame" | "str_frame" => {
+                let mut code = format!(
+                    "let mut {name}_len = [0u8; 8];\n\
+                     env::read_slice(&mut {name}_len);\n\
+                     let mut {name} = vec![0u8; u64::from_le_bytes({name}_len) as usize];\n\
+                     env::read_slice(&mut {name});\n"
+                );
+                if self.kind == "str_frame" {
+                    code += &format!("let {name} = String::from_utf8({name}).unwrap();\n");
+                } else if self.kind == "cbor_frame" {
+                    code += &format!("// decode with minicbor::decode(&{name})\n");
+                }
+                code
+            }
+            "serde" => format!("let {name}: {} = env::read();\n", self.rust_type),
+            "bool" => format!(
+                "let mut {name} = [0u8; 1];\n\
+                 env::read_slice(&mut {name});\n\
+                 let {name} = {name}[0] != 0;\n"
+            ),
+            "bytes32" | "raw" => format!(
+                "let mut {name} = [0u8; {}];\n\
+                 env::read_slice(&mut {name});\n",
+                self.size
+            ),
+            _ => format!(
+                "let mut {name} = [0u8; {}];\n\
+                 env::read_slice(&mut {name});\n\
+                 let {name} = {}::from_le_bytes({name});\n",
+                self.size, self.rust_type
+            ),
+        }
+    }
+}
 
 /// A builder for constructing input data for RISC Zero guests
 /// 
@@ -20,6 +79,7 @@ use pyo3::exceptions::PyValueError;
 #[pyclass(module = "pyr0")]
 pub struct InputBuilder {
     data: Vec<u8>,
+    fields: Vec<Field>,
 }
 
 #[pymethods]
@@ -29,6 +89,7 @@ impl InputBuilder {
     pub fn new() -> Self {
         Self {
             data: Vec::new(),
+            fields: Vec::new(),
         }
     }
     
@@ -51,7 +112,7 @@ impl InputBuilder {
     /// let input: Input = minicbor::decode(&buf).unwrap();  // Entire buffer is CBOR
     /// ```
     pub fn write_cbor(mut slf: PyRefMut<Self>, cbor_bytes: Vec<u8>) -> PyRefMut<Self> {
-        slf.data.extend_from_slice(&cbor_bytes);
+        slf.write_cbor_internal(cbor_bytes);
         slf
     }
     
@@ -64,7 +125,7 @@ impl InputBuilder {
     /// let value = u32::from_le_bytes(bytes);
     /// ```
     pub fn write_u32(mut slf: PyRefMut<Self>, value: u32) -> PyRefMut<Self> {
-        slf.data.extend_from_slice(&value.to_le_bytes());
+        slf.write_u32_internal(value);
         slf
     }
     
@@ -77,10 +138,122 @@ impl InputBuilder {
     /// let value = u64::from_le_bytes(bytes);
     /// ```
     pub fn write_u64(mut slf: PyRefMut<Self>, value: u64) -> PyRefMut<Self> {
-        slf.data.extend_from_slice(&value.to_le_bytes());
+        slf.write_u64_internal(value);
+        slf
+    }
+    
+    /// Write an i32 value (4 bytes, little-endian two's complement)
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let mut bytes = [0u8; 4];
+    /// env::read_slice(&mut bytes);
+    /// let value = i32::from_le_bytes(bytes);
+    /// ```
+    pub fn write_i32(mut slf: PyRefMut<Self>, value: i32) -> PyRefMut<Self> {
+        slf.write_i32_internal(value);
+        slf
+    }
+    
+    /// Write an i64 value (8 bytes, little-endian two's complement)
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let mut bytes = [0u8; 8];
+    /// env::read_slice(&mut bytes);
+    /// let value = i64::from_le_bytes(bytes);
+    /// ```
+    pub fn write_i64(mut slf: PyRefMut<Self>, value: i64) -> PyRefMut<Self> {
+        slf.write_i64_internal(value);
+        slf
+    }
+    
+    /// Write an f32 value (4 bytes, little-endian IEEE 754)
+    /// 
+    /// Python floats are doubles, so the value is rounded to single precision.
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let mut bytes = [0u8; 4];
+    /// env::read_slice(&mut bytes);
+    /// let value = f32::from_le_bytes(bytes);
+    /// ```
+    pub fn write_f32(mut slf: PyRefMut<Self>, value: f32) -> PyRefMut<Self> {
+        slf.write_f32_internal(value);
+        slf
+    }
+    
+    /// Write an f64 value (8 bytes, little-endian IEEE 754)
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let mut bytes = [0u8; 8];
+    /// env::read_slice(&mut bytes);
+    /// let value = f64::from_le_bytes(bytes);
+    /// ```
+    pub fn write_f64(mut slf: PyRefMut<Self>, value: f64) -> PyRefMut<Self> {
+        slf.write_f64_internal(value);
+        slf
+    }
+    
+    /// Write a bool as a single byte (0 or 1)
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let mut byte = [0u8; 1];
+    /// env::read_slice(&mut byte);
+    /// let flag = byte[0] != 0;
+    /// ```
+    pub fn write_bool(mut slf: PyRefMut<Self>, value: bool) -> PyRefMut<Self> {
+        slf.write_bool_internal(value);
+        slf
+    }
+    
+    /// Write a string as UTF-8 with a length frame (Pattern C)
+    /// 
+    /// Writes: [u64 byte length in little-endian][UTF-8 bytes]
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let mut len_bytes = [0u8; 8];
+    /// env::read_slice(&mut len_bytes);
+    /// let mut utf8 = vec![0u8; u64::from_le_bytes(len_bytes) as usize];
+    /// env::read_slice(&mut utf8);
+    /// let text = String::from_utf8(utf8).unwrap();
+    /// ```
+    pub fn write_str_frame(mut slf: PyRefMut<Self>, text: &str) -> PyRefMut<Self> {
+        slf.write_str_frame_internal(text);
         slf
     }
     
+    /// Write values in RISC Zero serde format, for guests using env::read()
+    /// 
+    /// Takes the same values as pyr0.codec.commit_encode(): (type, value)
+    /// tuples such as ("u32", 5) or ("[u8; 32]", digest), or untagged
+    /// bool/str/bytes/float. Every value is expanded to whole u32 words the
+    /// way env::read() expects (e.g. bytes become a length word plus one word
+    /// per byte), so don't mix this with byte-sized raw writes before it.
+    /// 
+    /// **Python code:**
+    /// ```python
+    /// builder.write_serde([("u32", 7), b"payload", "name"])
+    /// ```
+    /// 
+    /// **Guest code (Rust):**
+    /// ```rust
+    /// let count: u32 = env::read();
+    /// let payload: Vec<u8> = env::read();
+    /// let name: String = env::read();
+    /// ```
+    /// 
+    /// Raises:
+    ///     ValueError/TypeError: If a value can't be encoded as its type
+    pub fn write_serde<'py>(mut slf: PyRefMut<'py, Self>, values: &Bound<'py, PyAny>) -> PyResult<PyRefMut<'py, Self>> {
+        let items = codec::encode_items(values)?;
+        slf.write_serde_internal(items);
+        Ok(slf)
+    }
+    
     /// Write exactly 32 bytes (enforces length)
     /// 
     /// Common for cryptographic keys, hashes, and image IDs.
@@ -91,12 +264,7 @@ impl InputBuilder {
     /// env::read_slice(&mut bytes);
     /// ```
     pub fn write_bytes32(mut slf: PyRefMut<Self>, data: Vec<u8>) -> PyResult<PyRefMut<Self>> {
-        if data.len() != 32 {
-            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                format!("write_bytes32 requires exactly 32 bytes, got {}", data.len())
-            ));
-        }
-        slf.data.extend_from_slice(&data);
+        slf.write_bytes32_internal(data).map_err(PyErr::new::<PyValueError, _>)?;
         Ok(slf)
     }
     
@@ -126,7 +294,7 @@ impl InputBuilder {
     /// 
     /// Returns self for method chaining.
     pub fn write_raw_bytes(mut slf: PyRefMut<Self>, data: Vec<u8>) -> PyRefMut<Self> {
-        slf.data.extend_from_slice(&data);
+        slf.write_raw_bytes_internal(data);
         slf
     }
     
@@ -143,9 +311,69 @@ impl InputBuilder {
         self.data.len()
     }
     
+    /// Describe every write so far, in order
+    /// 
+    /// Returns:
+    ///     list of dicts with keys "kind" (the writer, e.g. "u32", "frame",
+    ///     "serde"), "type" (the Rust type the guest reads), "offset" and
+    ///     "size" (in bytes, within build())
+    /// 
+    /// Example:
+    ///     >>> pyr0.InputBuilder().write_u32(1).write_frame(b"ab").layout()
+    ///     [{'kind': 'u32', 'type': 'u32', 'offset': 0, 'size': 4},
+    ///      {'kind': 'frame', 'type': 'Vec<u8>', 'offset': 4, 'size': 10}]
+    pub fn layout<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let entry = PyDict::new(py);
+                entry.set_item("kind", field.kind)?;
+                entry.set_item("type", &field.rust_type)?;
+                entry.set_item("offset", field.offset)?;
+                entry.set_item("size", field.size)?;
+                Ok(entry)
+            })
+            .collect()
+    }
+    
+    /// Generate the guest code that reads this input back, field by field
+    /// 
+    /// Paste it into the guest's main() so the host and guest layouts can't
+    /// drift apart; regenerate it whenever the host-side writes change.
+    /// Variables are named field0, field1, ... in write order.
+    /// 
+    /// Returns:
+    ///     str: Rust statements using risc0_zkvm::guest::env
+    pub fn generate_guest_snippet(&self) -> String {
+        let mut code = String::from("// Generated by pyr0 InputBuilder.generate_guest_snippet()\n");
+        for (i, field) in self.fields.iter().enumerate() {
+            code += &format!(
+                "\n// field{i}: {} ({}) at offset {}, {} bytes\n",
+                field.kind, field.rust_type, field.offset, field.size
+            );
+            code += &field.guest_read(&format!("field{i}"));
+        }
+        code
+    }
+    
     /// Clear all data and start over
     pub fn clear(&mut self) {
         self.data.clear();
+        self.fields.clear();
+    }
+    
+    /// Overwrite the buffer with zeros, then clear it
+    /// 
+    /// Use this instead of clear() once the builder has held private keys or
+    /// witnesses. clear() only resets the length, leaving the old bytes in
+    /// host memory until the allocation is reused.
+    /// 
+    /// Bytes already returned by build() are separate Python objects and are
+    /// not affected. prove() reads them in place without copying, so drop
+    /// your references to them to keep the number of copies down.
+    pub fn clear_secure(&mut self) {
+        self.data.zeroize();
+        self.fields.clear();
     }
     
     /// Write CBOR with length frame (Pattern C: Safe mixing)
@@ -177,9 +405,7 @@ impl InputBuilder {
     /// let extra = u32::from_le_bytes(n);
     /// ```
     pub fn write_cbor_frame(mut slf: PyRefMut<Self>, cbor_bytes: Vec<u8>) -> PyRefMut<Self> {
-        let len = cbor_bytes.len() as u64;
-        slf.data.extend_from_slice(&len.to_le_bytes());
-        slf.data.extend_from_slice(&cbor_bytes);
+        slf.write_cbor_frame_internal(cbor_bytes);
         slf
     }
     
@@ -196,35 +422,115 @@ impl InputBuilder {
     /// env::read_slice(&mut data);
     /// ```
     pub fn write_frame(mut slf: PyRefMut<Self>, data: Vec<u8>) -> PyRefMut<Self> {
-        let len = data.len() as u64;
-        slf.data.extend_from_slice(&len.to_le_bytes());
-        slf.data.extend_from_slice(&data);
+        slf.write_frame_internal(data);
         slf
     }
 }
 
 // Internal methods for use from Rust code (e.g., Composer)
 impl InputBuilder {
+    /// Every write so far, in order
+    pub(crate) fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+    
+    /// xxd-style dump of the buffer, `width` bytes per line, marking the
+    /// line each write starts on
+    pub(crate) fn hexdump(&self, width: usize) -> String {
+        let mut out = String::new();
+        for (line, chunk) in self.data.chunks(width).enumerate() {
+            let start = line * width;
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+                .collect();
+            out += &format!("{:08x}  {:<pad$}  |{}|", start, hex.join(" "), ascii, pad = width * 3 - 1);
+            let starts: Vec<String> = self
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| (start..start + chunk.len()).contains(&f.offset))
+                .map(|(i, f)| format!("field{} {} @{}", i, f.kind, f.offset))
+                .collect();
+            if !starts.is_empty() {
+                out += &format!("  <- {}", starts.join(", "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+    
+    /// Append one field's bytes and record it in the layout
+    fn append(&mut self, kind: &'static str, rust_type: impl Into<String>, bytes: &[u8]) {
+        self.fields.push(Field { kind, rust_type: rust_type.into(), offset: self.data.len(), size: bytes.len() });
+        self.data.extend_from_slice(bytes);
+    }
+    
+    /// Append a length-prefixed field: [u64 length][bytes]
+    fn append_framed(&mut self, kind: &'static str, rust_type: &str, bytes: &[u8]) {
+        let mut framed = Vec::with_capacity(8 + bytes.len());
+        framed.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        framed.extend_from_slice(bytes);
+        self.append(kind, rust_type, &framed);
+    }
+    
     /// Internal version of write_cbor that doesn't need PyRefMut
     pub(crate) fn write_cbor_internal(&mut self, cbor_bytes: Vec<u8>) {
-        self.data.extend_from_slice(&cbor_bytes);
+        self.append("cbor", "Vec<u8>", &cbor_bytes);
     }
     
     /// Internal version of write_cbor_frame that doesn't need PyRefMut
     pub(crate) fn write_cbor_frame_internal(&mut self, cbor_bytes: Vec<u8>) {
-        let len = cbor_bytes.len() as u64;
-        self.data.extend_from_slice(&len.to_le_bytes());
-        self.data.extend_from_slice(&cbor_bytes);
+        self.append_framed("cbor_frame", "Vec<u8>", &cbor_bytes);
     }
     
     /// Internal version of write_u32 that doesn't need PyRefMut
     pub(crate) fn write_u32_internal(&mut self, value: u32) {
-        self.data.extend_from_slice(&value.to_le_bytes());
+        self.append("u32", "u32", &value.to_le_bytes());
     }
     
     /// Internal version of write_u64 that doesn't need PyRefMut
     pub(crate) fn write_u64_internal(&mut self, value: u64) {
-        self.data.extend_from_slice(&value.to_le_bytes());
+        self.append("u64", "u64", &value.to_le_bytes());
+    }
+    
+    /// Internal version of write_i32 that doesn't need PyRefMut
+    pub(crate) fn write_i32_internal(&mut self, value: i32) {
+        self.append("i32", "i32", &value.to_le_bytes());
+    }
+    
+    /// Internal version of write_i64 that doesn't need PyRefMut
+    pub(crate) fn write_i64_internal(&mut self, value: i64) {
+        self.append("i64", "i64", &value.to_le_bytes());
+    }
+    
+    /// Internal version of write_f32 that doesn't need PyRefMut
+    pub(crate) fn write_f32_internal(&mut self, value: f32) {
+        self.append("f32", "f32", &value.to_le_bytes());
+    }
+    
+    /// Internal version of write_f64 that doesn't need PyRefMut
+    pub(crate) fn write_f64_internal(&mut self, value: f64) {
+        self.append("f64", "f64", &value.to_le_bytes());
+    }
+    
+    /// Internal version of write_bool that doesn't need PyRefMut
+    pub(crate) fn write_bool_internal(&mut self, value: bool) {
+        self.append("bool", "bool", &[value as u8]);
+    }
+    
+    /// Internal version of write_str_frame that doesn't need PyRefMut
+    pub(crate) fn write_str_frame_internal(&mut self, text: &str) {
+        self.append_framed("str_frame", "String", text.as_bytes());
+    }
+    
+    /// Internal version of write_serde, taking values already encoded by
+    /// codec::encode_items()
+    pub(crate) fn write_serde_internal(&mut self, items: Vec<(WireType, Vec<u8>)>) {
+        for (ty, bytes) in items {
+            self.append("serde", ty.rust_name(), &bytes);
+        }
     }
     
     /// Internal version of write_bytes32 that doesn't need PyRefMut
@@ -232,19 +538,17 @@ impl InputBuilder {
         if data.len() != 32 {
             return Err(format!("write_bytes32 requires exactly 32 bytes, got {}", data.len()));
         }
-        self.data.extend_from_slice(&data);
+        self.append("bytes32", "[u8; 32]", &data);
         Ok(())
     }
     
     /// Internal version of write_raw_bytes that doesn't need PyRefMut
     pub(crate) fn write_raw_bytes_internal(&mut self, data: Vec<u8>) {
-        self.data.extend_from_slice(&data);
+        self.append("raw", format!("[u8; {}]", data.len()), &data);
     }
     
     /// Internal version of write_frame that doesn't need PyRefMut
     pub(crate) fn write_frame_internal(&mut self, data: Vec<u8>) {
-        let len = data.len() as u64;
-        self.data.extend_from_slice(&len.to_le_bytes());
-        self.data.extend_from_slice(&data);
+        self.append_framed("frame", "Vec<u8>", &data);
     }
-}
\ No newline at end of file
+}
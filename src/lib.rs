@@ -4,88 +4,497 @@ mod session;
 mod claim;
 mod composer;
 mod input_builder;
+mod manifest;
+mod pause;
+mod poseidon2;
+mod codec;
+mod coprocessor;
+mod audit;
+mod checkpoint;
+mod errors;
+mod futures;
+mod fuzz;
+mod guest_input;
+mod host_io;
+mod profile;
+mod registry;
+mod prover_config;
+mod replay;
+mod reservation;
+mod schema;
+mod segment;
+mod verifier;
 
 use crate::image::Image;
-use crate::receipt::{Receipt, ExitStatus, ExitKind, ReceiptKind};
-use crate::session::{ExitCode, SessionInfo};
+use crate::receipt::{Receipt, ExitStatus, ExitKind, ReceiptKind, DeserializationPolicy, VerificationPolicy};
+use crate::session::{ExitCode, SessionDiff, SessionInfo};
 use crate::claim::Claim;
-use crate::composer::Composer;
+use crate::composer::{Composer, SharedAssumption};
 use crate::input_builder::InputBuilder;
+use crate::manifest::GuestManifest;
+use crate::guest_input::GuestInput;
+use crate::host_io::HostIO;
+use crate::prover_config::ProverConfig;
+use crate::reservation::ProverReservation;
+use crate::segment::{KeccakReceipt, Segment, SegmentReceipt, SuccinctReceipt};
+use crate::checkpoint::{CheckpointWriter, CHECKPOINT_FD};
 use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyTuple};
 use risc0_zkvm::{default_prover, ExecutorEnv, ProverOpts};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
 
 #[pyfunction]
 fn load_image(elf: &Bound<'_, PyAny>) -> PyResult<Image> {
     let elf_bytes: Vec<u8> = elf.extract()?;
+    load_elf(&elf_bytes)
+}
+
+/// Load an image straight from an ELF file on disk
+/// 
+/// The file is memory-mapped rather than read into a Python bytes object
+/// first, which avoids an extra copy of large guests.
+/// 
+/// Args:
+///     path: Path to the guest ELF
+/// 
+/// Returns:
+///     Image: The loaded image
+/// 
+/// Raises:
+///     ImageError: If the file can't be opened or isn't a valid guest ELF
+/// 
+/// Example:
+///     image = pyr0.load_image_from_path(pyr0.build_guest("my_guest"))
+#[pyfunction]
+fn load_image_from_path(path: PathBuf) -> PyResult<Image> {
+    load_elf_file(&path)
+}
+
+/// Load an image through an on-disk cache keyed by image ID
+/// 
+/// Worker processes tend to load the same guest over and over, often
+/// knowing only its image ID. The first call loads the ELF and saves the
+/// result (Image.to_bytes()) as `<cache_dir>/<image_id>.pyr0-image`; later
+/// calls, in any process, read that file instead and need no ELF. The file
+/// is re-hashed on every load, so a tampered cache entry can't stand in
+/// for the guest.
+/// 
+/// Args:
+///     cache_dir: Directory for cached images (created if missing)
+///     image_id: Expected image ID (32 bytes or 64-char hex)
+///     elf: ELF bytes or path, used on a cache miss. May be omitted when
+///          the image is known to be cached.
+/// 
+/// Returns:
+///     Image: The loaded image
+/// 
+/// Raises:
+///     ImageError: If the image isn't cached and no ELF was given, or the
+///                 ELF's image ID isn't image_id
+///     SerializationError: If the cache file is corrupt, or its memory
+///                         image or ELF doesn't hash to image_id (see
+///                         Image.from_bytes())
+/// 
+/// Example:
+///     image = pyr0.load_image_cached("/var/cache/pyr0", GUEST_ID, elf="guest.bin")
+#[pyfunction]
+#[pyo3(signature = (cache_dir, image_id, elf=None))]
+fn load_image_cached(
+    cache_dir: PathBuf,
+    image_id: &Bound<'_, PyAny>,
+    elf: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Image> {
+    let expected = if let Ok(hex_str) = image_id.extract::<String>() {
+        receipt::digest_from_hex(&hex_str)?
+    } else {
+        receipt::digest_from_bytes(&image_id.extract::<Vec<u8>>()?)?
+    };
+    let path = cache_dir.join(format!("{}.pyr0-image", hex::encode(expected.as_bytes())));
+    
+    if let Ok(data) = std::fs::read(&path) {
+        // from_bytes() checks the stored ID against the memory image and ELF
+        let image = Image::from_bytes(&data)?;
+        if image.id()? != expected.as_bytes() {
+            return Err(errors::serialization_error(format!(
+                "Cache file {} holds a different image",
+                path.display()
+            )));
+        }
+        return Ok(image);
+    }
+    
+    let Some(elf) = elf else {
+        return Err(errors::image_error(format!(
+            "Image {} is not cached in {} and no ELF was given",
+            hex::encode(expected.as_bytes()),
+            cache_dir.display()
+        )));
+    };
+    let elf_bytes = match elf.extract::<Vec<u8>>() {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            let elf_path: PathBuf = elf.extract()?;
+            std::fs::read(&elf_path)
+                .map_err(|e| errors::image_error(format!("Failed to read {}: {}", elf_path.display(), e)))?
+        }
+    };
+    let image = load_elf(&elf_bytes)?;
+    if image.id()? != expected.as_bytes() {
+        return Err(errors::image_error(format!(
+            "ELF has image ID {}, expected {}",
+            image.id_hex()?,
+            hex::encode(expected.as_bytes())
+        )));
+    }
+    
+    // Write to a temporary file and rename, so concurrent workers never
+    // read a half-written entry
+    let data = image.to_bytes()?;
+    let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+    std::fs::create_dir_all(&cache_dir)
+        .and_then(|_| std::fs::write(&tmp, &data))
+        .and_then(|_| std::fs::rename(&tmp, &path))
+        .map_err(|e| errors::image_error(format!("Failed to write {}: {}", path.display(), e)))?;
+    Ok(image)
+}
+
+/// Shared by load_image_from_path() and Image.from_file()
+pub(crate) fn load_elf_file(path: &Path) -> PyResult<Image> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| errors::image_error(format!("Failed to open {}: {}", path.display(), e)))?;
+    // SAFETY: the mapping is only read while the image is built, and Image
+    // keeps its own copy of the ELF. A file truncated underneath us can still
+    // fault, as with any mmap.
+    let elf = unsafe { memmap2::Mmap::map(&file) }
+        .map_err(|e| errors::image_error(format!("Failed to map {}: {}", path.display(), e)))?;
+    load_elf(&elf)
+}
+
+/// Shared by load_image() and pyr0.fuzz.image_from_bytes()
+pub(crate) fn load_elf(elf_bytes: &[u8]) -> PyResult<Image> {
     // Compute the image ID from the ELF
-    let image_id = risc0_binfmt::compute_image_id(&elf_bytes)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to compute image ID: {}", e)))?;
+    let image_id = risc0_binfmt::compute_image_id(elf_bytes)
+        .map_err(|e| errors::image_error(format!("Failed to compute image ID: {}", e)))?;
     
-    Ok(Image::from_elf(&elf_bytes, image_id)?)
+    Image::from_elf(elf_bytes, image_id)
+        .map_err(|e| errors::image_error(format!("Failed to load image: {}", e)))
 }
 
 // For testing/debugging - execute without proving
+//
+// With capture_output=True, whatever the guest prints to stdout/stderr is
+// collected into SessionInfo.stdout/.stderr instead of going to the host's
+// streams, so guest diagnostics are visible without setting RUST_LOG etc.
+//
+// on_checkpoint(marker, cycles) is called for each progress record the guest
+// writes to CHECKPOINT_FD (see prove()).
+//
+// env sets environment variables for the guest (std::env::var), and
+// assumptions lists receipts the guest may env::verify(); as in
+// prove_fake(), only their claims matter, so any kind of receipt will do.
+//
+// record=path writes the guest, input, env and assumption claims to a
+// recording file - even if execution fails - so replay(path) can reproduce
+// the run exactly on another machine. Needs the input as bytes or a buffer,
+// and can't be combined with host_io, whose answers aren't recorded.
+//
+// host_io is a HostIO whose handlers answer the guest's host calls.
+//
+// pausable=True keeps an execution that stops at env::pause() in memory, so
+// resume() can continue it with more input; the returned SessionInfo's
+// pause_handle names it. It needs the input as bytes or a buffer, and works
+// with env and assumptions but none of the other options.
 #[pyfunction]
-#[pyo3(signature = (image, input_bytes))]
+#[pyo3(signature = (image, input_bytes, capture_output=false, on_checkpoint=None, env=None, assumptions=None, record=None, host_io=None, pausable=false))]
+#[allow(clippy::too_many_arguments)]
 fn dry_run(
-    _py: Python<'_>,
+    py: Python<'_>,
     image: &Image,
     input_bytes: &Bound<'_, PyAny>,
+    capture_output: bool,
+    on_checkpoint: Option<PyObject>,
+    env: Option<BTreeMap<String, String>>,
+    assumptions: Option<Vec<Receipt>>,
+    record: Option<PathBuf>,
+    host_io: Option<PyRef<HostIO>>,
+    pausable: bool,
 ) -> PyResult<SessionInfo> {
-    // Accept any bytes-like object and convert to bytes
-    let bytes: Vec<u8> = input_bytes.extract()?;
+    // bytes, buffers or a file-like object (see GuestInput)
+    let mut input = GuestInput::extract(input_bytes)?;
+    if record.is_some() && host_io.is_some() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "record= can't capture host_io= handlers, so the run couldn't be replayed"
+        ));
+    }
+    let host_io = host_io.as_deref();
+    let recorded_input = match record {
+        Some(_) => Some(input.bytes().ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "record= needs the input as bytes or a buffer, not a file-like object or path"
+        ))?.to_vec()),
+        None => None,
+    };
+    let env = env.unwrap_or_default();
+    let assumption_claims = assumptions
+        .unwrap_or_default()
+        .iter()
+        .map(|r| composer::unconditional_claim_digest(&r.inner))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
+    let mut checkpoints = on_checkpoint.map(CheckpointWriter::new);
+    let memory_image = image.get_image();
     
-    let env = ExecutorEnv::builder()
-        .write_slice(&bytes)
-        .build()?;
+    if pausable {
+        if capture_output || checkpoints.is_some() || record.is_some() || host_io.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "pausable=True can't be combined with capture_output, on_checkpoint, record or host_io"
+            ));
+        }
+        let data = pausable_input(&input)?;
+        let image_id = receipt::digest_from_bytes(&image.id()?)?;
+        let (outcome, handle) = py
+            .allow_threads(|| pause::start(image_id, memory_image, data, None, move |builder| {
+                replay::apply_environment(builder, &env, &assumption_claims);
+                Ok(())
+            }))
+            .map_err(|e| errors::proving_error(format!("Execution failed: {}", e)))?;
+        let pause::Outcome::Session(info) = outcome else { unreachable!("dry runs aren't proven") };
+        return Ok(info.with_pause_handle(handle));
+    }
+    
+    // Release the GIL while the guest runs; ExecutorEnv isn't Send, so it's
+    // built inside the closure
+    let result = py.allow_threads(|| -> anyhow::Result<SessionInfo> {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let info = {
+            let mut builder = ExecutorEnv::builder();
+            input.attach(&mut builder);
+            replay::apply_environment(&mut builder, &env, &assumption_claims);
+            if let Some(host_io) = host_io {
+                host_io.attach(&mut builder);
+            }
+            if capture_output {
+                builder.stdout(&mut stdout).stderr(&mut stderr);
+            }
+            if let Some(checkpoints) = &mut checkpoints {
+                builder.write_fd(CHECKPOINT_FD, checkpoints);
+            }
+            let env = builder.build()?;
 
-    let mut exec = risc0_zkvm::ExecutorImpl::new(env, image.get_image())?;
-    let session = exec.run()?;
+            let mut exec = risc0_zkvm::ExecutorImpl::new(env, memory_image)?;
+            let session = exec.run()?;
+            SessionInfo::new(&session)?
+        };
+        Ok(if capture_output { info.with_output(stdout, stderr) } else { info })
+    });
     
-    Ok(SessionInfo::new(&session)?)
+    if let (Some(path), Some(input)) = (&record, recorded_input) {
+        replay::RecordingData {
+            image_id: receipt::digest_from_bytes(&image.id()?)?,
+            elf: image.get_elf().to_vec(),
+            input,
+            env,
+            assumptions: assumption_claims,
+            outcome: result.as_ref().map(SessionInfo::clone).map_err(|e| e.to_string()),
+        }
+        .write(path)?;
+    }
+    result.map_err(|e| errors::proving_error(format!("Execution failed: {}", e)))
 }
 
 
+/// Input for a pausable run, which is queued up rather than streamed
+fn pausable_input(input: &GuestInput<'_>) -> PyResult<Vec<u8>> {
+    input.bytes().map(<[u8]>::to_vec).ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "pausable=True needs the input as bytes or a buffer, not a file-like object or path"
+    ))
+}
+
 /// Unified function to execute and prove in one call
+/// 
+/// Args:
+///     image: The Image containing the RISC-V ELF
+///     input_bytes: Input data for the guest program. bytes are read in
+///                  place without copying; other buffers (bytearray,
+///                  memoryview, numpy arrays) are copied once; file-like
+///                  objects with read() are streamed as the guest reads
+///                  stdin, so multi-hundred-MB inputs never sit in memory;
+///                  a path (pathlib.Path) is opened and streamed without
+///                  going through Python at all.
+///     max_cycles: Optional session limit in cycles. A guest that runs past it
+///                 stops with ExitKind.SESSION_LIMIT and SessionLimitExceeded
+///                 is raised instead of proving indefinitely.
+///     zeroize_input: If True, the one copy PyR0 makes of a mutable buffer
+///                    (bytearray, memoryview, numpy array) is overwritten
+///                    with zeros once proving finishes, whether or not it
+///                    succeeds; the guest reads that copy in place, so PyR0
+///                    keeps no other. bytes, file-like and path input isn't
+///                    copied, so there's nothing to wipe. Not cleared: the
+///                    Python object you passed in (use a bytearray and clear
+///                    it yourself, or InputBuilder with clear_secure()), and
+///                    whatever RISC Zero holds internally - the executor's
+///                    read buffers and the guest memory captured in segments
+///                    are freed, not wiped.
+///     on_checkpoint: Optional callable(marker, cycles) for progress
+///                    reporting within one long execution. It is called for
+///                    each 12-byte record the guest writes to
+///                    pyr0.CHECKPOINT_FD: a u32 marker of the guest's choosing
+///                    and a u64 env::cycle_count(), little-endian. An
+///                    exception in the callback aborts proving.
+///     manifest: Optional GuestManifest; the input (and image ID, if the
+///               manifest names one) is checked against it before proving
+///               and PreflightError is raised on any mismatch. Needs the
+///               input as bytes or a buffer, not a file-like object.
+///     host_io: Optional HostIO answering the guest's host calls (named
+///              channels and extra file descriptors) during execution.
+///     pausable: If True and the guest calls env::pause(), the execution is
+///               kept so resume() can continue it with more input (see
+///               resume()). The execution runs on a thread of its own and
+///               proves on the local prover, outside any reserve_prover()
+///               pool. Can't be combined with zeroize_input, on_checkpoint
+///               or host_io.
 #[pyfunction]
-#[pyo3(signature = (image, input_bytes))]
-fn prove(_py: Python<'_>, image: &Image, input_bytes: &Bound<'_, PyAny>) -> PyResult<Receipt> {
-    // Accept any bytes-like object and convert to bytes
-    let bytes: Vec<u8> = input_bytes.extract()?;
-    
-    // Build the execution environment
-    let env = ExecutorEnv::builder()
-        .write_slice(&bytes)
-        .build()?;
+#[pyo3(signature = (image, input_bytes, max_cycles=None, zeroize_input=false, on_checkpoint=None, manifest=None, host_io=None, pausable=false))]
+#[allow(clippy::too_many_arguments)]
+fn prove(
+    py: Python<'_>,
+    image: &Image,
+    input_bytes: &Bound<'_, PyAny>,
+    max_cycles: Option<u64>,
+    zeroize_input: bool,
+    on_checkpoint: Option<PyObject>,
+    manifest: Option<PyRef<GuestManifest>>,
+    host_io: Option<PyRef<HostIO>>,
+    pausable: bool,
+) -> PyResult<Receipt> {
+    // bytes, buffers or a file-like object (see GuestInput)
+    let mut input = GuestInput::extract(input_bytes)?;
+    if let Some(manifest) = &manifest {
+        let data = input.bytes().ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "manifest= needs the input as bytes or a buffer, not a file-like object or path"
+        ))?;
+        manifest.check_input(py, data, None, Some(&image.id()?))?;
+    }
+    if pausable {
+        if zeroize_input || on_checkpoint.is_some() || host_io.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "pausable=True can't be combined with zeroize_input, on_checkpoint or host_io"
+            ));
+        }
+        let data = pausable_input(&input)?;
+        let image_id = receipt::digest_from_bytes(&image.id()?)?;
+        let opts = Some(ProverOpts::default());
+        let memory_image = image.get_image();
+        let (outcome, handle) = py
+            .allow_threads(|| pause::start(image_id, memory_image, data, opts, move |builder| {
+                builder.session_limit(max_cycles);
+                Ok(())
+            }))
+            .map_err(|e| errors::prove_failure(py, e, max_cycles))?;
+        let pause::Outcome::Receipt(receipt) = outcome else { unreachable!("proving returns a receipt") };
+        return Ok(Receipt::from_risc0(receipt).with_pause_handle(handle));
+    }
+    let mut checkpoints = on_checkpoint.map(CheckpointWriter::new);
+    let host_io = host_io.as_deref();
+    let elf = image.get_elf();
     
-    // Use RISC Zero's high-level API - no segment handling needed!
-    let receipt = default_prover()
-        .prove(env, image.get_elf())?
-        .receipt;
+    // Proving can take minutes - let other Python threads run meanwhile
+    let receipt = reservation::allow_threads(py, || -> anyhow::Result<_> {
+        // Build the execution environment
+        let mut builder = ExecutorEnv::builder();
+        input.attach(&mut builder);
+        if let Some(checkpoints) = &mut checkpoints {
+            builder.write_fd(CHECKPOINT_FD, checkpoints);
+        }
+        if let Some(host_io) = host_io {
+            host_io.attach(&mut builder);
+        }
+        let env = builder.session_limit(max_cycles).build()?;
+        
+        // Use RISC Zero's high-level API - no segment handling needed!
+        Ok(default_prover().prove(env, elf)?.receipt)
+    });
+    if zeroize_input {
+        input.wipe();
+    }
+    let receipt = receipt.map_err(|e| errors::prove_failure(py, e, max_cycles))?;
     
     // Return a Receipt that wraps the RISC Zero receipt
     Ok(Receipt::from_risc0(receipt))
 }
 
 /// Execute and prove with specific options (e.g., succinct, groth16)
+/// 
+/// max_cycles and zeroize_input work as in prove(). Pass a ProverConfig to
+/// choose the backend, hash function and segment sizes (including the
+/// keccak accelerator's) explicitly instead of relying on RISC0_*
+/// environment variables.
+/// 
+/// coprocessor: Optional callable(request: dict) receiving each proof
+///     request the guest's accelerators make (keccak batches, ZKR
+///     programs) instead of the prover proving them in-process. The
+///     receipt then carries those requests as assumptions, to be proved
+///     separately (see prove_keccak()) and discharged with
+///     Receipt.resolve(keccak=...). Needs a build
+///     with the 'unstable' feature.
+/// 
+/// host_io: Optional HostIO answering the guest's host calls (see prove()).
 #[pyfunction]
-#[pyo3(signature = (image, input_bytes, succinct=false))]
-fn prove_with_opts(_py: Python<'_>, image: &Image, input_bytes: &Bound<'_, PyAny>, succinct: bool) -> PyResult<Receipt> {
-    let bytes: Vec<u8> = input_bytes.extract()?;
-    
-    let env = ExecutorEnv::builder()
-        .write_slice(&bytes)
-        .build()?;
+#[pyo3(signature = (image, input_bytes, succinct=false, max_cycles=None, zeroize_input=false, config=None, coprocessor=None, host_io=None))]
+#[allow(clippy::too_many_arguments)]
+fn prove_with_opts(
+    py: Python<'_>,
+    image: &Image,
+    input_bytes: &Bound<'_, PyAny>,
+    succinct: bool,
+    max_cycles: Option<u64>,
+    zeroize_input: bool,
+    config: Option<ProverConfig>,
+    coprocessor: Option<PyObject>,
+    host_io: Option<PyRef<HostIO>>,
+) -> PyResult<Receipt> {
+    #[cfg(not(feature = "unstable"))]
+    if coprocessor.is_some() {
+        return Err(coprocessor::unsupported());
+    }
+    let mut input = GuestInput::extract(input_bytes)?;
+    let host_io = host_io.as_deref();
+    let elf = image.get_elf();
     
-    let opts = if succinct {
+    let mut opts = if succinct {
         ProverOpts::succinct()
     } else {
         ProverOpts::default()
     };
+    if let Some(config) = &config {
+        config.apply_opts(&mut opts);
+    }
     
-    let receipt = default_prover()
-        .prove_with_opts(env, image.get_elf(), &opts)?
-        .receipt;
+    let receipt = reservation::allow_threads(py, || -> anyhow::Result<_> {
+        let mut builder = ExecutorEnv::builder();
+        input.attach(&mut builder);
+        if let Some(config) = &config {
+            config.apply_env(&mut builder)?;
+        }
+        if let Some(host_io) = host_io {
+            host_io.attach(&mut builder);
+        }
+        #[cfg(feature = "unstable")]
+        if let Some(callback) = coprocessor {
+            builder.coprocessor_callback(coprocessor::PyCoprocessor(callback));
+        }
+        let env = builder.session_limit(max_cycles).build()?;
+        
+        let prover = config.as_ref().map_or_else(default_prover, ProverConfig::prover);
+        Ok(prover.prove_with_opts(env, elf, &opts)?.receipt)
+    });
+    if zeroize_input {
+        input.wipe();
+    }
+    let receipt = receipt.map_err(|e| errors::prove_failure(py, e, max_cycles))?;
     
     Ok(Receipt::from_risc0(receipt))
 }
@@ -98,24 +507,147 @@ fn prove_with_opts(_py: Python<'_>, image: &Image, input_bytes: &Bound<'_, PyAny
 /// Args:
 ///     image: The Image containing the RISC-V ELF
 ///     input_bytes: Input data for the guest program
+///     zeroize_input: Wipe PyR0's copy of the input once proving finishes
+///                    (see prove() for exactly what is cleared)
 /// 
 /// Returns:
 ///     Receipt: A succinct receipt with no unresolved assumptions
 #[pyfunction]
-fn prove_succinct(_py: Python<'_>, image: &Image, input_bytes: &Bound<'_, PyAny>) -> PyResult<Receipt> {
-    let bytes: Vec<u8> = input_bytes.extract()?;
+#[pyo3(signature = (image, input_bytes, zeroize_input=false))]
+fn prove_succinct(
+    py: Python<'_>,
+    image: &Image,
+    input_bytes: &Bound<'_, PyAny>,
+    zeroize_input: bool,
+) -> PyResult<Receipt> {
+    let mut input = GuestInput::extract(input_bytes)?;
+    let elf = image.get_elf();
+    
+    let receipt = reservation::allow_threads(py, || -> anyhow::Result<_> {
+        let mut builder = ExecutorEnv::builder();
+        input.attach(&mut builder);
+        let env = builder.build()?;
+        
+        Ok(default_prover().prove_with_opts(env, elf, &ProverOpts::succinct())?.receipt)
+    });
+    if zeroize_input {
+        input.wipe();
+    }
+    let receipt = receipt.map_err(|e| errors::prove_failure(py, e, None))?;
+    
+    Ok(Receipt::from_risc0(receipt))
+}
+
+/// Execute the guest and wrap the result in an INSECURE fake receipt
+/// 
+/// Runs the executor only - no proving - and returns a ReceiptKind.FAKE
+/// receipt with the real journal and claim, like RISC Zero's dev mode.
+/// Use it to iterate on guest logic in test suites without paying the
+/// proving cost.
+/// 
+/// ⚠️ A fake receipt proves nothing. verify() rejects it (this build of
+/// PyR0 has dev mode disabled), Composer.assume() refuses it, and every
+/// call emits a warning. Never ship it anywhere a real receipt is expected.
+/// 
+/// Args:
+///     image: The Image containing the RISC-V ELF
+///     input_bytes: Input data for the guest program (see prove())
+///     max_cycles: Optional session limit in cycles (see prove())
+///     assumptions: Receipts the guest may env::verify(). Unlike
+///                  Composer.assume(), FAKE receipts are accepted, so whole
+///                  compositions can be run without proving anything.
+/// 
+/// Returns:
+///     Receipt: A receipt with kind ReceiptKind.FAKE
+#[pyfunction]
+#[pyo3(signature = (image, input_bytes, max_cycles=None, assumptions=None))]
+fn prove_fake(
+    py: Python<'_>,
+    image: &Image,
+    input_bytes: &Bound<'_, PyAny>,
+    max_cycles: Option<u64>,
+    assumptions: Option<Vec<Receipt>>,
+) -> PyResult<Receipt> {
+    let mut input = GuestInput::extract(input_bytes)?;
+    let memory_image = image.get_image();
+    let assumption_claims = assumptions
+        .unwrap_or_default()
+        .iter()
+        .map(|r| composer::unconditional_claim_digest(&r.inner))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| errors::composition_error(format!("Failed to get claim: {}", e)))?;
     
-    let env = ExecutorEnv::builder()
-        .write_slice(&bytes)
-        .build()?;
+    let receipt = py.allow_threads(|| -> anyhow::Result<_> {
+        let mut builder = ExecutorEnv::builder();
+        input.attach(&mut builder);
+        // The executor only checks claims, so unproven assumptions will do
+        for claim in assumption_claims {
+            builder.add_assumption(risc0_zkvm::Assumption { claim, control_root: risc0_zkvm::sha::Digest::ZERO });
+        }
+        let env = builder.session_limit(max_cycles).build()?;
+        
+        let session = risc0_zkvm::ExecutorImpl::new(env, memory_image)?.run()?;
+        let claim = session.claim()?;
+        let journal = session.journal.map(|j| j.bytes).unwrap_or_default();
+        let fake = risc0_zkvm::FakeReceipt::new(claim);
+        Ok(risc0_zkvm::Receipt::new(risc0_zkvm::InnerReceipt::Fake(fake), journal))
+    })
+        .map_err(|e| errors::prove_failure(py, e, max_cycles))?;
     
-    let receipt = default_prover()
-        .prove_with_opts(env, image.get_elf(), &ProverOpts::succinct())?
-        .receipt;
+    py.import("warnings")?.call_method1(
+        "warn",
+        ("pyr0.prove_fake() returned an INSECURE fake receipt - for testing only",),
+    )?;
     
     Ok(Receipt::from_risc0(receipt))
 }
 
+/// Awaitable version of prove() for asyncio code
+/// 
+/// Proving runs on the event loop's default executor with the GIL released,
+/// so the loop stays responsive and several proofs can run concurrently.
+/// Must be called from a coroutine (needs a running event loop).
+/// 
+/// Args:
+///     image: The Image containing the RISC-V ELF
+///     input_bytes: Input data for the guest program. Mutable buffers are
+///                  snapshotted immediately; bytes and file-like objects are
+///                  used as-is (don't touch a file until the future resolves).
+///     max_cycles: Optional session limit in cycles (see prove())
+///     zeroize_input: See prove(). A snapshot of a mutable buffer is a
+///                    Python bytes object and is only freed, not wiped.
+/// 
+/// Returns:
+///     asyncio.Future[Receipt]: Resolves to the receipt
+/// 
+/// Example:
+///     receipts = await asyncio.gather(
+///         pyr0.prove_async(image, input1),
+///         pyr0.prove_async(image, input2),
+///     )
+#[pyfunction]
+#[pyo3(signature = (image, input_bytes, max_cycles=None, zeroize_input=false))]
+fn prove_async<'py>(
+    py: Python<'py>,
+    image: Bound<'py, Image>,
+    input_bytes: &Bound<'py, PyAny>,
+    max_cycles: Option<u64>,
+    zeroize_input: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    // Snapshot mutable buffers so later mutation of a bytearray can't race
+    // the prover; bytes are immutable and file-like objects are streamed
+    let input = if input_bytes.is_instance_of::<PyBytes>() || input_bytes.hasattr("read")? {
+        input_bytes.clone()
+    } else {
+        py.get_type::<PyBytes>().call1((input_bytes,))?
+    };
+    let args = PyTuple::new(py, [image.into_any(), input])?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("max_cycles", max_cycles)?;
+    kwargs.set_item("zeroize_input", zeroize_input)?;
+    futures::run_in_executor(wrap_pyfunction!(prove, py)?.as_any(), &args, Some(&kwargs))
+}
+
 
 // Advanced functions removed - segments are no longer exposed
 // If needed in future, these could work with Receipt types instead
@@ -130,12 +662,79 @@ fn prove_succinct(_py: Python<'_>, image: &Image, input_bytes: &Bound<'_, PyAny>
 #[pyfunction]
 fn compute_image_id_hex(elf_bytes: Vec<u8>) -> PyResult<String> {
     let image_id = risc0_binfmt::compute_image_id(&elf_bytes)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            format!("Failed to compute image ID: {}", e)
-        ))?;
+        .map_err(|e| errors::image_error(format!("Failed to compute image ID: {}", e)))?;
     Ok(hex::encode(image_id))
 }
 
+/// An ELF given either in memory or as a path to read
+enum ElfSource {
+    Bytes(Vec<u8>),
+    Path(PathBuf),
+}
+
+impl ElfSource {
+    fn image_id_hex(&self) -> Result<String, String> {
+        let image_id = match self {
+            ElfSource::Bytes(bytes) => risc0_binfmt::compute_image_id(bytes),
+            ElfSource::Path(path) => {
+                let bytes = std::fs::read(path)
+                    .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+                risc0_binfmt::compute_image_id(&bytes)
+            }
+        };
+        image_id
+            .map(hex::encode)
+            .map_err(|e| format!("Failed to compute image ID: {}", e))
+    }
+}
+
+/// Compute image IDs for many ELFs in parallel
+/// 
+/// Hashing runs on rayon's thread pool with the GIL released, so
+/// fingerprinting dozens of guest builds doesn't block other Python threads.
+/// 
+/// Args:
+///     elfs: List of ELF binaries (bytes) and/or paths to ELF files
+/// 
+/// Returns:
+///     List of 64-character hex image IDs, in the same order as the input
+/// 
+/// Raises:
+///     ImageError: If an item is neither bytes nor a path, or any ELF is invalid
+#[pyfunction]
+fn compute_image_ids(py: Python<'_>, elfs: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<String>> {
+    let sources = elfs
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            if let Ok(bytes) = item.extract::<Vec<u8>>() {
+                Ok(ElfSource::Bytes(bytes))
+            } else if let Ok(path) = item.extract::<PathBuf>() {
+                Ok(ElfSource::Path(path))
+            } else {
+                Err(errors::image_error(format!("Item {} must be ELF bytes or a path", i)))
+            }
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    
+    let results: Vec<Result<String, String>> = py.allow_threads(|| {
+        sources
+            .par_iter()
+            .map(|source| {
+                catch_unwind(AssertUnwindSafe(|| source.image_id_hex())).unwrap_or_else(|payload| {
+                    Err(format!("image ID computation panicked: {}", fuzz::panic_message(&*payload)))
+                })
+            })
+            .collect()
+    });
+    
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(i, r)| r.map_err(|e| errors::image_error(format!("Item {}: {}", i, e))))
+        .collect()
+}
+
 /// Compress a composite receipt to succinct format
 /// 
 /// This runs the recursion program to resolve all assumptions,
@@ -144,123 +743,236 @@ fn compute_image_id_hex(elf_bytes: Vec<u8>) -> PyResult<String> {
 /// 
 /// Args:
 ///     receipt: A composite receipt to compress
-///     assumptions: Optional list of assumption receipts needed for resolution.
-///                  If None and the receipt has unresolved assumptions, will raise.
+///     assumptions: Optional list of assumption receipts needed for resolution
+///                  (see Receipt.resolve()). If None, the assumption
+///                  receipts carried in the composite receipt are folded in.
 /// 
 /// Returns:
 ///     Receipt: A succinct receipt with all assumptions resolved
 /// 
 /// Raises:
-///     RuntimeError: If compression fails, receipt is already succinct,
-///                   or has unresolved assumptions without providing them
+///     ProvingError: If compression fails, receipt is already succinct,
+///                   or carries FAKE or GROTH16 assumption receipts
 #[pyfunction]
 #[pyo3(signature = (receipt, assumptions=None))]
 fn compress_to_succinct(
-    _py: Python<'_>, 
+    py: Python<'_>, 
     receipt: &Receipt,
     assumptions: Option<Vec<PyRef<Receipt>>>
 ) -> PyResult<Receipt> {
-    use crate::receipt::ReceiptKind;
-    
     // Check if already succinct
     if receipt.is_succinct()? {
-        return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-            "Receipt is already succinct"
-        ));
+        return Err(errors::proving_error("Receipt is already succinct"));
     }
     
-    // Check if it's a composite with potential assumptions
-    if receipt.kind()? == ReceiptKind::Composite {
-        // For composite receipts, we need to check if they have unresolved assumptions
-        // RISC Zero doesn't expose this directly, but we can try compression
-        // and handle the error if assumptions are needed
-        
-        if let Some(assumption_receipts) = assumptions {
-            // Build an environment with the provided assumptions
-            let mut builder = ExecutorEnv::builder();
-            
-            // Add each assumption
-            for assumption in assumption_receipts {
-                // Validate the assumption is unconditional
-                if !assumption.is_unconditional()? {
-                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                        "Provided assumptions must be unconditional (succinct/groth16)"
-                    ));
-                }
-                builder.add_assumption(assumption.inner.clone());
-            }
-            
-            // Note: RISC Zero's compress API doesn't directly take assumptions
-            // This is a limitation - we'd need to use prove_with_opts with the 
-            // composite receipt as input, which isn't directly exposed
-            
-            // For now, attempt direct compression and provide clear error
-            let compressed = risc0_zkvm::default_prover()
-                .compress(&ProverOpts::succinct(), &receipt.inner)
-                .map_err(|e| {
-                    if e.to_string().contains("assumption") {
-                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                            "Cannot compress composite receipt with unresolved assumptions. \
-                             The compress API doesn't support providing assumptions directly. \
-                             Use Composer API instead for composition workflows."
-                        )
-                    } else {
-                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                            format!("Failed to compress receipt: {}", e)
-                        )
-                    }
-                })?;
-            
-            return Ok(Receipt::from_risc0(compressed));
+    // Assumption receipts supplied after the fact are resolved one by one
+    if let Some(assumptions) = assumptions {
+        let assumptions = assumptions.into_iter().map(|r| Receipt::from_risc0(r.inner.clone())).collect();
+        return receipt.resolve(py, assumptions);
+    }
+    
+    // The recursion program can only fold succinct and composite assumption
+    // receipts into the proof
+    if let risc0_zkvm::InnerReceipt::Composite(composite) = &receipt.inner.inner {
+        use risc0_zkvm::InnerAssumptionReceipt;
+        let unfoldable = composite.assumption_receipts.iter().any(|assumption| {
+            matches!(assumption, InnerAssumptionReceipt::Fake(_) | InnerAssumptionReceipt::Groth16(_))
+        });
+        if unfoldable {
+            return Err(errors::proving_error(
+                "Cannot compress a composite receipt whose assumptions are FAKE or GROTH16 receipts. \
+                 Either provide succinct assumption receipts or use the Composer API \
+                 for composition workflows."
+            ));
         }
     }
     
-    // Attempt compression without assumptions
-    let compressed = risc0_zkvm::default_prover()
-        .compress(&ProverOpts::succinct(), &receipt.inner)
-        .map_err(|e| {
-            if e.to_string().contains("assumption") || e.to_string().contains("unresolved") {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    "Cannot compress composite receipt with unresolved assumptions. \
-                     Either provide the assumption receipts or use the Composer API \
-                     for composition workflows."
-                )
-            } else {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                    format!("Failed to compress receipt: {}", e)
-                )
-            }
-        })?;
+    let compressed = reservation::allow_threads(py, || {
+        risc0_zkvm::default_prover().compress(&ProverOpts::succinct(), &receipt.inner)
+    })
+        .map_err(|e| errors::proving_error(format!("Failed to compress receipt: {}", e)))?;
     
     Ok(Receipt::from_risc0(compressed))
 }
 
+/// Check that a Groth16 prover can run here, with an actionable message if not
+///
+/// Bonsai does the conversion remotely. Locally, RISC Zero's stark-to-snark
+/// step runs in a linux/amd64 Docker image, so it needs an x86_64 host and a
+/// running Docker daemon.
+fn groth16_prover_available() -> Result<(), String> {
+    let bonsai = std::env::var_os("BONSAI_API_URL").is_some() && std::env::var_os("BONSAI_API_KEY").is_some();
+    if bonsai {
+        return Ok(());
+    }
+    
+    if !cfg!(target_arch = "x86_64") {
+        return Err(format!(
+            "Groth16 proving is only supported on x86_64 hosts (this is {}). \
+             Set BONSAI_API_URL and BONSAI_API_KEY to prove remotely, or run on an x86_64 machine.",
+            std::env::consts::ARCH
+        ));
+    }
+    
+    let docker_ok = std::process::Command::new("docker")
+        .arg("info")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if !docker_ok {
+        return Err(
+            "Groth16 proving needs Docker, but `docker info` failed. \
+             Install Docker and make sure the daemon is running and usable by this user, \
+             or set BONSAI_API_URL and BONSAI_API_KEY to prove remotely."
+                .to_string()
+        );
+    }
+    
+    Ok(())
+}
+
+/// Convert a receipt to Groth16 format for on-chain verification
+/// 
+/// Succinct receipts are wrapped directly; composite receipts are first
+/// compressed to succinct. The result is a small SNARK that Ethereum (and
+/// other chains) can verify with RISC Zero's verifier contracts.
+/// 
+/// Args:
+///     receipt: A succinct or composite receipt (composite receipts must
+///              have no unresolved assumptions)
+/// 
+/// Returns:
+///     Receipt: A Groth16 receipt
+/// 
+/// Raises:
+///     ProvingError: If the receipt is already Groth16 or fake, no Groth16
+///                   prover is available (Docker not running, non-x86_64
+///                   host), or conversion fails
+#[pyfunction]
+fn compress_to_groth16(py: Python<'_>, receipt: &Receipt) -> PyResult<Receipt> {
+    use crate::receipt::ReceiptKind;
+    
+    match receipt.kind()? {
+        ReceiptKind::Groth16 => return Err(errors::proving_error("Receipt is already groth16")),
+        ReceiptKind::Fake => return Err(errors::proving_error("Cannot convert a fake receipt to groth16")),
+        ReceiptKind::Composite | ReceiptKind::Succinct => {}
+    }
+    
+    groth16_prover_available().map_err(errors::proving_error)?;
+    
+    let compressed = reservation::allow_threads(py, || {
+        risc0_zkvm::default_prover().compress(&ProverOpts::groth16(), &receipt.inner)
+    })
+        .map_err(|e| errors::proving_error(format!("Failed to convert receipt to groth16: {}", e)))?;
+    
+    Ok(Receipt::from_risc0(compressed))
+}
+
+
 
 
+/// Soft limit on journal size, not enforced by the zkVM
+///
+/// The journal is returned with every receipt and is usually posted on-chain,
+/// so anything larger belongs in a hash commitment instead.
+const RECOMMENDED_MAX_JOURNAL_SIZE: usize = 64 * 1024;
 
-#[pymodule]
+// Safe to run without the GIL on free-threaded builds. Provers and executors
+// are created per call, mutable pyclasses (Composer, InputBuilder) rely on
+// PyO3's runtime borrow checking, and the module-level state is either
+// per-thread or locked:
+// - reservation.rs: the stack of entered reserve_prover() pools is
+//   thread-local, so a reservation only limits proving on the thread that
+//   entered it, and __exit__ refuses to run on any other thread.
+// - pause.rs: paused executions sit in a process-wide Mutex-guarded map keyed
+//   by unique handles, so any thread may resume one; each executor stays on
+//   its own worker thread.
+// - receipt.rs: the thread pool for deadline_ms verification, created once.
+// - profile.rs and pause.rs: atomic counters for scratch file names and handles.
+// Nothing changes process-global settings such as environment variables.
+// Subinterpreters are not supported by PyO3; importing from one raises ImportError.
+#[pymodule(gil_used = false)]
 fn _rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Image>()?;
     m.add_class::<ExitCode>()?;
     m.add_class::<SessionInfo>()?;
+    m.add_class::<SessionDiff>()?;
+    m.add_class::<profile::ProfileReport>()?;
+    m.add_class::<replay::Recording>()?;
+    m.add_class::<HostIO>()?;
     m.add_class::<Receipt>()?;
+    m.add_class::<Segment>()?;
+    m.add_class::<SegmentReceipt>()?;
+    m.add_class::<SuccinctReceipt>()?;
+    m.add_class::<KeccakReceipt>()?;
+    m.add_class::<registry::ImageRegistry>()?;
+    m.add_class::<DeserializationPolicy>()?;
+    m.add_class::<VerificationPolicy>()?;
+    m.add_class::<verifier::VerifierContext>()?;
     m.add_class::<ExitStatus>()?;
     m.add_class::<ExitKind>()?;
     m.add_class::<ReceiptKind>()?;
     m.add_class::<Claim>()?;
     m.add_class::<Composer>()?;
+    m.add_class::<SharedAssumption>()?;
+    m.add_class::<ProverConfig>()?;
+    m.add_class::<ProverReservation>()?;
     m.add_class::<InputBuilder>()?;
+    m.add_class::<GuestManifest>()?;
+    
+    // Guest platform parameters (mirroring risc0-zkvm-platform)
+    m.add("GUEST_MAX_MEM", risc0_zkvm_platform::memory::GUEST_MAX_MEM)?;
+    m.add("PAGE_SIZE", risc0_zkvm_platform::PAGE_SIZE)?;
+    m.add("WORD_SIZE", risc0_zkvm_platform::WORD_SIZE)?;
+    m.add("RECOMMENDED_MAX_JOURNAL_SIZE", RECOMMENDED_MAX_JOURNAL_SIZE)?;
+    m.add("CHECKPOINT_FD", CHECKPOINT_FD)?;
     
     // Core API functions
     m.add_function(wrap_pyfunction!(load_image, m)?)?;
+    m.add_function(wrap_pyfunction!(load_image_from_path, m)?)?;
+    m.add_function(wrap_pyfunction!(load_image_cached, m)?)?;
     m.add_function(wrap_pyfunction!(prove, m)?)?;
     m.add_function(wrap_pyfunction!(prove_with_opts, m)?)?;
     m.add_function(wrap_pyfunction!(prove_succinct, m)?)?;
+    m.add_function(wrap_pyfunction!(prove_fake, m)?)?;
+    m.add_function(wrap_pyfunction!(prove_async, m)?)?;
     m.add_function(wrap_pyfunction!(compute_image_id_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_image_ids, m)?)?;
     m.add_function(wrap_pyfunction!(compress_to_succinct, m)?)?;
+    m.add_function(wrap_pyfunction!(compress_to_groth16, m)?)?;
+    m.add_function(wrap_pyfunction!(reservation::reserve_prover, m)?)?;
+    
+    // Segmented proving for distributed orchestration
+    m.add_function(wrap_pyfunction!(segment::execute_to_segments, m)?)?;
+    m.add_function(wrap_pyfunction!(segment::prove_segment, m)?)?;
+    m.add_function(wrap_pyfunction!(segment::lift, m)?)?;
+    m.add_function(wrap_pyfunction!(segment::join, m)?)?;
+    m.add_function(wrap_pyfunction!(segment::resolve, m)?)?;
+    m.add_function(wrap_pyfunction!(segment::prove_keccak, m)?)?;
+    m.add_function(wrap_pyfunction!(segment::union, m)?)?;
+    m.add_function(wrap_pyfunction!(audit::export_audit_log, m)?)?;
+    m.add_function(wrap_pyfunction!(schema::schema, m)?)?;
+    
+    // Codec primitives (wrapped by pyr0.codec)
+    m.add_function(wrap_pyfunction!(codec::serde_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(codec::serde_decode, m)?)?;
+    
+    // Host-side hashing that matches the zkVM
+    m.add_function(wrap_pyfunction!(poseidon2::poseidon2_hash, m)?)?;
+    
+    // Panic-free parsers (wrapped by pyr0.fuzz)
+    m.add_function(wrap_pyfunction!(fuzz::receipt_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(fuzz::image_from_bytes, m)?)?;
     
     // Optional debugging function
     m.add_function(wrap_pyfunction!(dry_run, m)?)?;
+    m.add_function(wrap_pyfunction!(session::compare_sessions, m)?)?;
+    m.add_function(wrap_pyfunction!(profile::profile, m)?)?;
+    m.add_function(wrap_pyfunction!(replay::replay, m)?)?;
+    m.add_function(wrap_pyfunction!(pause::resume, m)?)?;
+    m.add_function(wrap_pyfunction!(pause::discard_paused, m)?)?;
     
     Ok(())
 }
@@ -0,0 +1,481 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyBytes, PyDict, PyList, PyString};
+use pyo3::IntoPyObjectExt;
+use risc0_zkvm::sha::Digest;
+use serde_json::Value;
+use std::path::PathBuf;
+
+use crate::codec::{WireType, WordReader};
+use crate::errors;
+use crate::input_builder::Field;
+
+/// Type of one manifest field, named like the kinds InputBuilder.layout() reports
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FieldType {
+    U32,
+    U64,
+    I32,
+    I64,
+    F32,
+    F64,
+    /// One byte, 0 or 1
+    Bool,
+    /// Fixed-size bytes: "bytes32", "image_id" or "[u8; N]"
+    Bytes(usize),
+    /// [u64 length][bytes]
+    Frame,
+    /// [u64 length][UTF-8]
+    StrFrame,
+    /// [u64 length][CBOR]
+    CborFrame,
+    /// Unframed CBOR running to the end of the stream
+    Cbor,
+    /// "serde:<type>", read with env::read() / written with env::commit()
+    Serde(WireType),
+}
+
+impl FieldType {
+    fn parse(name: &str) -> PyResult<Self> {
+        Ok(match name {
+            "u32" => FieldType::U32,
+            "u64" => FieldType::U64,
+            "i32" => FieldType::I32,
+            "i64" => FieldType::I64,
+            "f32" => FieldType::F32,
+            "f64" => FieldType::F64,
+            "bool" => FieldType::Bool,
+            "bytes32" | "image_id" => FieldType::Bytes(32),
+            "frame" => FieldType::Frame,
+            "str_frame" => FieldType::StrFrame,
+            "cbor_frame" => FieldType::CborFrame,
+            "cbor" => FieldType::Cbor,
+            _ => {
+                if let Some(serde_type) = name.strip_prefix("serde:") {
+                    FieldType::Serde(WireType::parse(serde_type)?)
+                } else if let Ok(WireType::ArrayU8(n)) = WireType::parse(name) {
+                    FieldType::Bytes(n)
+                } else {
+                    return Err(PyErr::new::<PyValueError, _>(format!(
+                        "Unknown manifest field type '{}'. Use u32/u64/i32/i64/f32/f64, bool, \
+                         bytes32, image_id, [u8; N], frame, str_frame, cbor_frame, cbor or serde:<type>",
+                        name
+                    )));
+                }
+            }
+        })
+    }
+
+    /// Whether an InputBuilder write of this kind and Rust type fills this field
+    fn accepts(&self, kind: &str, rust_type: &str) -> bool {
+        match self {
+            FieldType::U32 | FieldType::U64 | FieldType::I32 | FieldType::I64
+            | FieldType::F32 | FieldType::F64 | FieldType::Bool => kind == rust_type && self.number_name() == Some(kind),
+            FieldType::Bytes(32) => kind == "bytes32",
+            FieldType::Bytes(_) => false,
+            FieldType::Frame => kind == "frame",
+            FieldType::StrFrame => kind == "str_frame",
+            FieldType::CborFrame => kind == "cbor_frame",
+            FieldType::Cbor => kind == "cbor",
+            FieldType::Serde(ty) => kind == "serde" && rust_type == ty.rust_name(),
+        }
+    }
+
+    fn number_name(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldType::U32 => "u32",
+            FieldType::U64 => "u64",
+            FieldType::I32 => "i32",
+            FieldType::I64 => "i64",
+            FieldType::F32 => "f32",
+            FieldType::F64 => "f64",
+            FieldType::Bool => "bool",
+            _ => return None,
+        })
+    }
+}
+
+/// One named field of a manifest layout
+#[derive(Clone, Debug)]
+struct ManifestField {
+    name: String,
+    type_name: String,
+    ty: FieldType,
+}
+
+/// A decoded field and where it was found
+struct DecodedField {
+    name: String,
+    offset: usize,
+    size: usize,
+    value: PyObject,
+}
+
+fn parse_layout(fields: Vec<(String, String)>, what: &str) -> PyResult<Vec<ManifestField>> {
+    let mut parsed: Vec<ManifestField> = Vec::with_capacity(fields.len());
+    for (name, type_name) in fields {
+        if parsed.iter().any(|f| f.name == name) {
+            return Err(PyErr::new::<PyValueError, _>(format!("{} field '{}' is declared twice", what, name)));
+        }
+        if parsed.last().is_some_and(|f| f.ty == FieldType::Cbor) {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "{} field '{}' follows an unframed cbor field, which runs to the end; use cbor_frame",
+                what, name
+            )));
+        }
+        let ty = FieldType::parse(&type_name)?;
+        parsed.push(ManifestField { name, type_name, ty });
+    }
+    Ok(parsed)
+}
+
+/// Read a layout from a manifest document: [{"name": ..., "type": ...}, ...]
+fn layout_from_value(value: Option<&Value>, what: &str) -> PyResult<Vec<(String, String)>> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+    let entries = value.as_array().ok_or_else(|| {
+        PyErr::new::<PyValueError, _>(format!("Manifest '{}' must be a list of fields", what))
+    })?;
+    entries
+        .iter()
+        .map(|entry| {
+            let get = |key: &str| entry.get(key).and_then(Value::as_str).map(str::to_string);
+            match (get("name"), get("type")) {
+                (Some(name), Some(ty)) => Ok((name, ty)),
+                _ => Err(PyErr::new::<PyValueError, _>(format!(
+                    "Every manifest '{}' entry needs string 'name' and 'type' keys, got {}",
+                    what, entry
+                ))),
+            }
+        })
+        .collect()
+}
+
+fn take<'d>(data: &'d [u8], pos: &mut usize, n: usize) -> Result<&'d [u8], String> {
+    let end = pos.checked_add(n).filter(|&end| end <= data.len()).ok_or_else(|| {
+        format!("needs {} bytes, only {} left", n, data.len() - *pos)
+    })?;
+    let bytes = &data[*pos..end];
+    *pos = end;
+    Ok(bytes)
+}
+
+fn take_frame<'d>(data: &'d [u8], pos: &mut usize) -> Result<&'d [u8], String> {
+    let len = u64::from_le_bytes(take(data, pos, 8)?.try_into().unwrap());
+    let left = data.len() - *pos;
+    match usize::try_from(len) {
+        Ok(len) if len <= left => take(data, pos, len),
+        _ => Err(format!("frame declares {} bytes, only {} left", len, left)),
+    }
+}
+
+/// Convert a CBOR value to the matching Python object (tags are dropped)
+fn cbor_to_py(py: Python<'_>, value: &ciborium::Value) -> PyResult<PyObject> {
+    use ciborium::Value;
+    match value {
+        Value::Integer(i) => i128::from(*i).into_py_any(py),
+        Value::Bytes(b) => PyBytes::new(py, b).into_py_any(py),
+        Value::Float(f) => f.into_py_any(py),
+        Value::Text(s) => s.into_py_any(py),
+        Value::Bool(b) => b.into_py_any(py),
+        Value::Null => Ok(py.None()),
+        Value::Tag(_, inner) => cbor_to_py(py, inner),
+        Value::Array(items) => {
+            let items = items.iter().map(|v| cbor_to_py(py, v)).collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, items)?.into_py_any(py)
+        }
+        Value::Map(entries) => {
+            let dict = PyDict::new(py);
+            for (k, v) in entries {
+                dict.set_item(cbor_to_py(py, k)?, cbor_to_py(py, v)?)?;
+            }
+            dict.into_py_any(py)
+        }
+        _ => Err(PyErr::new::<PyValueError, _>("unsupported CBOR value")),
+    }
+}
+
+fn decode_cbor(py: Python<'_>, bytes: &[u8]) -> Result<PyObject, String> {
+    crate::receipt::check_cbor_lengths(bytes).map_err(|e| format!("invalid CBOR: {}", e))?;
+    let value: ciborium::Value = ciborium::from_reader(bytes).map_err(|e| format!("invalid CBOR: {}", e))?;
+    cbor_to_py(py, &value).map_err(|e| e.to_string())
+}
+
+/// Decode one field at `pos`, advancing it
+fn decode_field(py: Python<'_>, ty: FieldType, data: &[u8], pos: &mut usize) -> Result<PyObject, String> {
+    let py_err = |e: PyErr| e.to_string();
+    match ty {
+        FieldType::U32 => u32::from_le_bytes(take(data, pos, 4)?.try_into().unwrap()).into_py_any(py).map_err(py_err),
+        FieldType::U64 => u64::from_le_bytes(take(data, pos, 8)?.try_into().unwrap()).into_py_any(py).map_err(py_err),
+        FieldType::I32 => i32::from_le_bytes(take(data, pos, 4)?.try_into().unwrap()).into_py_any(py).map_err(py_err),
+        FieldType::I64 => i64::from_le_bytes(take(data, pos, 8)?.try_into().unwrap()).into_py_any(py).map_err(py_err),
+        FieldType::F32 => f32::from_le_bytes(take(data, pos, 4)?.try_into().unwrap()).into_py_any(py).map_err(py_err),
+        FieldType::F64 => f64::from_le_bytes(take(data, pos, 8)?.try_into().unwrap()).into_py_any(py).map_err(py_err),
+        FieldType::Bool => match take(data, pos, 1)?[0] {
+            0 => false.into_py_any(py).map_err(py_err),
+            1 => true.into_py_any(py).map_err(py_err),
+            b => Err(format!("invalid bool byte {}", b)),
+        },
+        FieldType::Bytes(n) => PyBytes::new(py, take(data, pos, n)?).into_py_any(py).map_err(py_err),
+        FieldType::Frame => PyBytes::new(py, take_frame(data, pos)?).into_py_any(py).map_err(py_err),
+        FieldType::StrFrame => {
+            let text = std::str::from_utf8(take_frame(data, pos)?).map_err(|e| format!("invalid UTF-8: {}", e))?;
+            PyString::new(py, text).into_py_any(py).map_err(py_err)
+        }
+        FieldType::CborFrame => decode_cbor(py, take_frame(data, pos)?),
+        FieldType::Cbor => decode_cbor(py, take(data, pos, data.len() - *pos)?),
+        FieldType::Serde(wire) => {
+            let mut reader = WordReader::new(&data[*pos..]);
+            let value = reader.read(py, wire).map_err(py_err)?;
+            *pos += reader.position();
+            Ok(value)
+        }
+    }
+}
+
+/// Decode `data` field by field; each problem is reported as one string
+fn decode_fields(py: Python<'_>, fields: &[ManifestField], data: &[u8]) -> Result<Vec<DecodedField>, String> {
+    let mut decoded = Vec::with_capacity(fields.len());
+    let mut pos = 0;
+    for field in fields {
+        let offset = pos;
+        let value = decode_field(py, field.ty, data, &mut pos).map_err(|e| {
+            format!("field '{}' ({}) at offset {}: {}", field.name, field.type_name, offset, e)
+        })?;
+        decoded.push(DecodedField { name: field.name.clone(), offset, size: pos - offset, value });
+    }
+    if pos != data.len() {
+        return Err(format!("{} bytes left over after the last declared field", data.len() - pos));
+    }
+    Ok(decoded)
+}
+
+/// The input and journal layout a guest expects, shipped alongside it
+///
+/// Declares each field the guest reads from its input and commits to its
+/// journal, by name and type. Types are named like InputBuilder.layout()
+/// kinds: u32/u64/i32/i64/f32/f64 and bool (env::read_slice()), bytes32,
+/// image_id or "[u8; N]" (fixed bytes), frame, str_frame, cbor_frame
+/// ([u64 length][data]), cbor (unframed, to the end), and "serde:<type>"
+/// for values read with env::read() or committed with env::commit().
+///
+/// A TOML manifest looks like:
+///
+///     name = "outer"
+///     image_id = "3a6f..."        # optional; checked against the image
+///     input = [
+///         { name = "sum", type = "u32" },
+///         { name = "inner_id", type = "image_id" },
+///     ]
+///     journal = [
+///         { name = "doubled", type = "u32" },
+///     ]
+///
+/// The JSON form has the same keys. Pass it to prove(..., manifest=m) or
+/// Composer.prove(manifest=m) to check the input before proving.
+#[pyclass(module = "pyr0", frozen)]
+pub struct GuestManifest {
+    /// Guest name, if the manifest gives one
+    #[pyo3(get)]
+    pub name: Option<String>,
+    image_id: Option<Digest>,
+    input: Vec<ManifestField>,
+    journal: Vec<ManifestField>,
+}
+
+#[pymethods]
+impl GuestManifest {
+    /// Declare a manifest directly
+    ///
+    /// Args:
+    ///     input: (name, type) pairs the guest reads, in order
+    ///     journal: (name, type) pairs the guest commits, in order
+    ///     name: Optional guest name
+    ///     image_id: Optional 32-byte image ID the manifest belongs to
+    ///
+    /// Raises:
+    ///     ValueError: On unknown types, repeated names, or a field after
+    ///                 an unframed cbor field
+    #[new]
+    #[pyo3(signature = (input=Vec::new(), journal=Vec::new(), name=None, image_id=None))]
+    pub fn new(
+        input: Vec<(String, String)>,
+        journal: Vec<(String, String)>,
+        name: Option<String>,
+        image_id: Option<Vec<u8>>,
+    ) -> PyResult<Self> {
+        Ok(GuestManifest {
+            name,
+            image_id: image_id.map(|id| crate::receipt::digest_from_bytes(&id)).transpose()?,
+            input: parse_layout(input, "Input")?,
+            journal: parse_layout(journal, "Journal")?,
+        })
+    }
+
+    /// Parse a JSON manifest
+    #[staticmethod]
+    pub fn from_json(text: &str) -> PyResult<Self> {
+        let value: Value = serde_json::from_str(text)
+            .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid JSON manifest: {}", e)))?;
+        Self::from_value(&value)
+    }
+
+    /// Parse a TOML manifest
+    #[staticmethod]
+    pub fn from_toml(text: &str) -> PyResult<Self> {
+        let value: Value = toml::from_str(text)
+            .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid TOML manifest: {}", e)))?;
+        Self::from_value(&value)
+    }
+
+    /// Load a manifest file: TOML if it ends in .toml, JSON otherwise
+    #[staticmethod]
+    pub fn load(path: PathBuf) -> PyResult<Self> {
+        let text = std::fs::read_to_string(&path).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml")) {
+            Self::from_toml(&text)
+        } else {
+            Self::from_json(&text)
+        }
+    }
+
+    /// Image ID the manifest belongs to, if it names one
+    #[getter]
+    pub fn image_id(&self) -> Option<Vec<u8>> {
+        self.image_id.map(|id| id.as_bytes().to_vec())
+    }
+
+    /// Declared input fields as (name, type) pairs
+    #[getter]
+    pub fn input(&self) -> Vec<(String, String)> {
+        self.input.iter().map(|f| (f.name.clone(), f.type_name.clone())).collect()
+    }
+
+    /// Declared journal fields as (name, type) pairs
+    #[getter]
+    pub fn journal(&self) -> Vec<(String, String)> {
+        self.journal.iter().map(|f| (f.name.clone(), f.type_name.clone())).collect()
+    }
+
+    /// Check input bytes against the declared input layout
+    ///
+    /// Raises:
+    ///     PreflightError: Listing where the input departs from the layout
+    pub fn validate_input(&self, py: Python<'_>, data: &[u8]) -> PyResult<()> {
+        self.check_input(py, data, None, None)
+    }
+
+    /// Decode journal bytes into a dict using the declared journal layout
+    ///
+    /// Numbers decode to int/float, fixed bytes and frames to bytes,
+    /// str_frame to str, CBOR fields to the matching Python objects, and
+    /// serde fields as in pyr0.codec.commit_decode(). See also
+    /// Receipt.decode_journal().
+    ///
+    /// Raises:
+    ///     ValueError: If the journal doesn't match the declared layout
+    pub fn decode_journal<'py>(&self, py: Python<'py>, journal: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+        let decoded = decode_fields(py, &self.journal, journal).map_err(|e| {
+            PyErr::new::<PyValueError, _>(format!(
+                "Journal doesn't match the {} manifest: {}",
+                self.name.as_deref().unwrap_or("guest"),
+                e
+            ))
+        })?;
+        let fields = PyDict::new(py);
+        for field in decoded {
+            fields.set_item(field.name, field.value)?;
+        }
+        Ok(fields)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "GuestManifest(name={:?}, input={}, journal={})",
+            self.name,
+            self.input.len(),
+            self.journal.len()
+        )
+    }
+}
+
+impl GuestManifest {
+    fn from_value(value: &Value) -> PyResult<Self> {
+        if !value.is_object() {
+            return Err(PyErr::new::<PyValueError, _>("A manifest must be a table/object"));
+        }
+        let name = value.get("name").and_then(Value::as_str).map(str::to_string);
+        let image_id = value
+            .get("image_id")
+            .and_then(Value::as_str)
+            .map(crate::receipt::digest_from_hex)
+            .transpose()?;
+        Ok(GuestManifest {
+            name,
+            image_id,
+            input: parse_layout(layout_from_value(value.get("input"), "input")?, "Input")?,
+            journal: parse_layout(layout_from_value(value.get("journal"), "journal")?, "Journal")?,
+        })
+    }
+
+    /// Image ID the manifest names, if any
+    pub(crate) fn expected_image_id(&self) -> Option<Digest> {
+        self.image_id
+    }
+
+    /// Check input bytes, plus the writes that produced them and the image
+    /// ID when known, raising PreflightError with every problem found
+    ///
+    /// Typed writes (everything but write_raw_bytes) must start where a
+    /// declared field starts and have that field's type and size; raw
+    /// writes are only checked byte-wise.
+    pub(crate) fn check_input(
+        &self,
+        py: Python<'_>,
+        data: &[u8],
+        writes: Option<&[Field]>,
+        image_id: Option<&[u8]>,
+    ) -> PyResult<()> {
+        let mut issues = Vec::new();
+        if let (Some(expected), Some(actual)) = (self.image_id, image_id) {
+            if expected.as_bytes() != actual {
+                issues.push(format!(
+                    "manifest is for image {}, not {}",
+                    hex::encode(expected.as_bytes()),
+                    hex::encode(actual)
+                ));
+            }
+        }
+        match decode_fields(py, &self.input, data) {
+            Ok(decoded) => {
+                for write in writes.unwrap_or_default().iter().filter(|w| w.kind != "raw") {
+                    let declared = decoded.iter().zip(&self.input).find(|(d, _)| d.offset == write.offset);
+                    match declared {
+                        Some((d, field)) if d.size == write.size && field.ty.accepts(write.kind, &write.rust_type) => {}
+                        Some((_, field)) => issues.push(format!(
+                            "write_{}() at offset {} wrote {} ({} bytes) where field '{}' expects {}",
+                            write.kind, write.offset, write.rust_type, write.size, field.name, field.type_name
+                        )),
+                        None => issues.push(format!(
+                            "write_{}() at offset {} doesn't line up with any declared field",
+                            write.kind, write.offset
+                        )),
+                    }
+                }
+            }
+            Err(e) => issues.push(e),
+        }
+        if issues.is_empty() {
+            return Ok(());
+        }
+        let message = format!(
+            "Input doesn't match the {} manifest:\n{}",
+            self.name.as_deref().unwrap_or("guest"),
+            issues.iter().map(|i| format!("  - {}", i)).collect::<Vec<_>>().join("\n")
+        );
+        Err(errors::preflight_error(message, issues))
+    }
+}
@@ -0,0 +1,294 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::{get_prover_server, ExecutorEnv, ExecutorEnvBuilder, ExecutorImpl, ProverOpts, VerifierContext};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::io::Read;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::errors;
+use crate::image::Image;
+use crate::prover_config::ProverConfig;
+use crate::receipt::Receipt;
+use crate::session::SessionInfo;
+
+/// Most paused executions held at once; each keeps the guest's whole memory
+const MAX_PAUSED: usize = 64;
+
+/// How long a paused execution waits for resume() before it is dropped
+const PAUSE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Guest stdin that more input can be appended to between runs
+#[derive(Clone, Default)]
+struct InputQueue(Rc<RefCell<VecDeque<u8>>>);
+
+impl Read for InputQueue {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().read(buf)
+    }
+}
+
+/// What one stretch of a pausable execution produced
+pub(crate) enum Outcome {
+    Session(SessionInfo),
+    Receipt(risc0_zkvm::Receipt),
+}
+
+/// An outcome and, if the execution paused, the handle to resume it with
+type Reply = anyhow::Result<(Outcome, Option<u64>)>;
+
+/// Ask a paused execution to continue
+struct Resume {
+    more_input: Vec<u8>,
+    /// Prover options for the next stretch, or None to only execute
+    opts: Option<ProverOpts>,
+    reply: Sender<Reply>,
+}
+
+/// A paused execution, as seen from outside its worker thread
+struct Held {
+    image_id: Digest,
+    commands: Sender<Resume>,
+}
+
+/// Paused executions by handle
+///
+/// The executor isn't Send, so each one stays on a worker thread of its
+/// own; the map only holds the channels to reach them. Dropping an entry
+/// ends its worker.
+fn paused() -> &'static Mutex<HashMap<u64, Held>> {
+    static PAUSED: OnceLock<Mutex<HashMap<u64, Held>>> = OnceLock::new();
+    PAUSED.get_or_init(Mutex::default)
+}
+
+fn lock_paused() -> std::sync::MutexGuard<'static, HashMap<u64, Held>> {
+    paused().lock().unwrap_or_else(|p| p.into_inner())
+}
+
+/// Run the executor to its next stop, proving the stretch if `opts` is given
+fn step(executor: &mut ExecutorImpl<'static>, opts: Option<&ProverOpts>) -> anyhow::Result<(Outcome, bool)> {
+    let session = executor.run()?;
+    let paused = matches!(session.exit_code, risc0_zkvm::ExitCode::Paused(_));
+    let outcome = match opts {
+        None => Outcome::Session(SessionInfo::new(&session)?),
+        Some(opts) => {
+            let prover = get_prover_server(opts)?;
+            Outcome::Receipt(prover.prove_session(&VerifierContext::default(), &session)?.receipt)
+        }
+    };
+    Ok((outcome, paused))
+}
+
+/// Wait for resume() on a held execution; None once it expires or is discarded
+fn wait(handle: u64, commands: Receiver<Resume>) -> Option<Resume> {
+    loop {
+        match commands.recv_timeout(PAUSE_TTL) {
+            Ok(command) => return Some(command),
+            Err(RecvTimeoutError::Disconnected) => return None,
+            // If resume() already took the entry, its command is on the way
+            Err(RecvTimeoutError::Timeout) => {
+                if lock_paused().remove(&handle).is_some() {
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Body of a pausable execution's worker thread
+fn worker(
+    image_id: Digest,
+    mut executor: ExecutorImpl<'static>,
+    input: InputQueue,
+    mut opts: Option<ProverOpts>,
+    mut reply: Sender<Reply>,
+) {
+    static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+    loop {
+        let result = step(&mut executor, opts.as_ref());
+        let held = match &result {
+            Ok((_, true)) => {
+                let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+                let (commands, received) = mpsc::channel();
+                lock_paused().insert(handle, Held { image_id, commands });
+                Some((handle, received))
+            }
+            _ => None,
+        };
+        let handle = held.as_ref().map(|(handle, _)| *handle);
+        if reply.send(result.map(|(outcome, _)| (outcome, handle))).is_err() {
+            // The caller is gone, so nobody can resume this execution
+            if let Some(handle) = handle {
+                lock_paused().remove(&handle);
+            }
+            return;
+        }
+        let Some((handle, received)) = held else { return };
+        let Some(command) = wait(handle, received) else { return };
+        input.0.borrow_mut().extend(command.more_input);
+        opts = command.opts;
+        reply = command.reply;
+    }
+}
+
+/// Start an execution that can be resumed after env::pause()
+///
+/// It runs on a worker thread of its own, which keeps the executor if the
+/// guest pauses. `configure` adds everything but stdin to the environment;
+/// with `opts` the first stretch is proven as well. Call with the GIL released.
+pub(crate) fn start(
+    image_id: Digest,
+    memory_image: risc0_binfmt::MemoryImage,
+    input: Vec<u8>,
+    opts: Option<ProverOpts>,
+    configure: impl FnOnce(&mut ExecutorEnvBuilder<'static>) -> anyhow::Result<()> + Send + 'static,
+) -> Reply {
+    if lock_paused().len() >= MAX_PAUSED {
+        anyhow::bail!(
+            "{MAX_PAUSED} paused executions are already held; resume them or call discard_paused() first"
+        );
+    }
+    let (reply, result) = mpsc::channel();
+    std::thread::Builder::new()
+        .name("pyr0-paused".to_string())
+        .spawn(move || {
+            let queue = InputQueue::default();
+            queue.0.borrow_mut().extend(input);
+            let executor = (|| -> anyhow::Result<_> {
+                let mut builder = ExecutorEnv::builder();
+                builder.stdin(queue.clone());
+                configure(&mut builder)?;
+                ExecutorImpl::new(builder.build()?, memory_image)
+            })();
+            match executor {
+                Ok(executor) => worker(image_id, executor, queue, opts, reply),
+                Err(e) => {
+                    let _ = reply.send(Err(e));
+                }
+            }
+        })?;
+    result.recv().map_err(|_| anyhow::anyhow!("Pausable execution thread panicked"))?
+}
+
+/// Where a paused execution stopped: a SessionInfo or Receipt of it, or its handle
+#[derive(FromPyObject)]
+pub enum PausePoint {
+    Session(SessionInfo),
+    Receipt(Receipt),
+    Handle(u64),
+}
+
+/// Continue a paused execution with more input
+///
+/// A guest that calls env::pause() stops with ExitKind.PAUSED. If it was
+/// started with pausable=True (dry_run() or prove()), the execution is kept
+/// in memory under a unique handle (the pause_handle of the returned
+/// SessionInfo or Receipt), and resume() picks it up where it stopped:
+/// more_input is appended to the guest's stdin and the guest runs on from
+/// env::pause(). A SessionInfo continues as a dry run, a Receipt as a proof
+/// of the next stretch; its pre_state_digest equals prior's
+/// post_state_digest, which is how a verifier links the receipts.
+///
+/// Each pause resumes once, from any thread; the GIL is released while the
+/// guest runs and is proven. At most 64 paused executions are held, each
+/// with the guest's whole memory. One not resumed within 10 minutes is
+/// dropped, as are all of them on discard_paused().
+///
+/// Args:
+///     image: The Image the paused execution is running
+///     prior: SessionInfo or Receipt of the run that paused, or its
+///            pause_handle
+///     more_input: Bytes appended to the guest's stdin before it continues
+///     config: Optional ProverConfig for proving the next stretch (a Receipt
+///             prior only). Segment sizes are fixed when the execution starts.
+///
+/// Returns:
+///     SessionInfo or Receipt (matching prior; a Receipt for a handle) for
+///     the next stretch, which may itself be PAUSED again
+///
+/// Raises:
+///     ValueError: If prior didn't pause, its execution was already resumed,
+///                 expired or discarded, or it runs a different image
+///     ProvingError: If execution or proving fails
+///
+/// Example:
+///     info = pyr0.dry_run(image, first_batch, pausable=True)
+///     while info.exit.kind == pyr0.ExitKind.PAUSED:
+///         info = pyr0.resume(image, info, next_batch())
+#[pyfunction]
+#[pyo3(signature = (image, prior, more_input=Vec::new(), config=None))]
+pub fn resume(
+    py: Python<'_>,
+    image: &Image,
+    prior: PausePoint,
+    more_input: Vec<u8>,
+    config: Option<ProverConfig>,
+) -> PyResult<PyObject> {
+    let (handle, prove) = match &prior {
+        PausePoint::Session(info) => (info.pause_handle, false),
+        PausePoint::Receipt(receipt) => (receipt.pause_handle, true),
+        PausePoint::Handle(handle) => (Some(*handle), true),
+    };
+    if config.is_some() && !prove {
+        return Err(PyErr::new::<PyValueError, _>("config only applies when resuming a proof"));
+    }
+    let Some(handle) = handle else {
+        return Err(PyErr::new::<PyValueError, _>(
+            "prior has no paused execution (start it with pausable=True; only PAUSED runs can be resumed)"
+        ));
+    };
+    let image_id = crate::receipt::digest_from_bytes(&image.id()?)?;
+
+    let held = {
+        let mut held = lock_paused();
+        match held.get(&handle) {
+            None => return Err(PyErr::new::<PyValueError, _>(
+                "No paused execution is held for prior (each pause resumes once; \
+                 held executions expire after 10 minutes and on discard_paused())"
+            )),
+            Some(entry) if entry.image_id != image_id => {
+                return Err(PyErr::new::<PyValueError, _>(format!(
+                    "The paused execution is running image {}",
+                    hex::encode(entry.image_id.as_bytes())
+                )));
+            }
+            Some(_) => held.remove(&handle).expect("entry was just found"),
+        }
+    };
+    let opts = prove.then(|| {
+        let mut opts = ProverOpts::default();
+        if let Some(config) = &config {
+            config.apply_opts(&mut opts);
+        }
+        opts
+    });
+
+    let (reply, result) = mpsc::channel();
+    held.commands
+        .send(Resume { more_input, opts, reply })
+        .map_err(|_| errors::proving_error("The paused execution is no longer running"))?;
+    let (outcome, handle) = py
+        .allow_threads(move || result.recv().map_err(|_| anyhow::anyhow!("Pausable execution thread panicked"))?)
+        .map_err(|e| errors::proving_error(format!("Resumed execution failed: {e}")))?;
+    match outcome {
+        Outcome::Session(info) => Ok(info.with_pause_handle(handle).into_pyobject(py)?.into_any().unbind()),
+        Outcome::Receipt(receipt) => {
+            let receipt = Receipt::from_risc0(receipt).with_pause_handle(handle);
+            Ok(receipt.into_pyobject(py)?.into_any().unbind())
+        }
+    }
+}
+
+/// Drop every held paused execution
+///
+/// Returns:
+///     int: How many were dropped
+#[pyfunction]
+pub fn discard_paused() -> usize {
+    lock_paused().drain().count()
+}
@@ -0,0 +1,40 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use risc0_zkp::core::hash::poseidon2::Poseidon2HashSuite;
+use risc0_zkp::field::baby_bear::{BabyBearElem, P};
+
+/// Hash BabyBear field elements with RISC Zero's Poseidon2
+///
+/// This is the same sponge the zkVM uses for its recursion circuit and
+/// control roots (Poseidon2HashSuite's hash_elem_slice), so a guest hashing
+/// the same elements gets the same digest. The hash is unpadded: inputs of
+/// different lengths can collide by length extension, so commit to a fixed
+/// number of elements (or include the length as an element).
+///
+/// Args:
+///     inputs: Field elements as ints in [0, 2013265921)
+///
+/// Returns:
+///     bytes: The 32-byte digest, 8 little-endian words in Montgomery form,
+///     exactly as risc0's Digest stores it
+///
+/// Raises:
+///     ValueError: If an input is not a canonical BabyBear element
+///
+/// Example:
+///     leaf = pyr0.poseidon2_hash([k_pub_lo, k_pub_hi, nonce])
+#[pyfunction]
+pub fn poseidon2_hash(inputs: Vec<u64>) -> PyResult<Vec<u8>> {
+    let elems = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| match u32::try_from(value) {
+            Ok(value) if value < P => Ok(BabyBearElem::new(value)),
+            _ => Err(PyErr::new::<PyValueError, _>(format!(
+                "Input {i} ({value}) is not a BabyBear field element (must be < {P})"
+            ))),
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let digest = Poseidon2HashSuite::new_suite().hashfn.hash_elem_slice(&elems);
+    Ok(digest.as_bytes().to_vec())
+}
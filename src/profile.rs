@@ -0,0 +1,288 @@
+use pyo3::prelude::*;
+use risc0_zkvm::ExecutorEnv;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::errors;
+use crate::guest_input::GuestInput;
+use crate::image::Image;
+use crate::session::SessionInfo;
+
+/// Cycle profile of one execution, from profile()
+#[pyclass(module = "pyr0", frozen)]
+pub struct ProfileReport {
+    /// The profile in pprof format (uncompressed protobuf), readable by
+    /// `go tool pprof` and other pprof viewers
+    #[pyo3(get)]
+    pub pprof: Vec<u8>,
+    /// Summary of the profiled execution
+    #[pyo3(get)]
+    pub session: SessionInfo,
+    /// Cycles spent inside each guest syscall wrapper (sys_read, sys_write,
+    /// sys_sha_buffer, ...), including whatever they call
+    #[pyo3(get)]
+    pub syscalls: HashMap<String, u64>,
+    /// Cycles spent in each function's own code, excluding callees
+    functions: Vec<(String, u64)>,
+}
+
+impl ProfileReport {
+    /// Summarize a pprof profile of `session`
+    fn new(pprof: Vec<u8>, session: SessionInfo) -> PyResult<Self> {
+        let samples = parse_pprof(&pprof)
+            .map_err(|e| errors::serialization_error(format!("Malformed pprof profile: {e}")))?;
+        let (functions, syscalls) = summarize(&samples);
+        Ok(Self { pprof, session, syscalls, functions })
+    }
+}
+
+#[pymethods]
+impl ProfileReport {
+    /// Load a profile written by save() or profile(output=...)
+    ///
+    /// Args:
+    ///     path: The pprof file
+    ///     session: The SessionInfo of the profiled execution
+    ///
+    /// Raises:
+    ///     SerializationError: If the file can't be read or isn't a
+    ///                         well-formed pprof profile (e.g. truncated)
+    #[staticmethod]
+    pub fn load(path: PathBuf, session: SessionInfo) -> PyResult<Self> {
+        let pprof = std::fs::read(&path)
+            .map_err(|e| errors::serialization_error(format!("Failed to read {}: {}", path.display(), e)))?;
+        Self::new(pprof, session)
+    }
+
+    /// Cycles covered by the profile's samples
+    #[getter]
+    pub fn sampled_cycles(&self) -> u64 {
+        self.functions.iter().map(|(_, cycles)| cycles).sum()
+    }
+
+    /// The n functions with the most self cycles, as (name, cycles), busiest first
+    #[pyo3(signature = (n=10))]
+    pub fn top_functions(&self, n: usize) -> Vec<(String, u64)> {
+        self.functions.iter().take(n).cloned().collect()
+    }
+
+    /// Human-readable report: cycle totals, syscalls, then the busiest functions
+    #[pyo3(signature = (n=10))]
+    pub fn summary(&self, n: usize) -> String {
+        let mut lines = vec![format!(
+            "user_cycles: {}, total_cycles: {}, segments: {}",
+            self.session.user_cycles, self.session.total_cycles, self.session.segment_count
+        )];
+        let mut syscalls: Vec<_> = self.syscalls.iter().collect();
+        syscalls.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        lines.push("syscalls:".to_string());
+        lines.extend(syscalls.iter().map(|(name, cycles)| format!("  {cycles:>12}  {name}")));
+        lines.push("functions (self cycles):".to_string());
+        lines.extend(self.top_functions(n).iter().map(|(name, cycles)| format!("  {cycles:>12}  {name}")));
+        lines.join("\n")
+    }
+
+    /// Write the pprof profile to a file
+    pub fn save(&self, path: PathBuf) -> PyResult<()> {
+        std::fs::write(&path, &self.pprof)
+            .map_err(|e| errors::serialization_error(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ProfileReport(user_cycles={}, sampled_cycles={}, functions={}, syscalls={})",
+            self.session.user_cycles,
+            self.sampled_cycles(),
+            self.functions.len(),
+            self.syscalls.len()
+        )
+    }
+}
+
+/// Run a guest under RISC Zero's profiler, without proving
+///
+/// Every cycle is attributed to the guest call stack it ran in, so hotspots
+/// can be found from Python without a separate Rust harness. Set
+/// RISC0_PPROF_ENABLE_INLINE_FUNCTIONS=yes to see inlined frames too.
+///
+/// Args:
+///     image: The Image containing the RISC-V ELF (with symbols; a stripped
+///            ELF profiles as one anonymous function)
+///     input_bytes: Input data for the guest program (see prove())
+///     output: Optional path to also write the pprof profile to
+///
+/// Returns:
+///     ProfileReport: The pprof profile, the per-syscall cycle summary and
+///     the busiest functions
+///
+/// Raises:
+///     ProvingError: If execution fails or the profile can't be written
+///     SerializationError: If the profiler's output isn't a well-formed
+///                         pprof profile
+///
+/// Example:
+///     report = pyr0.profile(image, data, output="guest.pb")
+///     print(report.summary())
+///     # go tool pprof -top guest.pb
+#[pyfunction]
+#[pyo3(signature = (image, input_bytes, output=None))]
+pub fn profile(
+    py: Python<'_>,
+    image: &Image,
+    input_bytes: &Bound<'_, PyAny>,
+    output: Option<PathBuf>,
+) -> PyResult<ProfileReport> {
+    let mut input = GuestInput::extract(input_bytes)?;
+    let elf = image.get_elf();
+
+    // RISC Zero's profiler only writes to a file
+    let (path, temporary) = match output {
+        Some(path) => (path, false),
+        None => (scratch_path(), true),
+    };
+    let result = py.allow_threads(|| -> anyhow::Result<(SessionInfo, Vec<u8>)> {
+        let mut builder = ExecutorEnv::builder();
+        input.attach(&mut builder);
+        builder.enable_profiler(&path);
+        let env = builder.build()?;
+        let session = risc0_zkvm::ExecutorImpl::from_elf(env, elf)?.run()?;
+        let pprof = std::fs::read(&path)
+            .map_err(|e| anyhow::anyhow!("failed to read profile {}: {e}", path.display()))?;
+        Ok((SessionInfo::new(&session)?, pprof))
+    });
+    if temporary {
+        let _ = std::fs::remove_file(&path);
+    }
+    let (session, pprof) = result.map_err(|e| errors::proving_error(format!("Profiling failed: {e}")))?;
+
+    ProfileReport::new(pprof, session)
+}
+
+/// Unique file in the temp directory for a profile nobody asked to keep
+fn scratch_path() -> PathBuf {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    std::env::temp_dir().join(format!(
+        "pyr0-profile-{}-{}.pb",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// Demangled function name, without the trailing hash
+fn display_name(name: &str) -> String {
+    format!("{:#}", rustc_demangle::demangle(name))
+}
+
+/// Self cycles per function (busiest first) and cycles per guest syscall
+fn summarize(samples: &[Sample]) -> (Vec<(String, u64)>, HashMap<String, u64>) {
+    let mut functions: HashMap<String, u64> = HashMap::new();
+    let mut syscalls: HashMap<String, u64> = HashMap::new();
+    for sample in samples {
+        let Some(leaf) = sample.stack.first() else { continue };
+        *functions.entry(display_name(leaf)).or_default() += sample.cycles;
+        // Attribute to the innermost syscall wrapper on the stack, if any
+        let syscall = sample.stack.iter().map(|name| display_name(name)).find_map(|name| {
+            let short = name.rsplit("::").next().unwrap_or(&name);
+            short.starts_with("sys_").then(|| short.to_string())
+        });
+        if let Some(syscall) = syscall {
+            *syscalls.entry(syscall).or_default() += sample.cycles;
+        }
+    }
+    let mut functions: Vec<_> = functions.into_iter().collect();
+    functions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    (functions, syscalls)
+}
+
+/// One pprof sample: a call stack (leaf first) and the cycles spent in it
+struct Sample {
+    stack: Vec<String>,
+    cycles: u64,
+}
+
+/// The parts of pprof's profile.proto that profile() reads
+///
+/// Same field numbers and types as the prost-generated code for
+/// github.com/google/pprof/proto/profile.proto; other fields are skipped.
+mod proto {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Profile {
+        #[prost(message, repeated, tag = "2")]
+        pub sample: Vec<Sample>,
+        #[prost(message, repeated, tag = "4")]
+        pub location: Vec<Location>,
+        #[prost(message, repeated, tag = "5")]
+        pub function: Vec<Function>,
+        #[prost(string, repeated, tag = "6")]
+        pub string_table: Vec<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Sample {
+        #[prost(uint64, repeated, tag = "1")]
+        pub location_id: Vec<u64>,
+        #[prost(int64, repeated, tag = "2")]
+        pub value: Vec<i64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Location {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(message, repeated, tag = "4")]
+        pub line: Vec<Line>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Line {
+        #[prost(uint64, tag = "1")]
+        pub function_id: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Function {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(int64, tag = "2")]
+        pub name: i64,
+    }
+}
+
+/// Resolve a pprof Profile's samples into named call stacks
+///
+/// Err describes why the profile is malformed: it doesn't decode (e.g.
+/// it's truncated), or a sample refers to a location, function or string
+/// the profile doesn't have.
+fn parse_pprof(data: &[u8]) -> Result<Vec<Sample>, String> {
+    use prost::Message;
+
+    let profile = proto::Profile::decode(data).map_err(|e| e.to_string())?;
+    let functions: HashMap<u64, &proto::Function> = profile.function.iter().map(|f| (f.id, f)).collect();
+    let locations: HashMap<u64, &proto::Location> = profile.location.iter().map(|l| (l.id, l)).collect();
+
+    // A location without line info (e.g. a stripped ELF) has no function name
+    let name = |id: &u64| -> Result<String, String> {
+        let location = locations.get(id).ok_or_else(|| format!("sample refers to missing location {id}"))?;
+        let Some(line) = location.line.first() else { return Ok("<unknown>".to_string()) };
+        let function = functions
+            .get(&line.function_id)
+            .ok_or_else(|| format!("location {id} refers to missing function {}", line.function_id))?;
+        let name = usize::try_from(function.name)
+            .ok()
+            .and_then(|index| profile.string_table.get(index))
+            .ok_or_else(|| format!("function {} has no name string {}", function.id, function.name))?;
+        Ok(if name.is_empty() { "<unknown>".to_string() } else { name.clone() })
+    };
+    profile
+        .sample
+        .iter()
+        .map(|sample| {
+            let cycles = sample.value.first().copied().unwrap_or(0);
+            Ok(Sample {
+                stack: sample.location_id.iter().map(&name).collect::<Result<_, _>>()?,
+                cycles: u64::try_from(cycles).map_err(|_| format!("sample has negative cycles {cycles}"))?,
+            })
+        })
+        .collect()
+}
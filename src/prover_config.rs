@@ -0,0 +1,166 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use risc0_zkvm::{ExecutorEnvBuilder, LocalProver, Prover, ProverOpts};
+use std::rc::Rc;
+
+/// Segment size limits in log2 cycles (risc0_zkp::MIN_CYCLES_PO2 / MAX_CYCLES_PO2)
+const MIN_SEGMENT_PO2: u32 = 13;
+const MAX_SEGMENT_PO2: u32 = 24;
+
+/// Keccak coprocessor segment limits in log2 cycles (risc0_circuit_keccak::KECCAK_PO2_RANGE)
+const MIN_KECCAK_PO2: u32 = 14;
+const MAX_KECCAK_PO2: u32 = 18;
+
+/// Hash functions the STARK prover supports
+const HASHFNS: &[&str] = &["poseidon2", "sha-256"];
+
+/// The accelerator this build of PyR0 proves on
+///
+/// RISC Zero picks the proving HAL at compile time, so this follows the
+/// `cuda` / `metal` Cargo features rather than anything set at runtime.
+pub(crate) fn compiled_backend() -> &'static str {
+    if cfg!(feature = "cuda") {
+        "cuda"
+    } else if cfg!(feature = "metal") {
+        "metal"
+    } else {
+        "cpu"
+    }
+}
+
+/// Explicit prover settings for prove_with_opts()
+///
+/// Without a config, PyR0 uses RISC Zero's default prover, which consults
+/// environment variables (RISC0_PROVER, BONSAI_API_URL, ...). With one,
+/// proving always runs in-process on the requested backend.
+///
+/// Args:
+///     backend: "cpu", "cuda" or "metal" (default: the backend this build
+///              was compiled for). The backend is fixed at build time, so
+///              asking for another one raises instead of silently falling back.
+///     hashfn: Hash function for the STARK proof: "poseidon2" (default) or "sha-256"
+///     segment_po2: Segment size as log2 of the cycle count, 13-24. Smaller
+///                  segments use less memory per segment; None keeps
+///                  RISC Zero's default.
+///     keccak_po2: Segment size of the keccak accelerator's proofs, 14-18.
+///                 Guests that hash with the keccak accelerator (e.g.
+///                 risc0's patched tiny-keccak) batch their permutations
+///                 into proofs of this size; lowering it by one roughly
+///                 halves their memory use. None keeps RISC Zero's default.
+///
+/// SHA-256 needs no setting: it is a built-in accelerator, used by any guest
+/// hashing through risc0_zkvm::sha or risc0's patched sha2 crate.
+///
+/// Raises:
+///     ValueError: If a setting is unknown or out of range
+///
+/// Example:
+///     config = pyr0.ProverConfig(backend="cuda", segment_po2=21)
+///     receipt = pyr0.prove_with_opts(image, input_data, config=config)
+#[pyclass(module = "pyr0")]
+#[derive(Clone)]
+pub struct ProverConfig {
+    /// Proving backend ("cpu", "cuda" or "metal")
+    #[pyo3(get)]
+    pub backend: String,
+    /// STARK hash function ("poseidon2" or "sha-256")
+    #[pyo3(get)]
+    pub hashfn: String,
+    /// Segment size in log2 cycles, or None for RISC Zero's default
+    #[pyo3(get)]
+    pub segment_po2: Option<u32>,
+    /// Keccak accelerator proof size in log2 cycles, or None for RISC Zero's default
+    #[pyo3(get)]
+    pub keccak_po2: Option<u32>,
+}
+
+#[pymethods]
+impl ProverConfig {
+    #[new]
+    #[pyo3(signature = (backend=None, hashfn="poseidon2", segment_po2=None, keccak_po2=None))]
+    pub fn new(backend: Option<&str>, hashfn: &str, segment_po2: Option<u32>, keccak_po2: Option<u32>) -> PyResult<Self> {
+        let compiled = compiled_backend();
+        let backend = backend.map(str::to_lowercase).unwrap_or_else(|| compiled.to_string());
+        if !["cpu", "cuda", "metal"].contains(&backend.as_str()) {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "Unknown backend '{}'. Use 'cpu', 'cuda' or 'metal'",
+                backend
+            )));
+        }
+        if backend != compiled {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "Backend '{}' is not available: this build of PyR0 proves on '{}'. \
+                 Rebuild with the matching Cargo feature (e.g. maturin develop --features {})",
+                backend, compiled, backend
+            )));
+        }
+
+        let hashfn = hashfn.to_lowercase();
+        if !HASHFNS.contains(&hashfn.as_str()) {
+            return Err(PyErr::new::<PyValueError, _>(format!(
+                "Unknown hashfn '{}'. Use one of: {}",
+                hashfn,
+                HASHFNS.join(", ")
+            )));
+        }
+
+        if let Some(po2) = segment_po2 {
+            if !(MIN_SEGMENT_PO2..=MAX_SEGMENT_PO2).contains(&po2) {
+                return Err(PyErr::new::<PyValueError, _>(format!(
+                    "segment_po2 must be between {} and {}, got {}",
+                    MIN_SEGMENT_PO2, MAX_SEGMENT_PO2, po2
+                )));
+            }
+        }
+
+        if let Some(po2) = keccak_po2 {
+            if !(MIN_KECCAK_PO2..=MAX_KECCAK_PO2).contains(&po2) {
+                return Err(PyErr::new::<PyValueError, _>(format!(
+                    "keccak_po2 must be between {} and {}, got {}",
+                    MIN_KECCAK_PO2, MAX_KECCAK_PO2, po2
+                )));
+            }
+        }
+
+        Ok(ProverConfig { backend, hashfn, segment_po2, keccak_po2 })
+    }
+
+    /// Backends this build of PyR0 can prove on
+    #[staticmethod]
+    pub fn available_backends() -> Vec<&'static str> {
+        vec![compiled_backend()]
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ProverConfig(backend='{}', hashfn='{}', segment_po2={}, keccak_po2={})",
+            self.backend,
+            self.hashfn,
+            self.segment_po2.map_or("None".to_string(), |po2| po2.to_string()),
+            self.keccak_po2.map_or("None".to_string(), |po2| po2.to_string())
+        )
+    }
+}
+
+impl ProverConfig {
+    /// Apply the hash function to a set of prover options
+    pub(crate) fn apply_opts(&self, opts: &mut ProverOpts) {
+        opts.hashfn = self.hashfn.clone();
+    }
+
+    /// Apply the segment sizes to an executor environment
+    pub(crate) fn apply_env(&self, builder: &mut ExecutorEnvBuilder<'_>) -> anyhow::Result<()> {
+        if let Some(po2) = self.segment_po2 {
+            builder.segment_limit_po2(po2);
+        }
+        if let Some(po2) = self.keccak_po2 {
+            builder.keccak_max_po2(po2)?;
+        }
+        Ok(())
+    }
+
+    /// An in-process prover, independent of RISC0_PROVER and Bonsai settings
+    pub(crate) fn prover(&self) -> Rc<dyn Prover> {
+        Rc::new(LocalProver::new("local"))
+    }
+}
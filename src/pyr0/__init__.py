@@ -1,7 +1,12 @@
 from pyr0._rust import *
 from pyr0 import serialization
+from pyr0 import codec
+from pyr0 import fuzz
+from pyr0 import testing
+from pyr0 import templates
 from pyr0.build import (
     build_guest,
+    new_guest,
     BuildError,
     GuestBuildFailedError,
     ElfNotFoundError,
@@ -9,40 +14,97 @@ from pyr0.build import (
 )
 from pyr0.exceptions import (
     PyR0Error,
+    ProvingError,
+    ImageError,
     CompositionError,
     VerificationError,
+    VerificationTimeout,
+    VerificationStage,
+    SessionLimitExceeded,
     SerializationError,
     PreflightError,
     AssumptionError,
+    InputPatternError,
+    JournalAssertionError,
 )
 
 __all__ = [
     # Core API functions
     "load_image",
+    "load_image_from_path",
+    "load_image_cached",
     "prove",
     "prove_with_opts",
     "prove_succinct",
+    "prove_fake",
+    "prove_async",
     "compute_image_id_hex",
+    "compute_image_ids",
     "compress_to_succinct",
+    "compress_to_groth16",
+    "reserve_prover",
+    "execute_to_segments",
+    "prove_segment",
+    "lift",
+    "join",
+    "resolve",
+    "prove_keccak",
+    "union",
+    "export_audit_log",
+    "schema",
+    "poseidon2_hash",
+    
+    # Guest platform constants
+    "GUEST_MAX_MEM",
+    "PAGE_SIZE",
+    "WORD_SIZE",
+    "RECOMMENDED_MAX_JOURNAL_SIZE",
+    "CHECKPOINT_FD",
     
     # Build functions
     "build_guest",
+    "new_guest",
     
     # Debugging functions
     "dry_run",
+    "compare_sessions",
+    "profile",
+    "replay",
+    "resume",
+    "discard_paused",
     
     # Modules and classes
     "serialization",
+    "codec",
+    "fuzz",
+    "testing",
+    "templates",
     "Image",
     "Receipt",
+    "Segment",
+    "SegmentReceipt",
+    "SuccinctReceipt",
+    "KeccakReceipt",
+    "DeserializationPolicy",
+    "VerificationPolicy",
+    "VerifierContext",
+    "ImageRegistry",
     "ExitCode",
     "SessionInfo",
+    "SessionDiff",
+    "ProfileReport",
+    "Recording",
     "ExitStatus",
     "ExitKind",
     "ReceiptKind",
     "Claim",
     "Composer",
+    "SharedAssumption",
+    "ProverConfig",
+    "ProverReservation",
+    "HostIO",
     "InputBuilder",
+    "GuestManifest",
     
     # Exceptions
     "BuildError",
@@ -50,9 +112,16 @@ __all__ = [
     "ElfNotFoundError",
     "InvalidGuestDirectoryError",
     "PyR0Error",
+    "ProvingError",
+    "ImageError",
     "CompositionError",
     "VerificationError",
+    "VerificationTimeout",
+    "VerificationStage",
+    "SessionLimitExceeded",
     "SerializationError",
     "PreflightError",
     "AssumptionError",
+    "InputPatternError",
+    "JournalAssertionError",
 ]
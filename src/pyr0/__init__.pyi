@@ -8,9 +8,25 @@ from pyr0._rust import (
     # Classes
     Image as Image,
     Receipt as Receipt,
+    Segment as Segment,
+    SegmentReceipt as SegmentReceipt,
+    SuccinctReceipt as SuccinctReceipt,
+    KeccakReceipt as KeccakReceipt,
+    DeserializationPolicy as DeserializationPolicy,
+    VerificationPolicy as VerificationPolicy,
+    VerifierContext as VerifierContext,
+    ImageRegistry as ImageRegistry,
     Claim as Claim,
     Composer as Composer,
+    SharedAssumption as SharedAssumption,
+    ProverConfig as ProverConfig,
+    ProverReservation as ProverReservation,
+    HostIO as HostIO,
+    GuestManifest as GuestManifest,
     SessionInfo as SessionInfo,
+    SessionDiff as SessionDiff,
+    ProfileReport as ProfileReport,
+    Recording as Recording,
     ExitStatus as ExitStatus,
     ExitCode as ExitCode,
     
@@ -18,25 +34,59 @@ from pyr0._rust import (
     ReceiptKind as ReceiptKind,
     ExitKind as ExitKind,
     
+    # Constants
+    GUEST_MAX_MEM as GUEST_MAX_MEM,
+    PAGE_SIZE as PAGE_SIZE,
+    WORD_SIZE as WORD_SIZE,
+    RECOMMENDED_MAX_JOURNAL_SIZE as RECOMMENDED_MAX_JOURNAL_SIZE,
+    CHECKPOINT_FD as CHECKPOINT_FD,
+    
     # Functions
     load_image as load_image,
+    load_image_from_path as load_image_from_path,
+    load_image_cached as load_image_cached,
     prove as prove,
     prove_with_opts as prove_with_opts,
+    prove_async as prove_async,
+    prove_fake as prove_fake,
     compute_image_id_hex as compute_image_id_hex,
+    compute_image_ids as compute_image_ids,
     compress_to_succinct as compress_to_succinct,
+    compress_to_groth16 as compress_to_groth16,
+    reserve_prover as reserve_prover,
+    execute_to_segments as execute_to_segments,
+    prove_segment as prove_segment,
+    lift as lift,
+    join as join,
+    resolve as resolve,
+    prove_keccak as prove_keccak,
+    union as union,
+    export_audit_log as export_audit_log,
+    schema as schema,
+    poseidon2_hash as poseidon2_hash,
     dry_run as dry_run,
+    compare_sessions as compare_sessions,
+    profile as profile,
+    replay as replay,
+    resume as resume,
+    discard_paused as discard_paused,
 )
 
 # From build module
 from pyr0.build import (
     build_guest as build_guest,
+    new_guest as new_guest,
     BuildError as BuildError,
     GuestBuildFailedError as GuestBuildFailedError,
     ElfNotFoundError as ElfNotFoundError,
     InvalidGuestDirectoryError as InvalidGuestDirectoryError,
 )
 
-# Serialization module
+# Serialization modules
 from pyr0 import serialization as serialization
+from pyr0 import codec as codec
+from pyr0 import fuzz as fuzz
+from pyr0 import testing as testing
+from pyr0 import templates as templates
 
 __all__: List[str]
\ No newline at end of file
@@ -1,9 +1,18 @@
 """Type stubs for PyR0 Rust extension module."""
 
-from typing import Union, Optional, List, Tuple, overload, Literal
+from typing import Any, BinaryIO, Callable, Dict, Union, Optional, List, Sequence, Tuple, Iterable, overload, Literal
 from enum import Enum
+import asyncio
+import os
 
 # Enums
+# Guest platform parameters
+GUEST_MAX_MEM: int  # Highest guest address (exclusive)
+PAGE_SIZE: int  # zkVM page size in bytes
+WORD_SIZE: int  # Bytes per RISC-V word (serde encodes everything in words)
+RECOMMENDED_MAX_JOURNAL_SIZE: int  # Soft guidance, not enforced
+CHECKPOINT_FD: int  # Guest fd for (u32 marker, u64 cycles) progress records
+
 class ReceiptKind(Enum):
     COMPOSITE: ReceiptKind
     SUCCINCT: ReceiptKind
@@ -27,6 +36,16 @@ class Image:
     def image_id_bytes(self) -> bytes: ...
     @property
     def elf_size(self) -> int: ...
+    @property
+    def entry_point(self) -> int: ...
+    def fits_memory(self, estimate: int) -> bool: ...
+    def symbolize(self, address: int) -> Optional[str]: ...
+    def symbolize_many(self, addresses: Sequence[int]) -> List[Optional[str]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> Image: ...
+    @staticmethod
+    def from_file(path: Union[str, "os.PathLike[str]"]) -> Image: ...
 
 class ExitStatus:
     @property
@@ -34,7 +53,13 @@ class ExitStatus:
     @property
     def user_code(self) -> Optional[int]: ...
     @property
+    def segment_index(self) -> Optional[int]: ...
+    @property
+    def cycle_count(self) -> Optional[int]: ...
+    @property
     def ok(self) -> bool: ...
+    @property
+    def is_limit(self) -> bool: ...
 
 class Claim:
     @property
@@ -51,8 +76,89 @@ class Claim:
     def exit_code(self) -> int: ...
     @property
     def is_success(self) -> bool: ...
+    @property
+    def pre_state_digest(self) -> bytes: ...
+    @property
+    def pre_state_digest_hex(self) -> str: ...
+    @property
+    def post_state_digest(self) -> Optional[bytes]: ...
+    @property
+    def post_state_digest_hex(self) -> Optional[str]: ...
+    @property
+    def exit(self) -> ExitStatus: ...
+    @property
+    def exit_kind(self) -> ExitKind: ...
+    @property
+    def input_digest(self) -> bytes: ...
+    @property
+    def output_digest(self) -> bytes: ...
     
+    def digest(self) -> bytes: ...
     def matches(self, image_id: bytes, journal: bytes) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @staticmethod
+    def for_success(image_id: bytes, journal: bytes) -> Claim: ...
+    @staticmethod
+    def for_exit(image_id: bytes, journal: bytes, code: int) -> Claim: ...
+    @staticmethod
+    def from_parts(
+        pre_state_digest: bytes,
+        journal: bytes,
+        exit_kind: ExitKind = ...,
+        exit_code: int = 0,
+        post_state_digest: Optional[bytes] = None,
+        input_digest: Optional[bytes] = None,
+        output_digest: Optional[bytes] = None,
+    ) -> Claim: ...
+    def to_json(self, pretty: bool = False) -> str: ...
+
+class DeserializationPolicy:
+    max_size: int
+    max_assumptions: int
+    max_depth: int
+    def __init__(
+        self,
+        max_size: Optional[int] = None,
+        max_assumptions: Optional[int] = None,
+        max_depth: Optional[int] = None,
+    ) -> None: ...
+    @staticmethod
+    def unlimited() -> DeserializationPolicy: ...
+
+class VerifierContext:
+    def __init__(
+        self,
+        control_root: Optional[bytes] = None,
+        inner_control_root: Optional[bytes] = None,
+        bn254_control_id: Optional[bytes] = None,
+        control_ids: Optional[Sequence[bytes]] = None,
+    ) -> None: ...
+    @property
+    def control_root(self) -> bytes: ...
+    @property
+    def inner_control_root(self) -> Optional[bytes]: ...
+    @property
+    def bn254_control_id(self) -> bytes: ...
+    @property
+    def control_ids(self) -> List[bytes]: ...
+
+class VerificationPolicy:
+    def __init__(
+        self,
+        image_ids: Union[ImageRegistry, Iterable[Union[bytes, str, Image]]],
+        kinds: Optional[Sequence[Union[ReceiptKind, str]]] = None,
+        max_journal_size: Optional[int] = None,
+        exit_codes: Sequence[int] = [0],
+    ) -> None: ...
+    @property
+    def image_ids(self) -> List[bytes]: ...
+    @property
+    def kinds(self) -> Optional[List[ReceiptKind]]: ...
+    @property
+    def max_journal_size(self) -> Optional[int]: ...
+    @property
+    def exit_codes(self) -> List[int]: ...
 
 class Receipt:
     # Journal properties
@@ -66,6 +172,11 @@ class Receipt:
     def journal_len(self) -> int: ...
     @property
     def journal(self) -> bytes: ...  # Legacy
+    @overload
+    def journal_decode(self, fmt: str, strict: bool = True) -> Any: ...
+    @overload
+    def journal_decode(self, fmt: Sequence[str], strict: bool = True) -> List[Any]: ...
+    def decode_journal(self, manifest: GuestManifest) -> dict: ...
     
     # Claim
     def claim(self) -> Claim: ...
@@ -77,6 +188,12 @@ class Receipt:
     def claimed_image_id_bytes(self) -> bytes: ...
     @property
     def program_id(self) -> bytes: ...  # Legacy
+    @property
+    def pre_state_digest(self) -> bytes: ...
+    @property
+    def post_state_digest(self) -> bytes: ...
+    @property
+    def pause_handle(self) -> Optional[int]: ...
     
     # Receipt properties
     @property
@@ -84,11 +201,21 @@ class Receipt:
     @property
     def kind(self) -> ReceiptKind: ...
     @property
+    def verifier_parameters(self) -> bytes: ...
+    @property
+    def hashfn(self) -> Optional[str]: ...
+    @property
     def is_unconditional(self) -> bool: ...
     @property
     def is_succinct(self) -> bool: ...
     @property
     def assumption_count(self) -> int: ...
+    def segments(self) -> List[SegmentReceipt]: ...
+    def resolve(
+        self,
+        assumptions: Sequence['Receipt'] = ...,
+        keccak: Sequence['KeccakReceipt'] = ...,
+    ) -> 'Receipt': ...
     
     # Exit status
     @property
@@ -98,92 +225,523 @@ class Receipt:
     
     # Verification - polymorphic!
     @overload
-    def verify(self, image_id: bytes) -> None: ...
+    def verify(
+        self, image_id: bytes, deadline_ms: Optional[int] = None, context: Optional[VerifierContext] = None
+    ) -> None: ...
     @overload
-    def verify(self, image_id: str) -> None: ...
+    def verify(
+        self, image_id: str, deadline_ms: Optional[int] = None, context: Optional[VerifierContext] = None
+    ) -> None: ...
     @overload
-    def verify(self, image_id: Image) -> None: ...
+    def verify(
+        self, image_id: Image, deadline_ms: Optional[int] = None, context: Optional[VerifierContext] = None
+    ) -> None: ...
     
+    def verify_allowing_exit_codes(self, image_id: Union[bytes, str, Image], allowed: Sequence[int]) -> int: ...
+    def verify_policy(self, policy: VerificationPolicy) -> bytes: ...
     def verify_hex(self, image_id_hex: str) -> None: ...
     def verify_bytes(self, image_id: bytes) -> None: ...
-    def verify_integrity(self) -> None: ...
+    def verify_integrity(self, context: Optional[VerifierContext] = None) -> None: ...
     def verify_with_image_id(self, image_id: Union[bytes, str, Image]) -> None: ...  # Deprecated
     
     def matches_image_id(self, expected_image_id: bytes) -> bool: ...
-    
+
+    # Test fixtures
+    @staticmethod
+    def fake(image_id: Union[bytes, str, Image], journal: bytes, exit_code: int = 0) -> 'Receipt': ...
+
     # Serialization
-    def to_bytes(self) -> bytes: ...
+    def to_bytes(self, format: Literal["bincode", "borsh", "cbor"] = "bincode") -> bytes: ...
+    @staticmethod
+    def from_bytes(
+        data: bytes,
+        policy: Optional[DeserializationPolicy] = None,
+        format: Optional[Literal["bincode", "borsh", "cbor"]] = None,
+    ) -> 'Receipt': ...
+    def to_cose_sign1(self, signing_key: Optional[bytes] = None) -> bytes: ...
+    @staticmethod
+    def from_cose_sign1(
+        data: bytes,
+        verify_key: Optional[bytes] = None,
+        policy: Optional[DeserializationPolicy] = None,
+    ) -> 'Receipt': ...
+    def save(self, path: Union[str, "os.PathLike[str]"]) -> None: ...
+    @staticmethod
+    def load(path: Union[str, "os.PathLike[str]"], policy: Optional[DeserializationPolicy] = None) -> 'Receipt': ...
+    def to_json(self, pretty: bool = False) -> str: ...
+    @staticmethod
+    def from_json(text: str, policy: Optional[DeserializationPolicy] = None) -> 'Receipt': ...
+    
+    # Verifiable Credentials
+    def to_verifiable_credential(
+        self, issuer_did: str, subject: str, receipt_uri: Optional[str] = None
+    ) -> str: ...
+    @staticmethod
+    def verify_verifiable_credential(
+        credential: str,
+        image_id: Union[bytes, str, Image],
+        receipt: Optional['Receipt'] = None,
+    ) -> 'Receipt': ...
+
+class ProverConfig:
+    def __init__(
+        self,
+        backend: Optional[Literal["cpu", "cuda", "metal"]] = None,
+        hashfn: Literal["poseidon2", "sha-256"] = "poseidon2",
+        segment_po2: Optional[int] = None,
+        keccak_po2: Optional[int] = None,
+    ) -> None: ...
+    @property
+    def backend(self) -> str: ...
+    @property
+    def hashfn(self) -> str: ...
+    @property
+    def segment_po2(self) -> Optional[int]: ...
+    @property
+    def keccak_po2(self) -> Optional[int]: ...
+    @staticmethod
+    def available_backends() -> List[str]: ...
+
+class ImageRegistry:
+    def __init__(self) -> None: ...
+    def add(self, name: str, image_id: Union[bytes, str, Image], replace: bool = False) -> None: ...
+    def remove(self, name: str) -> None: ...
+    def names(self) -> List[str]: ...
+    def name_of(self, image_id: Union[bytes, str, Image]) -> Optional[str]: ...
+    def verify(self, receipt: Receipt, name: str) -> None: ...
+    def to_json(self, signing_key: Optional[bytes] = None) -> str: ...
+    @staticmethod
+    def from_json(data: str, verify_key: Optional[bytes] = None) -> ImageRegistry: ...
+    def save(self, path: Union[str, "os.PathLike[str]"], signing_key: Optional[bytes] = None) -> None: ...
     @staticmethod
-    def from_bytes(data: bytes) -> 'Receipt': ...
+    def load(path: Union[str, "os.PathLike[str]"], verify_key: Optional[bytes] = None) -> ImageRegistry: ...
+    def __getitem__(self, name: str) -> bytes: ...
+    def __contains__(self, name: str) -> bool: ...
+    def __len__(self) -> int: ...
+
+class HostIO:
+    def __init__(self) -> None: ...
+    def channel(self, name: str, handler: Callable[[bytes], bytes]) -> HostIO: ...
+    def read_fd(self, fd: int, source: Union[bytes, BinaryIO]) -> HostIO: ...
+    def write_fd(self, fd: int, sink: Union[Callable[[bytes], Any], BinaryIO]) -> HostIO: ...
+    @property
+    def channels(self) -> List[str]: ...
+
+class ProverReservation:
+    @property
+    def cores(self) -> Optional[int]: ...
+    @property
+    def gpu(self) -> Optional[int]: ...
+    @property
+    def nice(self) -> Optional[int]: ...
+    @property
+    def cpus(self) -> Optional[List[int]]: ...
+    def __enter__(self) -> ProverReservation: ...
+    def __exit__(self, exc_type: Any, exc: Any, tb: Any) -> bool: ...
+
+class GuestManifest:
+    def __init__(
+        self,
+        input: Sequence[Tuple[str, str]] = (),
+        journal: Sequence[Tuple[str, str]] = (),
+        name: Optional[str] = None,
+        image_id: Optional[bytes] = None,
+    ) -> None: ...
+    @staticmethod
+    def from_json(text: str) -> GuestManifest: ...
+    @staticmethod
+    def from_toml(text: str) -> GuestManifest: ...
+    @staticmethod
+    def load(path: Union[str, "os.PathLike[str]"]) -> GuestManifest: ...
+    @property
+    def name(self) -> Optional[str]: ...
+    @property
+    def image_id(self) -> Optional[bytes]: ...
+    @property
+    def input(self) -> List[Tuple[str, str]]: ...
+    @property
+    def journal(self) -> List[Tuple[str, str]]: ...
+    def validate_input(self, data: bytes) -> None: ...
+    def decode_journal(self, journal: bytes) -> dict: ...
+
+class SharedAssumption:
+    def __init__(self, receipt: Receipt) -> None: ...
+    def claim(self) -> Claim: ...
+    @property
+    def is_resolved(self) -> bool: ...
+    def resolve(self) -> Receipt: ...
 
 class Composer:
-    def __init__(self, image: Image) -> None: ...
+    def __init__(self, image: Image, input_mixing: Literal["error", "warn", "ignore"] = "error") -> None: ...
     
     # Assumption management
     def assume(self, receipt: Receipt) -> None: ...
     def assume_many(self, receipts: List[Receipt]) -> None: ...
+    def assume_shared(self, shared: SharedAssumption) -> None: ...
+    def assume_claim(self, image_id: bytes, journal_digest: bytes) -> None: ...
     
     # Writers for env::read_slice()
     def write_u32(self, value: int) -> None: ...
     def write_u64(self, value: int) -> None: ...
+    def write_i32(self, value: int) -> None: ...
+    def write_i64(self, value: int) -> None: ...
+    def write_f32(self, value: float) -> None: ...
+    def write_f64(self, value: float) -> None: ...
+    def write_bool(self, value: bool) -> None: ...
+    def write_str_frame(self, text: str) -> None: ...
     def write_bytes32(self, data: bytes) -> None: ...
     def write_image_id(self, image_id: bytes) -> None: ...
-    def write_slice(self, data: bytes) -> None: ...
+    def write_raw_bytes(self, data: bytes) -> None: ...
+    def write_frame(self, data: bytes) -> None: ...
+    def write_cbor(self, cbor_bytes: bytes) -> None: ...
+    def write_cbor_frame(self, cbor_bytes: bytes) -> None: ...
     
     # Writers for env::read::<T>()
-    def write_vec_u8(self, data: bytes) -> None: ...
-    def write_string(self, text: str) -> None: ...
+    def write_serde(self, values: Iterable[Any]) -> None: ...
+    def write_composition_input(self, receipt: Receipt, expected_image_id: bytes, extra: bytes = b"") -> None: ...
     
     # Utilities
-    def write_journal_from(self, receipt: Receipt) -> None: ...
+    def input_layout(self) -> List[dict]: ...
+    def input_hexdump(self, width: int = 16) -> str: ...
+    def generate_guest_snippet(self) -> str: ...
     
     # Verification setup
     def expect_verification(self, image_id: bytes, journal: bytes) -> None: ...
-    def preflight_check(self, raise_on_error: bool = True) -> List[str]: ...
+    def expect_verification_digest(self, image_id: bytes, journal_digest: bytes) -> None: ...
+    def expect_claim(self, claim: Claim) -> None: ...
+    def assert_journal(
+        self,
+        prefix: Optional[bytes] = None,
+        schema: Optional[Sequence[str]] = None,
+        values: Optional[Sequence[Any]] = None,
+        strict: bool = True,
+        size: Optional[int] = None,
+    ) -> None: ...
+    def preflight_check(
+        self, raise_on_error: bool = True, execute: bool = False, max_cycles: Optional[int] = None
+    ) -> List[str]: ...
+    
+    def dry_run(
+        self, *, preflight: bool = True, max_cycles: Optional[int] = None, capture_output: bool = False
+    ) -> SessionInfo: ...
     
     # Proving - polymorphic!
     @overload
-    def prove(self, *, preflight: bool = True) -> Receipt: ...
+    def prove(
+        self,
+        *,
+        preflight: bool = True,
+        max_cycles: Optional[int] = None,
+        zeroize_input: bool = False,
+        manifest: Optional[GuestManifest] = None,
+    ) -> Receipt: ...
     @overload
-    def prove(self, *, kind: ReceiptKind, preflight: bool = True) -> Receipt: ...
+    def prove(
+        self,
+        *,
+        kind: ReceiptKind,
+        preflight: bool = True,
+        max_cycles: Optional[int] = None,
+        zeroize_input: bool = False,
+        manifest: Optional[GuestManifest] = None,
+    ) -> Receipt: ...
     @overload
-    def prove(self, *, kind: Literal["succinct", "composite", "groth16"], preflight: bool = True) -> Receipt: ...
+    def prove(
+        self,
+        *,
+        kind: Literal["succinct", "composite", "groth16"],
+        preflight: bool = True,
+        max_cycles: Optional[int] = None,
+        zeroize_input: bool = False,
+        manifest: Optional[GuestManifest] = None,
+    ) -> Receipt: ...
+    
+    def prove_conditional(
+        self, *, preflight: bool = True, max_cycles: Optional[int] = None
+    ) -> Tuple[SuccinctReceipt, bytes]: ...
+    
+    def prove_async(
+        self,
+        *,
+        kind: Optional[Union[ReceiptKind, Literal["succinct", "composite", "groth16"]]] = None,
+        preflight: bool = True,
+        max_cycles: Optional[int] = None,
+        zeroize_input: bool = False,
+        manifest: Optional[GuestManifest] = None,
+    ) -> "asyncio.Future[Receipt]": ...
+    
+    def fingerprint(
+        self, kind: Optional[Union[ReceiptKind, Literal["succinct", "composite", "groth16"]]] = None
+    ) -> bytes: ...
     
     # Properties
     @property
     def input_size(self) -> int: ...
     @property
+    def input_bytes(self) -> bytes: ...
+    @property
     def assumption_count(self) -> int: ...
 
+class Segment:
+    @property
+    def index(self) -> int: ...
+    @property
+    def po2(self) -> int: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> Segment: ...
+
+class SuccinctReceipt:
+    @property
+    def claim_digest(self) -> bytes: ...
+    @property
+    def seal_size(self) -> int: ...
+    def verify_integrity(self) -> None: ...
+    def into_receipt(self, journal: bytes) -> Receipt: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> SuccinctReceipt: ...
+
+class KeccakReceipt:
+    @property
+    def claim_digest(self) -> bytes: ...
+    @property
+    def seal_size(self) -> int: ...
+    def verify_integrity(self) -> None: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> KeccakReceipt: ...
+
+class SegmentReceipt:
+    @property
+    def index(self) -> int: ...
+    @property
+    def hashfn(self) -> str: ...
+    @property
+    def seal_size(self) -> int: ...
+    @property
+    def claim_digest(self) -> bytes: ...
+    @property
+    def pre_state_digest(self) -> bytes: ...
+    @property
+    def post_state_digest(self) -> bytes: ...
+    @property
+    def exit(self) -> ExitStatus: ...
+    def verify_integrity(self) -> None: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> SegmentReceipt: ...
+
 class SessionInfo:
     @property
     def exit_code(self) -> 'ExitCode': ...
     @property
     def journal(self) -> bytes: ...
+    @property
+    def segment_count(self) -> int: ...
+    @property
+    def user_cycles(self) -> int: ...
+    @property
+    def total_cycles(self) -> int: ...
+    @property
+    def exit(self) -> ExitStatus: ...
+    @property
+    def limit_hit(self) -> Optional[ExitStatus]: ...
+    @property
+    def post_state_digest(self) -> bytes: ...
+    @property
+    def pause_handle(self) -> Optional[int]: ...
+    @property
+    def stdout(self) -> Optional[bytes]: ...
+    @property
+    def stderr(self) -> Optional[bytes]: ...
+
+class SessionDiff:
+    @property
+    def user_cycles_delta(self) -> int: ...
+    @property
+    def total_cycles_delta(self) -> int: ...
+    @property
+    def segment_count_delta(self) -> int: ...
+    @property
+    def user_cycles_ratio(self) -> Optional[float]: ...
+    @property
+    def journal_diff_offset(self) -> Optional[int]: ...
+    @property
+    def journal_changed(self) -> bool: ...
+    @property
+    def exit_changed(self) -> bool: ...
+    @property
+    def output_changed(self) -> bool: ...
+    def summary(self) -> str: ...
+
+class ProfileReport:
+    @staticmethod
+    def load(path: Union[str, "os.PathLike[str]"], session: SessionInfo) -> ProfileReport: ...
+    @property
+    def pprof(self) -> bytes: ...
+    @property
+    def session(self) -> SessionInfo: ...
+    @property
+    def syscalls(self) -> Dict[str, int]: ...
+    @property
+    def sampled_cycles(self) -> int: ...
+    def top_functions(self, n: int = 10) -> List[Tuple[str, int]]: ...
+    def summary(self, n: int = 10) -> str: ...
+    def save(self, path: Union[str, "os.PathLike[str]"]) -> None: ...
+
+class Recording:
+    @staticmethod
+    def load(path: Union[str, "os.PathLike[str]"]) -> Recording: ...
+    @property
+    def image_id(self) -> bytes: ...
+    @property
+    def input(self) -> bytes: ...
+    @property
+    def env(self) -> Dict[str, str]: ...
+    @property
+    def assumptions(self) -> List[bytes]: ...
+    @property
+    def recorded(self) -> Optional[SessionInfo]: ...
+    @property
+    def error(self) -> Optional[str]: ...
+    def replay(self, capture_output: bool = False) -> SessionInfo: ...
 
 class ExitCode:
     Halted: int
     Paused: int
     SystemSplit: int
 
+# Guest input: bytes (read in place), other buffers (copied once), a
+# binary file-like object or a path (streamed)
+_GuestInput = Union[bytes, bytearray, memoryview, BinaryIO, "os.PathLike[str]"]
+
 # Functions
 def load_image(elf: bytes) -> Image: ...
+def load_image_from_path(path: Union[str, "os.PathLike[str]"]) -> Image: ...
+
+def load_image_cached(
+    cache_dir: Union[str, "os.PathLike[str]"],
+    image_id: Union[bytes, str],
+    elf: Optional[Union[bytes, str, "os.PathLike[str]"]] = None,
+) -> Image: ...
 
-def prove(image: Image, input_bytes: bytes) -> Receipt: ...
+def prove(
+    image: Image,
+    input_bytes: _GuestInput,
+    max_cycles: Optional[int] = None,
+    zeroize_input: bool = False,
+    on_checkpoint: Optional[Callable[[int, int], None]] = None,
+    manifest: Optional[GuestManifest] = None,
+    host_io: Optional[HostIO] = None,
+    pausable: bool = False,
+) -> Receipt: ...
 
 def prove_with_opts(
     image: Image, 
-    input_bytes: bytes, 
-    succinct: bool = False
+    input_bytes: _GuestInput, 
+    succinct: bool = False,
+    max_cycles: Optional[int] = None,
+    zeroize_input: bool = False,
+    config: Optional[ProverConfig] = None,
+    coprocessor: Optional[Callable[[dict], None]] = None,
+    host_io: Optional[HostIO] = None,
+) -> Receipt: ...
+
+def prove_succinct(image: Image, input_bytes: _GuestInput, zeroize_input: bool = False) -> Receipt: ...
+
+def prove_fake(
+    image: Image,
+    input_bytes: _GuestInput,
+    max_cycles: Optional[int] = None,
+    assumptions: Optional[Sequence[Receipt]] = None,
 ) -> Receipt: ...
 
-def prove_succinct(image: Image, input_bytes: bytes) -> Receipt: ...
+def prove_async(
+    image: Image, input_bytes: _GuestInput, max_cycles: Optional[int] = None, zeroize_input: bool = False
+) -> "asyncio.Future[Receipt]": ...
 
 def compute_image_id_hex(elf_bytes: bytes) -> str: ...
 
+def compute_image_ids(elfs: List[Union[bytes, str, "os.PathLike[str]"]]) -> List[str]: ...
+
 def compress_to_succinct(
     receipt: Receipt,
     assumptions: Optional[List[Receipt]] = None
 ) -> Receipt: ...
 
-def dry_run(image: Image, input_bytes: bytes) -> SessionInfo: ...
\ No newline at end of file
+def compress_to_groth16(receipt: Receipt) -> Receipt: ...
+
+def reserve_prover(
+    cores: Optional[int] = None,
+    gpu: Optional[int] = None,
+    nice: Optional[int] = None,
+    cpus: Optional[Sequence[int]] = None,
+) -> ProverReservation: ...
+
+def execute_to_segments(
+    image: Image,
+    input_bytes: _GuestInput,
+    config: Optional[ProverConfig] = None,
+    max_cycles: Optional[int] = None,
+) -> Tuple[List[Segment], SessionInfo]: ...
+
+def prove_segment(segment: Segment, config: Optional[ProverConfig] = None) -> SegmentReceipt: ...
+
+def lift(receipt: SegmentReceipt) -> SuccinctReceipt: ...
+
+def join(a: SuccinctReceipt, b: SuccinctReceipt) -> SuccinctReceipt: ...
+
+def resolve(conditional: SuccinctReceipt, assumption: Union[Receipt, KeccakReceipt]) -> SuccinctReceipt: ...
+
+def prove_keccak(request: dict, config: Optional[ProverConfig] = None) -> KeccakReceipt: ...
+
+def union(a: KeccakReceipt, b: KeccakReceipt) -> KeccakReceipt: ...
+
+def poseidon2_hash(inputs: Sequence[int]) -> bytes: ...
+
+def dry_run(
+    image: Image,
+    input_bytes: _GuestInput,
+    capture_output: bool = False,
+    on_checkpoint: Optional[Callable[[int, int], None]] = None,
+    env: Optional[Dict[str, str]] = None,
+    assumptions: Optional[Sequence[Receipt]] = None,
+    record: Optional[Union[str, "os.PathLike[str]"]] = None,
+    host_io: Optional[HostIO] = None,
+    pausable: bool = False,
+) -> SessionInfo: ...
+@overload
+def resume(image: Image, prior: SessionInfo, more_input: bytes = b"") -> SessionInfo: ...
+@overload
+def resume(
+    image: Image, prior: Union[Receipt, int], more_input: bytes = b"", config: Optional[ProverConfig] = None
+) -> Receipt: ...
+def discard_paused() -> int: ...
+def replay(path: Union[str, "os.PathLike[str]"], capture_output: bool = False) -> SessionInfo: ...
+def compare_sessions(a: SessionInfo, b: SessionInfo) -> SessionDiff: ...
+
+def profile(
+    image: Image,
+    input_bytes: _GuestInput,
+    output: Optional[Union[str, "os.PathLike[str]"]] = None,
+) -> ProfileReport: ...
+
+def export_audit_log(
+    receipts: Iterable[Receipt],
+    path: Union[str, "os.PathLike[str]"],
+    image_id: Optional[Union[bytes, str, Image]] = None,
+    append: bool = False,
+) -> int: ...
+
+def schema(
+    name: Optional[Literal["Receipt", "Claim", "SessionInfo", "ExitStatus", "AuditRecord"]] = None
+) -> dict: ...
+
+# Codec primitives (use pyr0.codec instead)
+def _serde_encode(values: object) -> bytes: ...
+def _serde_decode(data: bytes, fmt: List[str], strict: bool = True, padded: bool = False) -> list: ...
+
+# Panic-free parsers (use pyr0.fuzz instead)
+def _fuzz_receipt_from_bytes(data: bytes, policy: Optional[DeserializationPolicy] = None) -> Receipt: ...
+def _fuzz_image_from_bytes(data: bytes) -> Image: ...
\ No newline at end of file
@@ -4,6 +4,7 @@ Guest building functionality for PyR0.
 
 import subprocess
 import os
+import re
 import shutil
 from pathlib import Path
 from typing import Optional
@@ -223,3 +224,180 @@ def build_guest(
     return elf_path
 
 
+# Guest crate templates for new_guest(). Each main.rs reads its input the way
+# the matching host-side pattern in input_builder.rs writes it.
+_GUEST_CARGO_TOML = """\
+[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+risc0-zkvm = {{ version = "1.2", default-features = false, features = ["std"] }}
+{extra_dependencies}
+[workspace]
+"""
+
+_GUEST_TEMPLATES = {
+    "cbor": (
+        '# minicbor with alloc for Vec/String support and derive for the macros\n'
+        'minicbor = { version = "0.24", default-features = false, features = ["alloc", "derive"] }\n',
+        """\
+// CBOR-only pattern: the whole input is one CBOR value
+//
+// Host:
+//     input_data = pyr0.InputBuilder().write_cbor(cbor2.dumps([7, b"payload"])).build()
+//     receipt = pyr0.prove(image, input_data)
+
+use minicbor::{Decode, Encode};
+use risc0_zkvm::guest::env;
+use std::io::Read;
+
+// minicbor's derive encodes structs as arrays indexed by #[n(...)],
+// so this matches the Python list [value, data]
+#[derive(Debug, Decode)]
+struct Input {
+    #[n(0)] value: u64,
+    #[cbor(n(1), with = "minicbor::bytes")] data: Vec<u8>,
+}
+
+#[derive(Debug, Encode)]
+struct Output {
+    #[n(0)] value: u64,
+    #[n(1)] data_len: u32,
+}
+
+fn main() {
+    // Read all input bytes from stdin (raw CBOR bytes)
+    let mut buf = Vec::<u8>::new();
+    env::stdin().read_to_end(&mut buf).unwrap();
+
+    let input: Input = minicbor::decode(&buf)
+        .expect("Failed to decode CBOR input");
+
+    let output = Output {
+        value: input.value,
+        data_len: input.data.len() as u32,
+    };
+
+    // Encode output to CBOR and commit to journal
+    let out_bytes = minicbor::to_vec(output)
+        .expect("Failed to encode output");
+    env::commit_slice(&out_bytes);
+}
+""",
+    ),
+    "raw": (
+        "",
+        """\
+// Raw-only pattern: fixed-size little-endian fields, read in write order
+//
+// Host:
+//     input_data = pyr0.InputBuilder().write_u32(7).write_bytes32(key).build()
+//     receipt = pyr0.prove(image, input_data)
+
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // write_u32: 4 bytes, little-endian
+    let mut count = [0u8; 4];
+    env::read_slice(&mut count);
+    let count = u32::from_le_bytes(count);
+
+    // write_bytes32: exactly 32 bytes
+    let mut key = [0u8; 32];
+    env::read_slice(&mut key);
+
+    env::commit(&count);
+    env::commit_slice(&key);
+}
+""",
+    ),
+    "composition": (
+        "",
+        """\
+// Composition pattern: verify an inner receipt with env::verify()
+//
+// Host:
+//     comp = pyr0.Composer(outer_image)
+//     comp.assume(inner_receipt)
+//     comp.write_image_id(inner_image.id)
+//     comp.write_u32(len(inner_receipt.journal_bytes))
+//     comp.write_raw_bytes(inner_receipt.journal_bytes)
+//     receipt = comp.prove()
+
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Image ID of the inner guest (write_image_id, 32 bytes)
+    let mut inner_image_id = [0u8; 32];
+    env::read_slice(&mut inner_image_id);
+
+    // The inner journal, length first (write_u32 + write_raw_bytes)
+    let mut journal_len = [0u8; 4];
+    env::read_slice(&mut journal_len);
+    let mut inner_journal = vec![0u8; u32::from_le_bytes(journal_len) as usize];
+    env::read_slice(&mut inner_journal);
+
+    // Adds an assumption that is checked when the outer receipt is resolved
+    env::verify(inner_image_id, &inner_journal).unwrap();
+
+    // Commit what was verified so a verifier can see it
+    env::commit_slice(&inner_image_id);
+    env::commit_slice(&inner_journal);
+}
+""",
+    ),
+}
+
+
+def new_guest(
+    template: str,
+    dest_dir: str | Path,
+    name: Optional[str] = None,
+) -> Path:
+    """
+    Scaffold a standalone guest crate for one of the InputBuilder patterns.
+
+    The generated main.rs reads its input exactly as the host-side pattern
+    writes it (the matching host code is in a comment at the top), so the
+    layout doesn't have to be copied by hand. The crate builds directly with
+    build_guest().
+
+    Args:
+        template: "cbor" (CBOR-only), "raw" (fixed-size env::read_slice()
+                  fields) or "composition" (env::verify() of an inner receipt)
+        dest_dir: Directory to create the crate in; created if missing, and
+                  must not already contain a Cargo.toml
+        name: Package (and binary) name; defaults to dest_dir's name
+
+    Returns:
+        Path to the crate directory
+
+    Raises:
+        ValueError: If template is unknown or name isn't a valid crate name
+        InvalidGuestDirectoryError: If dest_dir already holds a crate
+
+    Example:
+        guest_dir = pyr0.new_guest("raw", "guests/counter")
+        image = pyr0.load_image_from_path(pyr0.build_guest(guest_dir))
+    """
+    if template not in _GUEST_TEMPLATES:
+        raise ValueError(
+            f"Unknown guest template '{template}'. Use one of: {', '.join(_GUEST_TEMPLATES)}"
+        )
+    guest_path = Path(dest_dir).resolve()
+    if name is None:
+        name = re.sub(r"[^A-Za-z0-9_-]+", "-", guest_path.name).strip("-").lower()
+    if not re.fullmatch(r"[A-Za-z][A-Za-z0-9_-]*", name or ""):
+        raise ValueError(f"'{name}' is not a valid crate name")
+    if (guest_path / "Cargo.toml").exists():
+        raise InvalidGuestDirectoryError(f"Guest directory already contains a crate: {guest_path}")
+
+    extra_dependencies, main_rs = _GUEST_TEMPLATES[template]
+    (guest_path / "src").mkdir(parents=True, exist_ok=True)
+    (guest_path / "Cargo.toml").write_text(
+        _GUEST_CARGO_TOML.format(name=name, extra_dependencies=extra_dependencies)
+    )
+    (guest_path / "src" / "main.rs").write_text(main_rs)
+    return guest_path
@@ -0,0 +1,153 @@
+"""
+Encoders and decoders for RISC Zero's word-based serde format.
+
+This is the format produced by the guest's ``env::commit(&value)`` and
+consumed by ``env::read()``. Every primitive occupies at least one
+little-endian u32 word:
+
+- u8/u16/u32/i8/i16/i32/bool/f32: one word (small ints are widened)
+- u64/i64/f64: two words, low word first
+- String: length word, then UTF-8 bytes packed into zero-padded words
+- Vec<u8>: length word, then ONE WORD PER BYTE
+- [u8; N]: N words, one per byte, no length prefix
+
+Values are given either as ``(type, value)`` tuples or, for unambiguous
+Python types, untagged: ``bool`` -> bool, ``str`` -> String,
+``bytes`` -> Vec<u8>, ``float`` -> f64. Plain ints must always be tagged,
+since the host can't know whether the guest committed a u8, u32 or u64.
+
+Type names accept Rust spellings (``"u32"``, ``"Vec<u8>"``, ``"String"``,
+``"[u8; 32]"``) and Python aliases (``"bytes"``, ``"str"``).
+
+Constant-size journals: a journal's length is public, so a guest whose
+output length depends on private data leaks it. Such a guest should commit
+its values and then ``env::commit_slice(&[0u8; N])`` with enough zeros to
+reach a fixed total size agreed with the host. On the host, build expected
+journals with ``pad_journal_to()``, decode with ``commit_decode(...,
+padded=True)``, and enforce the size with
+``Composer.assert_journal(size=...)``.
+"""
+
+import hashlib
+from typing import Any, Iterable, List, Sequence, Union
+
+from pyr0._rust import _serde_encode, _serde_decode
+
+
+def commit_encode(values: Iterable[Any]) -> bytes:
+    """
+    Produce the journal bytes a guest's sequence of env::commit() calls appends.
+    
+    Use this in host-side tests to build expected journals for
+    ``Composer.expect_verification()`` without running the guest.
+    
+    Args:
+        values: Sequence of ``(type, value)`` tuples or untagged bool/str/bytes/float
+    
+    Returns:
+        The encoded bytes
+    
+    Raises:
+        TypeError: If an untagged value's type can't be inferred (e.g. a bare int)
+        ValueError: If a type name is unknown or a value doesn't fit its type
+    
+    Example:
+        >>> # Guest: env::commit(&42u8); env::commit(&(len as u32));
+        >>> commit_encode([("u8", 42), ("u32", 7)]).hex()
+        '2a00000007000000'
+    """
+    return _serde_encode(values)
+
+
+def commit_decode(
+    data: bytes, fmt: Sequence[str], strict: bool = True, padded: bool = False
+) -> List[Any]:
+    """
+    Decode bytes written by a guest's env::commit() calls.
+    
+    Args:
+        data: Encoded bytes (e.g. a receipt journal)
+        fmt: Type name of each committed value, in order
+        strict: If True (default), reject trailing bytes after the last value
+        padded: If True, the trailing bytes are constant-size padding and
+                must all be zero (overrides strict)
+    
+    Returns:
+        List of decoded Python values (ints, bools, floats, str, bytes)
+    
+    Raises:
+        ValueError: If the data is truncated, malformed, or has trailing bytes
+    
+    Example:
+        >>> commit_decode(bytes.fromhex('2a00000007000000'), ["u8", "u32"])
+        [42, 7]
+    """
+    return _serde_decode(data, list(fmt), strict, padded)
+
+
+class JournalHasher:
+    """
+    Incrementally compute a journal digest.
+    
+    Each call appends to the journal the way the guest call of the same
+    name does, so the digest can be derived from a stream of expected
+    outputs without buffering the whole journal. Pass the result to
+    ``Composer.expect_verification_digest()`` or ``Composer.assume_claim()``.
+    
+    Example:
+        >>> h = JournalHasher()
+        >>> h.commit([("u32", 7)])            # env::commit(&7u32)
+        >>> h.commit_slice(bytes([1, 2, 3, 4]))  # env::commit_slice(&[1u8, 2, 3, 4])
+        >>> h.digest() == hashlib.sha256(bytes.fromhex("0700000001020304")).digest()
+        True
+    """
+    
+    def __init__(self) -> None:
+        self._sha256 = hashlib.sha256()
+        self.size = 0
+    
+    def commit_slice(self, data: Union[bytes, bytearray, memoryview]) -> None:
+        """Append raw bytes, like env::commit_slice()."""
+        self._sha256.update(data)
+        self.size += len(data)
+    
+    def commit(self, values: Iterable[Any]) -> None:
+        """Append values encoded as env::commit() would (see commit_encode())."""
+        self.commit_slice(commit_encode(values))
+    
+    def digest(self) -> bytes:
+        """SHA-256 of the journal so far (32 bytes); more can still be appended."""
+        return self._sha256.copy().digest()
+    
+    def hexdigest(self) -> str:
+        """digest() as lowercase hex."""
+        return self._sha256.copy().hexdigest()
+
+
+def pad_journal_to(data: bytes, size: int) -> bytes:
+    """
+    Zero-pad journal bytes to a constant size.
+    
+    Gives the journal a guest following the constant-size convention (see
+    the module docs) produces, for use as an expected journal.
+    
+    Args:
+        data: Encoded values, e.g. from commit_encode()
+        size: Total journal size in bytes; must be a multiple of 4, since
+              guests commit whole words
+    
+    Returns:
+        data followed by zero bytes, exactly size bytes long
+    
+    Raises:
+        ValueError: If size isn't a multiple of 4 or data is longer than size
+    
+    Example:
+        >>> pad_journal_to(commit_encode([("u32", 7)]), 12).hex()
+        '070000000000000000000000'
+    """
+    if size < 0 or size % 4:
+        raise ValueError(f"size must be a non-negative multiple of 4, got {size}")
+    if len(data) > size:
+        raise ValueError(f"{len(data)} bytes of journal data don't fit in {size} bytes")
+    return bytes(data) + bytes(size - len(data))
@@ -0,0 +1,26 @@
+"""Type stubs for PyR0 codec module."""
+
+from typing import Any, Iterable, List, Sequence, Union
+
+def commit_encode(values: Iterable[Any]) -> bytes:
+    """Encode values exactly as a guest's env::commit() calls would."""
+    ...
+
+def commit_decode(
+    data: bytes, fmt: Sequence[str], strict: bool = True, padded: bool = False
+) -> List[Any]:
+    """Decode env::commit() output according to a list of type names."""
+    ...
+
+class JournalHasher:
+    """Incrementally compute a journal digest from env::commit()/commit_slice() chunks."""
+    size: int
+    def __init__(self) -> None: ...
+    def commit_slice(self, data: Union[bytes, bytearray, memoryview]) -> None: ...
+    def commit(self, values: Iterable[Any]) -> None: ...
+    def digest(self) -> bytes: ...
+    def hexdigest(self) -> str: ...
+
+def pad_journal_to(data: bytes, size: int) -> bytes:
+    """Zero-pad journal bytes to a constant size."""
+    ...
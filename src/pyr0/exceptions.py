@@ -5,13 +5,38 @@ These exceptions provide more specific error handling for different
 failure modes in zero-knowledge proof operations.
 """
 
+from enum import Enum
+from typing import Optional
+
 
 class PyR0Error(Exception):
     """Base exception for all PyR0 errors."""
     pass
 
 
-class CompositionError(PyR0Error):
+class ProvingError(PyR0Error, RuntimeError):
+    """
+    Raised when guest execution or proof generation fails.
+    
+    This covers executor failures (guest panics, bad input), prover errors
+    and receipt compression failures. Running past max_cycles raises the
+    more specific SessionLimitExceeded instead.
+    """
+    pass
+
+
+class ImageError(PyR0Error, ValueError):
+    """
+    Raised when an ELF can't be loaded as a guest image.
+    
+    This indicates:
+    - The bytes are not a valid RISC-V ELF for the zkVM
+    - The image ID couldn't be computed
+    """
+    pass
+
+
+class CompositionError(PyR0Error, RuntimeError):
     """
     Raised when proof composition fails.
     
@@ -23,20 +48,63 @@ class CompositionError(PyR0Error):
     pass
 
 
-class VerificationError(PyR0Error):
+class VerificationStage(str, Enum):
+    """
+    Which check rejected a receipt (VerificationError.code).
+    
+    Values compare equal to their names, e.g. ``e.code == "IMAGE_ID"``.
+    """
+    SEAL = "SEAL"                # Cryptographic proof invalid or corrupt
+    IMAGE_ID = "IMAGE_ID"        # Receipt proves a different program
+    EXIT_CODE = "EXIT_CODE"      # Guest did not halt with exit code 0
+    JOURNAL = "JOURNAL"          # Journal doesn't match the proven claim
+    ASSUMPTIONS = "ASSUMPTIONS"  # Receipt is conditional on unresolved assumptions
+    CLAIM = "CLAIM"              # Claim mismatch not attributable to the above
+    TIMEOUT = "TIMEOUT"          # Verification exceeded its deadline
+    SIGNATURE = "SIGNATURE"      # Envelope signature (COSE_Sign1) invalid
+    POLICY = "POLICY"            # Receipt kind or journal size not allowed by a VerificationPolicy
+
+
+class VerificationError(PyR0Error, RuntimeError):
     """
     Raised when proof verification fails.
     
-    This indicates:
-    - Invalid cryptographic proof
-    - Image ID mismatch
-    - Failed execution (non-zero exit code)
-    - Corrupted receipt data
+    ``code`` is a VerificationStage (or None if unknown) saying which check
+    failed, so callers can tell a corrupt proof (SEAL) from the wrong
+    program (IMAGE_ID) or a failed guest (EXIT_CODE) without parsing the
+    message. Subclasses RuntimeError for compatibility with older code.
     """
-    pass
+    def __init__(self, message: str, code: Optional[str] = None):
+        super().__init__(message)
+        self.code = VerificationStage(code) if code is not None else None
 
 
-class SerializationError(PyR0Error):
+class VerificationTimeout(VerificationError):
+    """
+    Raised when Receipt.verify(..., deadline_ms=...) exceeds its time budget.
+    
+    The receipt was neither accepted nor rejected. Treat it as untrusted;
+    adversarially deep composite receipts are the usual cause.
+    """
+    def __init__(self, message: str, code: Optional[str] = VerificationStage.TIMEOUT):
+        super().__init__(message, code)
+
+
+class SessionLimitExceeded(PyR0Error, RuntimeError):
+    """
+    Raised when a guest runs past the max_cycles given to a prove function.
+    
+    Attributes:
+        max_cycles: The limit that was exceeded
+        exit: ExitStatus with kind ExitKind.SESSION_LIMIT
+    """
+    def __init__(self, message: str, max_cycles: Optional[int] = None, exit=None):
+        super().__init__(message)
+        self.max_cycles = max_cycles
+        self.exit = exit
+
+
+class SerializationError(PyR0Error, ValueError):
     """
     Raised when serialization/deserialization fails.
     
@@ -60,7 +128,7 @@ class PreflightError(CompositionError):
         self.issues = issues
 
 
-class AssumptionError(CompositionError):
+class AssumptionError(CompositionError, ValueError):
     """
     Raised when assumption validation fails.
     
@@ -69,4 +137,42 @@ class AssumptionError(CompositionError):
     - Using a fake receipt in production
     - Using a failed receipt (non-zero exit)
     """
-    pass
\ No newline at end of file
+    pass
+
+class InputPatternError(CompositionError, ValueError):
+    """
+    Raised when a Composer's write methods mix incompatible input patterns.
+    
+    write_cbor() writes an unframed CBOR object that the guest decodes from
+    the whole of stdin (Pattern A), so combining it with any other write
+    silently corrupts the input. Use write_cbor_frame() to mix CBOR with raw
+    fields, or Composer(image, input_mixing="warn") to downgrade this to a
+    warning.
+    
+    Attributes:
+        method: The write method that was rejected
+        conflicts_with: The earlier write method it conflicts with
+    """
+    def __init__(self, message: str, method: Optional[str] = None, conflicts_with: Optional[str] = None):
+        super().__init__(message)
+        self.method = method
+        self.conflicts_with = conflicts_with
+
+
+class JournalAssertionError(PyR0Error, AssertionError):
+    """
+    Raised when a journal doesn't meet Composer.assert_journal() expectations.
+    
+    Proving succeeded, but the guest committed something other than what
+    was declared - usually a guest logic regression.
+    
+    Attributes:
+        mismatches: One human-readable diff per failed expectation
+        journal: The journal bytes that were checked
+        receipt: The receipt that was produced (None after dry_run())
+    """
+    def __init__(self, message: str, mismatches: list[str], journal: bytes = b"", receipt=None):
+        super().__init__(message)
+        self.mismatches = mismatches
+        self.journal = journal
+        self.receipt = receipt
@@ -0,0 +1,78 @@
+"""
+Panic-free parsers for fuzzing PyR0's untrusted-input surface.
+
+Receipts and ELF files are the two byte formats PyR0 parses on behalf of
+callers that may not trust their source. These entry points give a fuzzer
+(atheris, or a cargo-fuzz target driving the same code) a simple contract:
+for ANY input they either return an object or raise a ``PyR0Error``. A Rust
+panic inside the parser is caught and raised as the same error type, with
+"panicked" in the message, instead of escaping as ``PanicException``.
+
+Example atheris harness::
+
+    import sys
+    import atheris
+
+    with atheris.instrument_imports():
+        import pyr0
+
+    def test_one_input(data):
+        try:
+            pyr0.fuzz.receipt_from_bytes(data)
+        except pyr0.SerializationError as e:
+            assert "panicked" not in str(e), e
+
+    atheris.Setup(sys.argv, test_one_input)
+    atheris.Fuzz()
+"""
+
+from typing import Optional
+
+from pyr0._rust import (
+    DeserializationPolicy,
+    Image,
+    Receipt,
+    _fuzz_image_from_bytes,
+    _fuzz_receipt_from_bytes,
+)
+
+
+def receipt_from_bytes(data: bytes, policy: Optional[DeserializationPolicy] = None) -> Receipt:
+    """
+    Deserialize a receipt, converting every failure into SerializationError.
+    
+    Same decoding as ``Receipt.from_bytes()``, including the
+    DeserializationPolicy size and nesting limits.
+    
+    Args:
+        data: Untrusted receipt bytes
+        policy: Limits to enforce (default: DeserializationPolicy())
+    
+    Returns:
+        The decoded Receipt (not verified)
+    
+    Raises:
+        SerializationError: If the bytes aren't a valid receipt, exceed the
+                            policy, or make the decoder panic
+    """
+    return _fuzz_receipt_from_bytes(data, policy)
+
+
+def image_from_bytes(data: bytes) -> Image:
+    """
+    Parse an ELF into an Image, converting every failure into ImageError.
+    
+    Same parsing as ``pyr0.load_image()``: computes the image ID and builds
+    the guest memory image.
+    
+    Args:
+        data: Untrusted ELF bytes
+    
+    Returns:
+        The loaded Image
+    
+    Raises:
+        ImageError: If the bytes aren't a loadable RISC-V guest ELF, or make
+                    the parser panic
+    """
+    return _fuzz_image_from_bytes(data)
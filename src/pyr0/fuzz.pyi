@@ -0,0 +1,13 @@
+"""Type stubs for PyR0 fuzz module."""
+
+from typing import Optional
+
+from pyr0._rust import DeserializationPolicy, Image, Receipt
+
+def receipt_from_bytes(data: bytes, policy: Optional[DeserializationPolicy] = None) -> Receipt:
+    """Deserialize a receipt; any failure, including a panic, raises SerializationError."""
+    ...
+
+def image_from_bytes(data: bytes) -> Image:
+    """Parse an ELF into an Image; any failure, including a panic, raises ImageError."""
+    ...
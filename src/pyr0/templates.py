@@ -0,0 +1,181 @@
+"""
+Named Composer templates for proofs with a recurring shape.
+
+A service that proves the same statement thousands of times a day builds
+nearly the same Composer every time: the same guest, the same leading
+input bytes, the same assumptions and expected verifications, and a few
+fields that change per call. A ``ComposerTemplate`` declares all of that
+once; ``instantiate()`` fills in the variable fields, checks that exactly
+the declared ones were given, and writes them in the declared order.
+
+Example::
+
+    from pyr0.templates import ComposerTemplate, register_template, get_template
+
+    register_template(ComposerTemplate(
+        "transfer",
+        outer_image,
+        fields=[("amount", "u64"), ("memo", "str_frame")],
+        assumptions=[balance_receipt],
+        expected_verifications=[(balance_image.id, balance_receipt.journal_bytes)],
+    ))
+
+    receipt = get_template("transfer").prove(amount=250, memo="rent")
+
+Field types name a Composer writer (``"u64"`` calls ``write_u64()``,
+``"frame"`` calls ``write_frame()``, ...) or, as ``"serde:<type>"``, a
+value written with ``write_serde()`` for guests using ``env::read()``.
+"""
+
+from typing import Any, Dict, List, Optional, Sequence, Tuple, Union
+
+from pyr0._rust import Claim, Composer, Image, Receipt, ReceiptKind, SharedAssumption
+
+#: Field types that map directly to a Composer.write_<type>() method
+WRITER_TYPES = (
+    "u32", "u64", "i32", "i64", "f32", "f64", "bool",
+    "bytes32", "image_id", "raw_bytes", "frame", "str_frame", "cbor_frame",
+)
+
+SERDE_PREFIX = "serde:"
+
+_registry: Dict[str, "ComposerTemplate"] = {}
+
+
+class ComposerTemplate:
+    """
+    The fixed parts of a composition, plus the fields that vary per call.
+
+    Args:
+        name: Name to register the template under
+        image: The outer guest's Image
+        fields: (name, type) pairs written after input_prefix, in order.
+                type is one of WRITER_TYPES or "serde:<type>", e.g.
+                "serde:Vec<u8>"
+        input_prefix: Bytes written first on every instance (raw)
+        assumptions: Receipts or SharedAssumptions every instance assumes
+        expected_verifications: Claims, or (image_id, journal) pairs, the
+                                guest is expected to env::verify()
+        kind: Receipt kind prove() produces (default: Composer's default)
+        input_mixing: Passed to Composer()
+
+    Raises:
+        ValueError: If a field name repeats or a field type is unknown
+    """
+
+    def __init__(
+        self,
+        name: str,
+        image: Image,
+        fields: Sequence[Tuple[str, str]] = (),
+        input_prefix: bytes = b"",
+        assumptions: Sequence[Union[Receipt, SharedAssumption]] = (),
+        expected_verifications: Sequence[Union[Claim, Tuple[bytes, bytes]]] = (),
+        kind: Optional[Union[ReceiptKind, str]] = None,
+        input_mixing: str = "error",
+    ):
+        self.name = name
+        self.image = image
+        self.fields: List[Tuple[str, str]] = [(field, ty) for field, ty in fields]
+        self.input_prefix = bytes(input_prefix)
+        self.assumptions = list(assumptions)
+        self.expected_verifications = list(expected_verifications)
+        self.kind = kind
+        self.input_mixing = input_mixing
+
+        seen = set()
+        for field, ty in self.fields:
+            if field in seen:
+                raise ValueError(f"Template '{name}' declares field '{field}' twice")
+            seen.add(field)
+            if ty not in WRITER_TYPES and not ty.startswith(SERDE_PREFIX):
+                raise ValueError(
+                    f"Template '{name}' field '{field}' has unknown type '{ty}'. "
+                    f"Use one of {', '.join(WRITER_TYPES)} or '{SERDE_PREFIX}<type>'"
+                )
+
+    def instantiate(self, **values: Any) -> Composer:
+        """
+        Build a Composer from the template and this call's field values.
+
+        Raises:
+            ValueError: If a declared field is missing, an undeclared one is
+                        given, or a value doesn't fit its field type
+        """
+        declared = [field for field, _ in self.fields]
+        missing = [field for field in declared if field not in values]
+        unknown = sorted(set(values) - set(declared))
+        if missing or unknown:
+            problems = []
+            if missing:
+                problems.append(f"missing {', '.join(missing)}")
+            if unknown:
+                problems.append(f"unexpected {', '.join(unknown)}")
+            raise ValueError(f"Template '{self.name}': {'; '.join(problems)}")
+
+        comp = Composer(self.image, input_mixing=self.input_mixing)
+        for assumption in self.assumptions:
+            if isinstance(assumption, SharedAssumption):
+                comp.assume_shared(assumption)
+            else:
+                comp.assume(assumption)
+        for expected in self.expected_verifications:
+            if isinstance(expected, Claim):
+                comp.expect_claim(expected)
+            else:
+                image_id, journal = expected
+                comp.expect_verification(image_id, journal)
+
+        if self.input_prefix:
+            comp.write_raw_bytes(self.input_prefix)
+        for field, ty in self.fields:
+            try:
+                if ty.startswith(SERDE_PREFIX):
+                    comp.write_serde([(ty[len(SERDE_PREFIX):], values[field])])
+                else:
+                    getattr(comp, f"write_{ty}")(values[field])
+            except (TypeError, ValueError, OverflowError) as e:
+                raise ValueError(f"Template '{self.name}' field '{field}' ({ty}): {e}") from e
+        return comp
+
+    def prove(self, **values: Any) -> Receipt:
+        """Instantiate the template and prove it with the template's kind."""
+        comp = self.instantiate(**values)
+        if self.kind is None:
+            return comp.prove()
+        return comp.prove(kind=self.kind)
+
+    def __repr__(self) -> str:
+        fields = ", ".join(f"{field}: {ty}" for field, ty in self.fields)
+        return f"ComposerTemplate({self.name!r}, fields=[{fields}])"
+
+
+def register_template(template: ComposerTemplate, replace: bool = False) -> ComposerTemplate:
+    """
+    Register a template under its name and return it.
+
+    Raises:
+        ValueError: If the name is taken and replace is False
+    """
+    if template.name in _registry and not replace:
+        raise ValueError(f"Template '{template.name}' is already registered; pass replace=True to override it")
+    _registry[template.name] = template
+    return template
+
+
+def get_template(name: str) -> ComposerTemplate:
+    """
+    Look up a registered template.
+
+    Raises:
+        KeyError: If no template has that name
+    """
+    try:
+        return _registry[name]
+    except KeyError:
+        raise KeyError(f"No template named '{name}' (registered: {', '.join(sorted(_registry)) or 'none'})") from None
+
+
+def template_names() -> List[str]:
+    """Names of all registered templates, sorted."""
+    return sorted(_registry)
@@ -0,0 +1,45 @@
+"""Type stubs for PyR0 templates module."""
+
+from typing import Any, List, Optional, Sequence, Tuple, Union
+
+from pyr0._rust import Claim, Composer, Image, Receipt, ReceiptKind, SharedAssumption
+
+WRITER_TYPES: Tuple[str, ...]
+SERDE_PREFIX: str
+
+class ComposerTemplate:
+    name: str
+    image: Image
+    fields: List[Tuple[str, str]]
+    input_prefix: bytes
+    assumptions: List[Union[Receipt, SharedAssumption]]
+    expected_verifications: List[Union[Claim, Tuple[bytes, bytes]]]
+    kind: Optional[Union[ReceiptKind, str]]
+    input_mixing: str
+    def __init__(
+        self,
+        name: str,
+        image: Image,
+        fields: Sequence[Tuple[str, str]] = (),
+        input_prefix: bytes = b"",
+        assumptions: Sequence[Union[Receipt, SharedAssumption]] = (),
+        expected_verifications: Sequence[Union[Claim, Tuple[bytes, bytes]]] = (),
+        kind: Optional[Union[ReceiptKind, str]] = None,
+        input_mixing: str = "error",
+    ) -> None: ...
+    def instantiate(self, **values: Any) -> Composer:
+        """Build a Composer from the template and this call's field values."""
+        ...
+    def prove(self, **values: Any) -> Receipt:
+        """Instantiate the template and prove it with the template's kind."""
+        ...
+
+def register_template(template: ComposerTemplate, replace: bool = False) -> ComposerTemplate:
+    """Register a template under its name and return it."""
+    ...
+def get_template(name: str) -> ComposerTemplate:
+    """Look up a registered template."""
+    ...
+def template_names() -> List[str]:
+    """Names of all registered templates, sorted."""
+    ...
@@ -0,0 +1,157 @@
+"""
+Fast, deterministic tests for composition-heavy applications.
+
+Proving a composition end to end takes minutes. ``CompositionHarness`` runs
+each guest in the executor only and wraps the result in a FAKE receipt
+(``pyr0.prove_fake``), which the next guest can ``env::verify()`` - so a whole
+proof tree executes in seconds. The claims and journals it produces are
+recorded once to a JSON fixture and checked on every later run, so a change
+in any guest's output shows up as a failing test in CI.
+
+Example (pytest)::
+
+    from pyr0.testing import CompositionHarness
+
+    def test_pipeline():
+        with CompositionHarness("tests/fixtures/pipeline.json") as h:
+            inner = h.run("inner", inner_image, inner_input)
+            h.run("outer", outer_image, outer_input, assumptions=[inner])
+
+Run with ``PYR0_RECORD_FIXTURES=1`` (or delete the fixture) to re-record
+after an intended change.
+
+Fake receipts prove nothing; nothing produced here may be verified or
+shipped. Use real proving for at least one end-to-end test.
+"""
+
+import json
+import os
+import warnings
+from pathlib import Path
+from typing import Any, Dict, Optional, Sequence, Union
+
+from pyr0._rust import Image, Receipt, prove_fake
+from pyr0.exceptions import JournalAssertionError
+
+FIXTURE_VERSION = 1
+RECORD_ENV_VAR = "PYR0_RECORD_FIXTURES"
+
+
+class CompositionHarness:
+    """
+    Runs guests with fake receipts and records or replays their claims.
+
+    Args:
+        fixture_path: JSON file holding the recorded claims
+        record: True to (re-)record the fixture, False to replay it. By
+                default, records if PYR0_RECORD_FIXTURES is set or the
+                fixture doesn't exist yet, and replays otherwise.
+
+    In replay mode, ``run()`` raises JournalAssertionError as soon as a step's
+    claim differs from the fixture, and ``finish()`` (called on leaving a
+    ``with`` block) raises if any recorded step was never run. In record
+    mode, ``finish()`` writes the fixture.
+    """
+
+    def __init__(self, fixture_path: Union[str, "os.PathLike[str]"], record: Optional[bool] = None):
+        self.fixture_path = Path(fixture_path)
+        if record is None:
+            record = bool(os.environ.get(RECORD_ENV_VAR)) or not self.fixture_path.exists()
+        self.record = record
+        self._expected: Dict[str, Dict[str, Any]] = {}
+        if not record:
+            fixture = json.loads(self.fixture_path.read_text())
+            if fixture.get("version") != FIXTURE_VERSION:
+                raise ValueError(
+                    f"{self.fixture_path} has fixture version {fixture.get('version')}, "
+                    f"expected {FIXTURE_VERSION}; re-record it with {RECORD_ENV_VAR}=1"
+                )
+            self._expected = fixture["steps"]
+        self._seen: Dict[str, Dict[str, Any]] = {}
+
+    def run(
+        self,
+        name: str,
+        image: Image,
+        input_bytes: Any,
+        assumptions: Sequence[Receipt] = (),
+        max_cycles: Optional[int] = None,
+    ) -> Receipt:
+        """
+        Execute one guest and check (or record) its claim.
+
+        Args:
+            name: Unique name of this step in the fixture
+            image: Guest to run
+            input_bytes: Guest input (anything pyr0.prove() accepts)
+            assumptions: Receipts from earlier steps the guest verifies
+            max_cycles: Optional session limit
+
+        Returns:
+            The step's FAKE receipt, to pass as an assumption to later steps
+
+        Raises:
+            ValueError: If name was already used
+            JournalAssertionError: In replay mode, if the claim differs from
+                                   the fixture
+        """
+        if name in self._seen:
+            raise ValueError(f"Step '{name}' was already run")
+        with warnings.catch_warnings():
+            warnings.simplefilter("ignore")  # prove_fake's INSECURE warning
+            receipt = prove_fake(image, input_bytes, max_cycles, list(assumptions))
+
+        actual = json.loads(receipt.claim().to_json())
+        self._seen[name] = actual
+        if not self.record:
+            self._check(name, actual, receipt)
+        return receipt
+
+    def _check(self, name: str, actual: Dict[str, Any], receipt: Receipt) -> None:
+        expected = self._expected.get(name)
+        if expected is None:
+            raise JournalAssertionError(
+                f"Step '{name}' is not in {self.fixture_path}; re-record with {RECORD_ENV_VAR}=1",
+                [f"{name}: not recorded"],
+                receipt.journal_bytes,
+                receipt,
+            )
+        mismatches = [
+            f"{name}.{field}: expected {expected.get(field)!r}, got {value!r}"
+            for field, value in actual.items()
+            if expected.get(field) != value
+        ]
+        if mismatches:
+            raise JournalAssertionError(
+                f"Step '{name}' differs from {self.fixture_path}:\n" + "\n".join(mismatches),
+                mismatches,
+                receipt.journal_bytes,
+                receipt,
+            )
+
+    def finish(self) -> None:
+        """
+        Write the fixture (record mode) or check every recorded step ran (replay).
+
+        Raises:
+            JournalAssertionError: In replay mode, if recorded steps were skipped
+        """
+        if self.record:
+            self.fixture_path.parent.mkdir(parents=True, exist_ok=True)
+            fixture = {"version": FIXTURE_VERSION, "steps": self._seen}
+            self.fixture_path.write_text(json.dumps(fixture, indent=2, sort_keys=True) + "\n")
+            return
+        missing = sorted(set(self._expected) - set(self._seen))
+        if missing:
+            raise JournalAssertionError(
+                f"Recorded steps never ran: {', '.join(missing)}",
+                [f"{name}: not run" for name in missing],
+            )
+
+    def __enter__(self) -> "CompositionHarness":
+        return self
+
+    def __exit__(self, exc_type, exc, tb) -> None:
+        # Don't mask the original failure, or record a partial fixture
+        if exc_type is None:
+            self.finish()
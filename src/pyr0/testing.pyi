@@ -0,0 +1,29 @@
+"""Type stubs for PyR0 testing module."""
+
+import os
+from typing import Any, Optional, Sequence, Union
+
+from pyr0._rust import Image, Receipt
+
+FIXTURE_VERSION: int
+RECORD_ENV_VAR: str
+
+class CompositionHarness:
+    fixture_path: os.PathLike[str]
+    record: bool
+    def __init__(self, fixture_path: Union[str, "os.PathLike[str]"], record: Optional[bool] = None) -> None: ...
+    def run(
+        self,
+        name: str,
+        image: Image,
+        input_bytes: Any,
+        assumptions: Sequence[Receipt] = (),
+        max_cycles: Optional[int] = None,
+    ) -> Receipt:
+        """Execute one guest with fake receipts and check (or record) its claim."""
+        ...
+    def finish(self) -> None:
+        """Write the fixture (record mode) or check every recorded step ran (replay)."""
+        ...
+    def __enter__(self) -> "CompositionHarness": ...
+    def __exit__(self, exc_type: Any, exc: Any, tb: Any) -> None: ...
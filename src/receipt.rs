@@ -1,13 +1,21 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::exceptions::PyValueError;
+use pyo3::IntoPyObjectExt;
 
 use risc0_zkvm::{
     Receipt as RiscZeroReceipt,
     MaybePruned,
     ExitCode as RiscZeroExitCode,
+    VerificationError,
 };
 use risc0_zkvm::sha::{Digest, Digestible};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 use crate::claim::Claim;
+use crate::errors;
+use crate::segment::SegmentReceipt;
+use crate::verifier::VerifierContext;
 
 /// Kind of receipt/proof
 #[pyclass(module = "pyr0", eq, eq_int)]
@@ -23,6 +31,63 @@ pub enum ReceiptKind {
     Fake,
 }
 
+impl ReceiptKind {
+    /// Lowercase name, matching the strings accepted by Composer.prove(kind=...)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReceiptKind::Composite => "composite",
+            ReceiptKind::Succinct => "succinct",
+            ReceiptKind::Groth16 => "groth16",
+            ReceiptKind::Fake => "fake",
+        }
+    }
+}
+
+/// Proof type name used in Verifiable Credential envelopes
+const VC_PROOF_TYPE: &str = "RiscZeroReceipt";
+
+/// Receipt.save() file header: magic, container version, then the
+/// risc0-zkvm version that produced the bincode payload
+const RECEIPT_FILE_MAGIC: &[u8; 8] = b"PYR0RCPT";
+const RECEIPT_FILE_VERSION: u16 = 1;
+
+/// Receipts are only bincode-compatible within the same risc0-zkvm release
+/// line: the major version, or major.minor before 1.0
+fn risc0_release_line(version: &str) -> &str {
+    let mut parts = version.splitn(3, '.');
+    let major = parts.next().unwrap_or("");
+    match (major, parts.next()) {
+        ("0", Some(minor)) => &version[..major.len() + 1 + minor.len()],
+        _ => major,
+    }
+}
+
+/// Current UTC time as an RFC 3339 timestamp (second precision)
+pub(crate) fn rfc3339_utc_now() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil-from-days conversion (proleptic Gregorian calendar)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, rem / 3600, (rem % 3600) / 60, rem % 60
+    )
+}
+
 
 /// Exit kind enumeration for Python
 #[pyclass(module = "pyr0", eq, eq_int)]
@@ -40,6 +105,19 @@ pub enum ExitKind {
     Unknown,
 }
 
+impl ExitKind {
+    /// Name as exposed to Python (e.g. "HALTED")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExitKind::Halted => "HALTED",
+            ExitKind::Paused => "PAUSED",
+            ExitKind::SystemSplit => "SYSTEM_SPLIT",
+            ExitKind::SessionLimit => "SESSION_LIMIT",
+            ExitKind::Unknown => "UNKNOWN",
+        }
+    }
+}
+
 /// Structured exit status information
 #[pyclass(module = "pyr0")]
 #[derive(Clone, Debug)]
@@ -48,6 +126,35 @@ pub struct ExitStatus {
     pub kind: ExitKind,
     #[pyo3(get)]
     pub user_code: Option<u32>,
+    /// Index of the segment in which execution stopped (executor results only)
+    #[pyo3(get)]
+    pub segment_index: Option<u32>,
+    /// Total cycles executed when execution stopped (executor results only)
+    #[pyo3(get)]
+    pub cycle_count: Option<u64>,
+}
+
+impl ExitStatus {
+    /// Map a RISC Zero exit code; segment/cycle details are filled in by the caller
+    pub(crate) fn from_risc0(exit_code: RiscZeroExitCode) -> Self {
+        let (kind, user_code) = match exit_code {
+            RiscZeroExitCode::Halted(code) => (ExitKind::Halted, Some(code)),
+            RiscZeroExitCode::Paused(code) => (ExitKind::Paused, Some(code)),
+            RiscZeroExitCode::SystemSplit => (ExitKind::SystemSplit, None),
+            RiscZeroExitCode::SessionLimit => (ExitKind::SessionLimit, None),
+        };
+        Self { kind, user_code, segment_index: None, cycle_count: None }
+    }
+
+    /// JSON form described by schema("ExitStatus")
+    pub(crate) fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "kind": self.kind.as_str(),
+            "user_code": self.user_code,
+            "segment_index": self.segment_index,
+            "cycle_count": self.cycle_count,
+        })
+    }
 }
 
 #[pymethods]
@@ -58,27 +165,677 @@ impl ExitStatus {
         matches!(self.kind, ExitKind::Halted) && self.user_code == Some(0)
     }
     
+    /// True if execution stopped because of a system split or session limit
+    #[getter]
+    pub fn is_limit(&self) -> bool {
+        matches!(self.kind, ExitKind::SystemSplit | ExitKind::SessionLimit)
+    }
+    
     pub fn __repr__(&self) -> String {
-        match (&self.kind, self.user_code) {
-            (ExitKind::Halted, Some(code)) => format!("ExitStatus(HALTED, {})", code),
-            (ExitKind::Paused, Some(code)) => format!("ExitStatus(PAUSED, {})", code),
-            (kind, _) => format!("ExitStatus({:?})", kind),
+        let base = match (&self.kind, self.user_code) {
+            (ExitKind::Halted, Some(code)) => format!("HALTED, {}", code),
+            (ExitKind::Paused, Some(code)) => format!("PAUSED, {}", code),
+            (kind, _) => format!("{:?}", kind),
+        };
+        match (self.segment_index, self.cycle_count) {
+            (Some(segment), Some(cycles)) => {
+                format!("ExitStatus({}, segment={}, cycles={})", base, segment, cycles)
+            }
+            _ => format!("ExitStatus({})", base),
         }
     }
 }
 
+/// Wire encodings of a receipt, selected by name in to_bytes()/from_bytes()
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ReceiptFormat {
+    /// risc0's native encoding, and the default
+    Bincode,
+    Borsh,
+    Cbor,
+}
+
+impl ReceiptFormat {
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "bincode" => Ok(ReceiptFormat::Bincode),
+            "borsh" => Ok(ReceiptFormat::Borsh),
+            "cbor" => Ok(ReceiptFormat::Cbor),
+            _ => Err(PyErr::new::<PyValueError, _>(format!(
+                "Unknown receipt format '{}'. Expected 'bincode', 'borsh' or 'cbor'",
+                name
+            ))),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ReceiptFormat::Bincode => "bincode",
+            ReceiptFormat::Borsh => "borsh",
+            ReceiptFormat::Cbor => "CBOR",
+        }
+    }
+
+    fn encode(self, receipt: &RiscZeroReceipt) -> PyResult<Vec<u8>> {
+        let encoded = match self {
+            ReceiptFormat::Bincode => bincode::serialize(receipt).map_err(|e| e.to_string()),
+            ReceiptFormat::Borsh => borsh::to_vec(receipt).map_err(|e| e.to_string()),
+            ReceiptFormat::Cbor => {
+                let mut out = Vec::new();
+                ciborium::into_writer(receipt, &mut out).map(|_| out).map_err(|e| e.to_string())
+            }
+        };
+        encoded.map_err(|e| errors::serialization_error(format!("Failed to serialize receipt as {}: {e}", self.as_str())))
+    }
+
+    /// Decode without the policy's structural checks (see DeserializationPolicy::decode_as)
+    fn decode(self, data: &[u8], max_size: usize) -> PyResult<RiscZeroReceipt> {
+        use bincode::Options;
+
+        let decoded = match self {
+            // Same encoding as bincode::deserialize, plus a byte limit so length
+            // prefixes can't request allocations larger than the input allows
+            ReceiptFormat::Bincode => bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .allow_trailing_bytes()
+                .with_limit(max_size as u64)
+                .deserialize(data)
+                .map_err(|e| e.to_string()),
+            // Only reached for trusted data: borsh has no allocation limit
+            ReceiptFormat::Borsh => borsh::from_slice(data).map_err(|e| e.to_string()),
+            ReceiptFormat::Cbor => check_cbor_lengths(data)
+                .and_then(|_| ciborium::from_reader(data).map_err(|e| e.to_string())),
+        };
+        decoded.map_err(|e| errors::serialization_error(format!("Failed to deserialize {} receipt: {e}", self.as_str())))
+    }
+}
+
+/// Check that every length declared in a CBOR item fits in the bytes that follow
+///
+/// ciborium preallocates each array and map from its declared length, so a
+/// few header bytes claiming billions of elements would cost up to a
+/// megabyte apiece. Every element takes at least one byte, so rejecting
+/// lengths longer than the remaining input keeps allocation proportional to
+/// the input size.
+pub(crate) fn check_cbor_lengths(data: &[u8]) -> Result<(), String> {
+    // Items still owed to each open array, map or tag (None: until a break)
+    let mut open: Vec<Option<u64>> = Vec::new();
+    let mut pos = 0;
+    loop {
+        let &initial = data.get(pos).ok_or("truncated CBOR")?;
+        pos += 1;
+        let (major, info) = (initial >> 5, initial & 0x1f);
+        let argument = match info {
+            0..=23 => Some(u64::from(info)),
+            24..=27 => {
+                let size = 1 << (info - 24);
+                let bytes = data.get(pos..pos + size).ok_or("truncated CBOR")?;
+                pos += size;
+                Some(bytes.iter().fold(0, |value, &b| value << 8 | u64::from(b)))
+            }
+            31 => None,
+            _ => return Err(format!("reserved CBOR header {initial:#04x}")),
+        };
+        let remaining = (data.len() - pos) as u64;
+        match (major, argument) {
+            (0 | 1 | 7, Some(_)) => {}
+            (2 | 3, Some(len)) if len <= remaining => pos += len as usize,
+            (4, Some(len)) | (5, Some(len)) if len.saturating_mul(u64::from(major - 3)) <= remaining => {
+                if len > 0 {
+                    open.push(Some(len * u64::from(major - 3)));
+                    continue;
+                }
+            }
+            (2..=5, Some(len)) => {
+                return Err(format!("CBOR item declares {len} elements but only {remaining} bytes follow"));
+            }
+            (2..=5, None) => {
+                open.push(None);
+                continue;
+            }
+            (6, Some(_)) => {
+                open.push(Some(1));
+                continue;
+            }
+            (7, None) => {
+                if open.pop() != Some(None) {
+                    return Err("unexpected CBOR break".to_string());
+                }
+            }
+            _ => return Err(format!("invalid CBOR header {initial:#04x}")),
+        }
+        // One item is complete; close the containers it was the last item of
+        loop {
+            match open.last_mut() {
+                None => return Ok(()),
+                Some(Some(left)) if *left > 1 => {
+                    *left -= 1;
+                    break;
+                }
+                Some(Some(_)) => {
+                    open.pop();
+                }
+                Some(None) => break,
+            }
+        }
+    }
+}
+
+/// CBOR tag of a COSE_Sign1 message (RFC 9052)
+const COSE_SIGN1_TAG: u64 = 18;
+/// COSE header labels and the EdDSA algorithm identifier
+const COSE_HEADER_ALG: i64 = 1;
+const COSE_HEADER_CONTENT_TYPE: i64 = 3;
+const COSE_ALG_EDDSA: i64 = -8;
+/// Content type of the CBOR receipt carried as the COSE payload
+const COSE_CONTENT_TYPE: &str = "application/vnd.pyr0.receipt+cbor";
+
+/// CBOR encoding of a ciborium Value
+fn cbor_bytes(value: &ciborium::Value) -> PyResult<Vec<u8>> {
+    let mut out = Vec::new();
+    ciborium::into_writer(value, &mut out)
+        .map_err(|e| errors::serialization_error(format!("Failed to encode COSE structure: {e}")))?;
+    Ok(out)
+}
+
+/// The bytes a COSE_Sign1 signature covers (Sig_structure, no external AAD)
+fn cose_sig_structure(protected: &[u8], payload: &[u8]) -> PyResult<Vec<u8>> {
+    use ciborium::Value;
+
+    cbor_bytes(&Value::Array(vec![
+        Value::Text("Signature1".into()),
+        Value::Bytes(protected.to_vec()),
+        Value::Bytes(Vec::new()),
+        Value::Bytes(payload.to_vec()),
+    ]))
+}
+
+/// Parse a 32-byte Ed25519 key (seed or public key) argument
+pub(crate) fn ed25519_key(key: &[u8], what: &str) -> PyResult<[u8; 32]> {
+    key.try_into().map_err(|_| PyErr::new::<PyValueError, _>(format!(
+        "{what} must be a 32-byte Ed25519 key, got {} bytes",
+        key.len()
+    )))
+}
+
+/// Limits applied by Receipt.from_bytes() before any cryptographic check
+///
+/// Receipts received from untrusted parties are decoded before they can be
+/// verified, so these bound the work and memory an attacker-supplied blob can
+/// cause. The defaults comfortably fit receipts produced by normal proving.
+#[pyclass(module = "pyr0")]
+#[derive(Clone, Debug)]
+pub struct DeserializationPolicy {
+    /// Maximum serialized size in bytes; also caps any single allocation while decoding
+    #[pyo3(get, set)]
+    pub max_size: usize,
+    /// Maximum number of assumption receipts, counted across all nesting levels
+    #[pyo3(get, set)]
+    pub max_assumptions: usize,
+    /// Maximum nesting of composite receipts inside assumption lists (0 = none)
+    #[pyo3(get, set)]
+    pub max_depth: usize,
+}
+
+impl Default for DeserializationPolicy {
+    fn default() -> Self {
+        Self {
+            max_size: 256 * 1024 * 1024,
+            max_assumptions: 256,
+            max_depth: 8,
+        }
+    }
+}
+
+impl DeserializationPolicy {
+    /// True for unlimited(), the policy for trusted data
+    fn is_unlimited(&self) -> bool {
+        self.max_size == usize::MAX
+    }
+
+    /// Walk a composite receipt's assumptions, enforcing count and depth limits
+    fn check_composite(
+        &self,
+        composite: &risc0_zkvm::CompositeReceipt,
+        depth: usize,
+        total_assumptions: &mut usize,
+    ) -> PyResult<()> {
+        use risc0_zkvm::InnerAssumptionReceipt;
+
+        if depth > self.max_depth {
+            return Err(errors::serialization_error(format!(
+                "Receipt nests composite assumptions deeper than max_depth={}",
+                self.max_depth
+            )));
+        }
+
+        *total_assumptions += composite.assumption_receipts.len();
+        if *total_assumptions > self.max_assumptions {
+            return Err(errors::serialization_error(format!(
+                "Receipt has more than max_assumptions={} assumption receipts",
+                self.max_assumptions
+            )));
+        }
+
+        for assumption in &composite.assumption_receipts {
+            if let InnerAssumptionReceipt::Composite(inner) = assumption {
+                self.check_composite(inner, depth + 1, total_assumptions)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode a receipt in any supported format (detected), enforcing all limits
+    pub(crate) fn decode(&self, data: &[u8]) -> PyResult<RiscZeroReceipt> {
+        self.decode_as(data, None)
+    }
+
+    /// Decode a receipt in the given format, or detect it when None
+    pub(crate) fn decode_as(&self, data: &[u8], format: Option<ReceiptFormat>) -> PyResult<RiscZeroReceipt> {
+        if data.len() > self.max_size {
+            return Err(errors::serialization_error(format!(
+                "Receipt is {} bytes, exceeding max_size={}",
+                data.len(),
+                self.max_size
+            )));
+        }
+
+        let inner = match format {
+            Some(ReceiptFormat::Borsh) if !self.is_unlimited() => {
+                return Err(errors::serialization_error(
+                    "borsh decoding can't limit allocations, so borsh receipts are only \
+                     read with DeserializationPolicy.unlimited(), from a trusted source"
+                ));
+            }
+            Some(format) => format.decode(data, self.max_size)?,
+            // A CBOR receipt is a map; bincode and borsh both start with the
+            // InnerReceipt variant index and can't be told apart up front
+            None if matches!(data.first(), Some(0xA0..=0xBF)) => ReceiptFormat::Cbor.decode(data, self.max_size)?,
+            None if !self.is_unlimited() => ReceiptFormat::Bincode.decode(data, self.max_size)?,
+            None => ReceiptFormat::Bincode
+                .decode(data, self.max_size)
+                .or_else(|e| ReceiptFormat::Borsh.decode(data, self.max_size).map_err(|_| e))?,
+        };
+
+        if let risc0_zkvm::InnerReceipt::Composite(composite) = &inner.inner {
+            self.check_composite(composite, 0, &mut 0)?;
+        }
+        Ok(inner)
+    }
+}
+
+#[pymethods]
+impl DeserializationPolicy {
+    #[new]
+    #[pyo3(signature = (max_size=None, max_assumptions=None, max_depth=None))]
+    pub fn new(max_size: Option<usize>, max_assumptions: Option<usize>, max_depth: Option<usize>) -> Self {
+        let defaults = Self::default();
+        Self {
+            max_size: max_size.unwrap_or(defaults.max_size),
+            max_assumptions: max_assumptions.unwrap_or(defaults.max_assumptions),
+            max_depth: max_depth.unwrap_or(defaults.max_depth),
+        }
+    }
+
+    /// A policy with no limits, for receipts from a trusted source
+    ///
+    /// Also the only policy that reads borsh receipts, whose decoder can't
+    /// bound its allocations.
+    #[staticmethod]
+    pub fn unlimited() -> Self {
+        Self {
+            max_size: usize::MAX,
+            max_assumptions: usize::MAX,
+            max_depth: usize::MAX,
+        }
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "DeserializationPolicy(max_size={}, max_assumptions={}, max_depth={})",
+            self.max_size, self.max_assumptions, self.max_depth
+        )
+    }
+}
+
+/// Everything a verifier requires of a receipt, checked by Receipt.verify_policy()
+///
+/// Collects the checks that are easy to forget when verify() is called by
+/// hand: which programs are trusted, which proof kinds are acceptable (e.g.
+/// only succinct or groth16, never composite or fake), how large a journal
+/// may be, and which exit codes count as success.
+///
+/// Example:
+///     policy = pyr0.VerificationPolicy(
+///         image_ids=registry,
+///         kinds=[pyr0.ReceiptKind.SUCCINCT, pyr0.ReceiptKind.GROTH16],
+///         max_journal_size=1024,
+///     )
+///     image_id = receipt.verify_policy(policy)
+#[pyclass(module = "pyr0", frozen)]
+#[derive(Clone, Debug)]
+pub struct VerificationPolicy {
+    image_ids: Vec<Digest>,
+    kinds: Option<Vec<ReceiptKind>>,
+    /// Largest accepted journal in bytes, or None for no limit
+    #[pyo3(get)]
+    pub max_journal_size: Option<usize>,
+    /// User exit codes accepted as success
+    #[pyo3(get)]
+    pub exit_codes: Vec<u32>,
+}
+
+#[pymethods]
+impl VerificationPolicy {
+    /// Args:
+    ///     image_ids: Trusted image IDs (bytes, hex strings or Images), or
+    ///                an ImageRegistry to trust all of its entries
+    ///     kinds: Accepted ReceiptKinds (or their names); None accepts any
+    ///     max_journal_size: Largest accepted journal in bytes; None for no limit
+    ///     exit_codes: Accepted user exit codes (default: [0])
+    ///
+    /// Raises:
+    ///     ValueError: If image_ids or exit_codes is empty, or an image ID
+    ///                 or kind is invalid
+    #[new]
+    #[pyo3(signature = (image_ids, kinds=None, max_journal_size=None, exit_codes=vec![0]))]
+    pub fn new(
+        image_ids: &Bound<'_, PyAny>,
+        kinds: Option<Vec<Bound<'_, PyAny>>>,
+        max_journal_size: Option<usize>,
+        exit_codes: Vec<u32>,
+    ) -> PyResult<Self> {
+        let image_ids = match image_ids.extract::<PyRef<crate::registry::ImageRegistry>>() {
+            Ok(registry) => registry.digests(),
+            Err(_) => image_ids
+                .try_iter()?
+                .map(|id| image_id_digest(&id?))
+                .collect::<PyResult<Vec<_>>>()?,
+        };
+        if image_ids.is_empty() {
+            return Err(PyErr::new::<PyValueError, _>("image_ids must name at least one trusted image"));
+        }
+        if exit_codes.is_empty() {
+            return Err(PyErr::new::<PyValueError, _>("exit_codes must list at least one exit code"));
+        }
+        let kinds = kinds
+            .map(|kinds| kinds.iter().map(|kind| crate::composer::parse_kind(Some(kind))).collect::<PyResult<Vec<_>>>())
+            .transpose()?;
+        Ok(Self { image_ids, kinds, max_journal_size, exit_codes })
+    }
+
+    /// Trusted image IDs (32 bytes each)
+    #[getter]
+    pub fn image_ids(&self) -> Vec<Vec<u8>> {
+        self.image_ids.iter().map(|id| id.as_bytes().to_vec()).collect()
+    }
+
+    /// Accepted receipt kinds, or None if any kind is accepted
+    #[getter]
+    pub fn kinds(&self) -> Option<Vec<ReceiptKind>> {
+        self.kinds.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        let kinds = match &self.kinds {
+            Some(kinds) => format!("[{}]", kinds.iter().map(ReceiptKind::as_str).collect::<Vec<_>>().join(", ")),
+            None => "any".to_string(),
+        };
+        format!(
+            "VerificationPolicy(image_ids={}, kinds={}, max_journal_size={:?}, exit_codes={:?})",
+            self.image_ids.len(), kinds, self.max_journal_size, self.exit_codes
+        )
+    }
+}
+
+/// Threads that verify with a deadline_ms budget
+///
+/// A timed-out verification keeps running until it finishes, so these run
+/// on a pool with one thread per CPU rather than a thread each: however
+/// many callers give up, abandoned work can't take more than the machine's
+/// cores, and later jobs queue behind it.
+fn deadline_pool() -> Result<&'static rayon::ThreadPool, rayon::ThreadPoolBuildError> {
+    static POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+    if let Some(pool) = POOL.get() {
+        return Ok(pool);
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(std::thread::available_parallelism().map_or(1, |n| n.get()))
+        .thread_name(|i| format!("pyr0-verify-{i}"))
+        .build()?;
+    Ok(POOL.get_or_init(|| pool))
+}
+
 #[pyclass(module = "pyr0")]
 #[derive(Clone)]
 pub struct Receipt {
     pub inner: RiscZeroReceipt,
+    /// Handle of the paused execution held for resume(), if any
+    pub(crate) pause_handle: Option<u64>,
 }
 
 impl Receipt {
     pub fn from_risc0(receipt: RiscZeroReceipt) -> Self {
-        Self { inner: receipt }
+        Self { inner: receipt, pause_handle: None }
+    }
+
+    /// Attach the handle resume() can continue this paused execution with
+    pub(crate) fn with_pause_handle(mut self, handle: Option<u64>) -> Self {
+        self.pause_handle = handle;
+        self
+    }
+
+    /// Decode the claim, failing if it is pruned
+    pub(crate) fn claim_value(&self) -> PyResult<risc0_zkvm::ReceiptClaim> {
+        self.inner.claim()
+            .map_err(|e| errors::serialization_error(format!("Failed to decode claim: {e}")))?
+            .value()
+            .map_err(|_| errors::serialization_error("Claim is pruned"))
+    }
+
+    /// Digest of the full ReceiptClaim (what assumptions and VCs reference)
+    pub(crate) fn claim_digest(&self) -> PyResult<Digest> {
+        let claim = self.inner.claim()
+            .map_err(|e| errors::serialization_error(format!("Failed to decode claim: {e}")))?;
+        Ok(claim.digest())
+    }
+
+    /// Full verification accepting any of the `allowed` user exit codes;
+    /// returns the one the guest halted with
+    fn verify_digest_allowing(&self, py: Python<'_>, digest: Digest, allowed: &[u32]) -> PyResult<u32> {
+        // Seal and journal; the image ID and exit code are checked below
+        self.verify_integrity(py, None)?;
+        
+        let claim = self.claim_value()
+            .map_err(|e| errors::verification_error(py, "CLAIM", e.to_string()))?;
+        let claimed_image_id = claim.pre.digest();
+        if claimed_image_id != digest {
+            return Err(errors::verification_error(py, "IMAGE_ID", format!(
+                "Verification failed (IMAGE_ID): receipt is for image {}, expected {}",
+                hex::encode(claimed_image_id.as_bytes()),
+                hex::encode(digest.as_bytes())
+            )));
+        }
+        let code = match claim.exit_code {
+            RiscZeroExitCode::Halted(code) if allowed.contains(&code) => code,
+            other => return Err(errors::verification_error(py, "EXIT_CODE", format!(
+                "Verification failed (EXIT_CODE): guest exited with {:?}, expected Halted with one of {:?}",
+                other, allowed
+            ))),
+        };
+        
+        // Same final check as verify(), against the claim for this exit code
+        let mut expected = risc0_zkvm::ReceiptClaim::ok(digest, self.inner.journal.bytes.clone());
+        expected.exit_code = RiscZeroExitCode::Halted(code);
+        if claim.digest() != expected.digest() {
+            let unresolved = matches!(&claim.output, MaybePruned::Value(Some(o)) if o.assumptions.digest() != Digest::ZERO);
+            let (stage, detail) = if unresolved {
+                ("ASSUMPTIONS", "receipt is conditional on unresolved assumptions")
+            } else {
+                ("CLAIM", "claim does not match the expected claim")
+            };
+            return Err(errors::verification_error(py, stage, format!("Verification failed ({stage}): {detail}")));
+        }
+        Ok(code)
+    }
+
+    /// Full verification: checks seal, image ID match, and success exit
+    pub(crate) fn verify_digest(&self, py: Python<'_>, image_id: Digest) -> PyResult<()> {
+        let inner = &self.inner;
+        py.allow_threads(|| inner.verify(image_id))
+            .map_err(|e| self.verification_failure(py, image_id, &e))
+    }
+
+    /// Like verify_digest, but against a VerifierContext's parameters
+    fn verify_digest_in(&self, py: Python<'_>, image_id: Digest, context: &VerifierContext) -> PyResult<()> {
+        let inner = &self.inner;
+        py.allow_threads(|| inner.verify_with_context(&context.build(), image_id))
+            .map_err(|e| self.verification_failure(py, image_id, &e))
+    }
+
+    /// Like verify_digest, but gives up after `deadline_ms` milliseconds
+    ///
+    /// Verification runs on the bounded deadline pool while we wait with the
+    /// GIL released. It can't be interrupted, so on timeout it isn't
+    /// cancelled: it finishes on its pool thread and the result is dropped.
+    /// A job whose deadline passed while it was queued is skipped.
+    fn verify_digest_within(
+        &self,
+        py: Python<'_>,
+        image_id: Digest,
+        deadline_ms: u64,
+        context: Option<VerifierContext>,
+    ) -> PyResult<()> {
+        let timeout = || errors::pyr0_error(
+            py,
+            "VerificationTimeout",
+            format!("Verification did not complete within {deadline_ms} ms"),
+        );
+        let deadline = Instant::now() + Duration::from_millis(deadline_ms);
+        let inner = self.inner.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        deadline_pool()
+            .map_err(|e| errors::pyr0_error(py, "VerificationError", format!("Failed to start verification threads: {e}")))?
+            .spawn(move || {
+                let result = if Instant::now() >= deadline {
+                    None
+                } else {
+                    Some(match &context {
+                        Some(context) => inner.verify_with_context(&context.build(), image_id),
+                        None => inner.verify(image_id),
+                    })
+                };
+                // The receiver is gone if we already timed out
+                let _ = tx.send(result);
+            });
+
+        match py.allow_threads(|| rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))) {
+            Ok(Some(Ok(()))) => Ok(()),
+            Ok(Some(Err(e))) => Err(self.verification_failure(py, image_id, &e)),
+            Ok(None) | Err(RecvTimeoutError::Timeout) => Err(timeout()),
+            Err(RecvTimeoutError::Disconnected) => Err(errors::pyr0_error(
+                py,
+                "VerificationError",
+                "Verification worker exited without a result",
+            )),
+        }
+    }
+
+    /// Turn a RISC Zero verification error into a VerificationError with a stage code
+    ///
+    /// risc0 reports a wrong image ID, a failed guest and a conditional receipt
+    /// all as a claim digest mismatch, so those are told apart by comparing the
+    /// receipt's decoded claim against what verify() expected.
+    fn verification_failure(&self, py: Python<'_>, image_id: Digest, err: &VerificationError) -> PyErr {
+        use risc0_zkvm::sha::Sha256;
+
+        let (code, detail) = match err {
+            VerificationError::ClaimDigestMismatch { .. } => match self.claim_value() {
+                Ok(claim) => {
+                    let claimed_image_id = claim.pre.digest();
+                    let output = match &claim.output {
+                        MaybePruned::Value(Some(output)) => Some(output),
+                        _ => None,
+                    };
+                    if claimed_image_id != image_id {
+                        ("IMAGE_ID", format!(
+                            "receipt is for image {}, expected {}",
+                            hex::encode(claimed_image_id.as_bytes()),
+                            hex::encode(image_id.as_bytes())
+                        ))
+                    } else if claim.exit_code != RiscZeroExitCode::Halted(0) {
+                        ("EXIT_CODE", format!("guest exited with {:?}, expected Halted(0)", claim.exit_code))
+                    } else if output.is_some_and(|o| o.assumptions.digest() != Digest::ZERO) {
+                        ("ASSUMPTIONS", "receipt is conditional on unresolved assumptions".to_string())
+                    } else if output.is_some_and(|o| {
+                        o.journal.digest() != *risc0_zkvm::sha::Impl::hash_bytes(&self.inner.journal.bytes)
+                    }) {
+                        ("JOURNAL", "journal does not match the proven claim".to_string())
+                    } else {
+                        ("CLAIM", err.to_string())
+                    }
+                }
+                Err(_) => ("CLAIM", err.to_string()),
+            },
+            VerificationError::JournalDigestMismatch => ("JOURNAL", err.to_string()),
+            VerificationError::UnresolvedAssumption { .. } => ("ASSUMPTIONS", err.to_string()),
+            _ => ("SEAL", err.to_string()),
+        };
+        errors::verification_error(py, code, format!("Verification failed ({code}): {detail}"))
+    }
+}
+
+/// Trusted image ID given as an Image, hex string or 32 bytes
+pub(crate) fn image_id_digest(image_id: &Bound<'_, PyAny>) -> PyResult<Digest> {
+    use crate::image::Image;
+    
+    if let Ok(image) = image_id.extract::<PyRef<Image>>() {
+        digest_from_bytes(&image.id()?)
+    } else if let Ok(hex_str) = image_id.extract::<String>() {
+        digest_from_hex(&hex_str)
+    } else if let Ok(bytes) = image_id.extract::<Vec<u8>>() {
+        digest_from_bytes(&bytes)
+    } else {
+        Err(PyErr::new::<PyValueError, _>(
+            "image_id must be bytes (32 bytes), hex string (64 chars), or Image object"
+        ))
     }
 }
 
+/// Parse a 32-byte image ID from hex (with or without 0x prefix)
+pub(crate) fn digest_from_hex(image_id_hex: &str) -> PyResult<Digest> {
+    // Handle optional 0x prefix
+    let hex_str = if image_id_hex.starts_with("0x") || image_id_hex.starts_with("0X") {
+        &image_id_hex[2..]
+    } else {
+        image_id_hex
+    };
+    
+    let bytes = hex::decode(hex_str)
+        .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid hex string: {e}")))?;
+    
+    if bytes.len() != 32 {
+        return Err(PyErr::new::<PyValueError, _>(
+            format!("Image ID must be 32 bytes (64 hex chars), got {} bytes", bytes.len())
+        ));
+    }
+    
+    Digest::try_from(bytes.as_slice())
+        .map_err(|_| PyErr::new::<PyValueError, _>("Failed to create digest from bytes"))
+}
+
+/// Parse a 32-byte image ID from raw bytes
+pub(crate) fn digest_from_bytes(image_id: &[u8]) -> PyResult<Digest> {
+    if image_id.len() != 32 {
+        return Err(PyErr::new::<PyValueError, _>(
+            format!("Image ID must be 32 bytes, got {} bytes", image_id.len())
+        ));
+    }
+    
+    Digest::try_from(image_id)
+        .map_err(|_| PyErr::new::<PyValueError, _>("Failed to create digest from bytes"))
+}
+
 #[pymethods]
 impl Receipt {
     // ===== Journal properties =====
@@ -107,6 +864,74 @@ impl Receipt {
         Ok(self.inner.journal.bytes.len())
     }
     
+    /// Decode a journal written with env::commit(&value)
+    /// 
+    /// Uses RISC Zero's word-based serde format, the same as
+    /// pyr0.codec.commit_decode().
+    /// 
+    /// Args:
+    ///     fmt: A type name such as "u32", "u64" or "Vec<u8>" for a single
+    ///          committed value, or a list of type names, one per commit
+    ///     strict: If True (default), reject trailing journal bytes
+    /// 
+    /// Returns:
+    ///     The decoded value, or a list of values if fmt is a list
+    /// 
+    /// Raises:
+    ///     ValueError: If the journal is truncated, malformed, or has trailing bytes
+    /// 
+    /// Example:
+    ///     total = receipt.journal_decode("u32")
+    ///     count, digest = receipt.journal_decode(["u64", "[u8; 32]"])
+    #[pyo3(signature = (fmt, strict=true))]
+    pub fn journal_decode(&self, fmt: &Bound<'_, PyAny>, strict: bool) -> PyResult<PyObject> {
+        let py = fmt.py();
+        let journal = &self.inner.journal.bytes;
+        if let Ok(single) = fmt.extract::<String>() {
+            let mut values = crate::codec::decode_values(py, journal, &[single], strict)?;
+            return Ok(values.remove(0));
+        }
+        let fmt: Vec<String> = fmt.extract()?;
+        crate::codec::decode_values(py, journal, &fmt, strict)?.into_py_any(py)
+    }
+    
+    /// Decode the journal into a dict, using a guest manifest's journal layout
+    /// 
+    /// Replaces offset arithmetic like "the result is at byte 16" with the
+    /// field names the guest's manifest declares. **UNTRUSTED until verified**,
+    /// like every other journal accessor.
+    /// 
+    /// Args:
+    ///     manifest: GuestManifest declaring the journal fields
+    /// 
+    /// Returns:
+    ///     dict: Field name -> decoded value, in declaration order
+    /// 
+    /// Raises:
+    ///     ValueError: If the manifest names a different image, or the
+    ///                 journal doesn't match its layout
+    /// 
+    /// Example:
+    ///     manifest = pyr0.GuestManifest.load("guest/manifest.toml")
+    ///     doubled = receipt.decode_journal(manifest)["doubled"]
+    pub fn decode_journal<'py>(
+        &self,
+        py: Python<'py>,
+        manifest: &crate::manifest::GuestManifest,
+    ) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        if let Some(expected) = manifest.expected_image_id() {
+            let actual = self.claim_value()?.pre.digest();
+            if actual != expected {
+                return Err(PyErr::new::<PyValueError, _>(format!(
+                    "Manifest is for image {}, but the receipt claims image {}",
+                    hex::encode(expected.as_bytes()),
+                    hex::encode(actual.as_bytes())
+                )));
+            }
+        }
+        manifest.decode_journal(py, &self.inner.journal.bytes)
+    }
+    
     // Legacy getter for backward compatibility
     #[getter]
     pub fn journal(&self) -> PyResult<Vec<u8>> {
@@ -121,11 +946,11 @@ impl Receipt {
     /// This is the core abstraction for understanding proof composition.
     pub fn claim(&self) -> PyResult<Claim> {
         let claim_pruned = self.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to decode claim: {e}")))?;
+            .map_err(|e| errors::serialization_error(format!("Failed to decode claim: {e}")))?;
         
         let claim = match claim_pruned.as_value() {
             Ok(claim) => claim,
-            Err(_) => return Err(PyErr::new::<PyRuntimeError, _>("Claim is pruned")),
+            Err(_) => return Err(errors::serialization_error("Claim is pruned")),
         };
         
         Claim::from_risc0_claim(claim, self.inner.journal.bytes.clone())
@@ -137,21 +962,14 @@ impl Receipt {
     #[getter]
     pub fn exit(&self) -> PyResult<ExitStatus> {
         let claim_pruned = self.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to decode claim: {e}")))?;
+            .map_err(|e| errors::serialization_error(format!("Failed to decode claim: {e}")))?;
         
         let claim = match claim_pruned.as_value() {
             Ok(claim) => claim,
-            Err(_) => return Err(PyErr::new::<PyRuntimeError, _>("Claim is pruned")),
+            Err(_) => return Err(errors::serialization_error("Claim is pruned")),
         };
         
-        let (kind, user_code) = match claim.exit_code {
-            RiscZeroExitCode::Halted(code) => (ExitKind::Halted, Some(code)),
-            RiscZeroExitCode::Paused(code) => (ExitKind::Paused, Some(code)),
-            RiscZeroExitCode::SystemSplit => (ExitKind::SystemSplit, None),
-            RiscZeroExitCode::SessionLimit => (ExitKind::SessionLimit, None),
-        };
-        
-        Ok(ExitStatus { kind, user_code })
+        Ok(ExitStatus::from_risc0(claim.exit_code))
     }
     
     // Legacy getter for backward compatibility - returns raw u32
@@ -174,11 +992,11 @@ impl Receipt {
     #[getter]
     pub fn claimed_image_id_hex(&self) -> PyResult<String> {
         let claim_pruned = self.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to decode claim: {e}")))?;
+            .map_err(|e| errors::serialization_error(format!("Failed to decode claim: {e}")))?;
         
         let claim = match claim_pruned.as_value() {
             Ok(claim) => claim,
-            Err(_) => return Err(PyErr::new::<PyRuntimeError, _>("Claim is pruned")),
+            Err(_) => return Err(errors::serialization_error("Claim is pruned")),
         };
         
         let digest = match &claim.pre {
@@ -199,11 +1017,11 @@ impl Receipt {
     #[getter]
     pub fn claimed_image_id_bytes(&self) -> PyResult<Vec<u8>> {
         let claim_pruned = self.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to decode claim: {e}")))?;
+            .map_err(|e| errors::serialization_error(format!("Failed to decode claim: {e}")))?;
         
         let claim = match claim_pruned.as_value() {
             Ok(claim) => claim,
-            Err(_) => return Err(PyErr::new::<PyRuntimeError, _>("Claim is pruned")),
+            Err(_) => return Err(errors::serialization_error("Claim is pruned")),
         };
         
         let digest = match &claim.pre {
@@ -213,6 +1031,32 @@ impl Receipt {
         Ok(digest.as_bytes().to_vec())
     }
     
+    /// Digest of the pre-execution SystemState (**UNTRUSTED until verified**)
+    /// 
+    /// For a fresh execution this equals the claimed image ID.
+    #[getter]
+    pub fn pre_state_digest(&self) -> PyResult<Vec<u8>> {
+        Ok(self.claim_value()?.pre.digest().as_bytes().to_vec())
+    }
+    
+    /// Digest of the post-execution SystemState (**UNTRUSTED until verified**)
+    /// 
+    /// For PAUSED receipts this is the state a continuation resumes from,
+    /// which is what host-side continuation-linking logic needs to compare.
+    #[getter]
+    pub fn post_state_digest(&self) -> PyResult<Vec<u8>> {
+        Ok(self.claim_value()?.post.digest().as_bytes().to_vec())
+    }
+
+    /// Handle of the paused execution resume() continues, or None
+    ///
+    /// Only set on a PAUSED receipt from prove(..., pausable=True) or
+    /// resume(), until it is resumed, expires or is discarded. Not serialized.
+    #[getter]
+    pub fn pause_handle(&self) -> Option<u64> {
+        self.pause_handle
+    }
+
     // Legacy getter for backward compatibility (but marked as "claimed")
     #[getter]
     pub fn program_id(&self) -> PyResult<Vec<u8>> {
@@ -246,7 +1090,35 @@ impl Receipt {
             _ => ReceiptKind::Composite,  // Default for any future variants
         })
     }
-    
+
+    /// Digest of the verifier parameters the receipt was proven for (32 bytes)
+    ///
+    /// Identifies which verifier (recursion circuit, control IDs, proof
+    /// system version) can check the receipt, so a service receiving
+    /// receipts from many provers can route each to a compatible one.
+    /// Receipts don't record the risc0 version that produced them; this
+    /// digest is what changes when verification would. All zeros for FAKE.
+    /// Not authenticated until the receipt verifies.
+    #[getter]
+    pub fn verifier_parameters(&self) -> Vec<u8> {
+        self.inner.inner.verifier_parameters().as_bytes().to_vec()
+    }
+
+    /// Hash function of the STARK seal ("poseidon2", "sha-256", ...)
+    ///
+    /// None for groth16 and fake receipts, and for composite receipts
+    /// without segments.
+    #[getter]
+    pub fn hashfn(&self) -> Option<String> {
+        use risc0_zkvm::InnerReceipt;
+
+        match &self.inner.inner {
+            InnerReceipt::Composite(composite) => composite.segments.first().map(|segment| segment.hashfn.clone()),
+            InnerReceipt::Succinct(succinct) => Some(succinct.hashfn.clone()),
+            _ => None,
+        }
+    }
+
     /// Check if this receipt is unconditional (has no unresolved assumptions)
     /// 
     /// Returns True for succinct or groth16 receipts, False for composite receipts.
@@ -290,6 +1162,69 @@ impl Receipt {
         })
     }
     
+    /// Segment receipts making up a COMPOSITE receipt, in execution order
+    /// 
+    /// Each carries its own claim, so a continuation can be audited segment by
+    /// segment (or the receipts re-joined elsewhere) without dropping to Rust.
+    /// 
+    /// Returns:
+    ///     List[SegmentReceipt]: One receipt per segment
+    /// 
+    /// Raises:
+    ///     ValueError: If the receipt isn't COMPOSITE. Succinct and Groth16
+    ///                 receipts have already joined their segments into one proof.
+    pub fn segments(&self) -> PyResult<Vec<SegmentReceipt>> {
+        match &self.inner.inner {
+            risc0_zkvm::InnerReceipt::Composite(composite) => {
+                Ok(composite.segments.iter().cloned().map(SegmentReceipt::from_risc0).collect())
+            }
+            _ => Err(PyErr::new::<PyValueError, _>(format!(
+                "Only COMPOSITE receipts carry segment receipts, this one is {}",
+                self.kind()?.as_str()
+            ))),
+        }
+    }
+    
+    /// Discharge this receipt's assumptions into an unconditional SUCCINCT receipt
+    /// 
+    /// Lifts and joins the segments of a COMPOSITE receipt, then resolves
+    /// each env::verify() assumption in turn with the receipt proving it.
+    /// Also accepts the conditional SUCCINCT receipts built from
+    /// Composer.prove_conditional() (via SuccinctReceipt.into_receipt()).
+    /// Assumption receipts may come in any order; those a composite receipt
+    /// already carries needn't be given again.
+    /// 
+    /// Args:
+    ///     assumptions: Receipts for the assumptions, SUCCINCT or COMPOSITE
+    ///                  (compressed first). Extra receipts are ignored.
+    ///     keccak: KeccakReceipts for the accelerator assumptions of a
+    ///             receipt proved with prove_with_opts(coprocessor=...)
+    /// 
+    /// Returns:
+    ///     Receipt: A SUCCINCT receipt with no remaining assumptions
+    /// 
+    /// Raises:
+    ///     ProvingError: If an assumption has no matching receipt, this
+    ///                   receipt is GROTH16 or FAKE, or recursion fails
+    /// 
+    /// Example:
+    ///     final = conditional.resolve([inner_receipt])
+    ///     final.verify(outer_image)
+    #[pyo3(signature = (assumptions=Vec::new(), keccak=Vec::new()))]
+    pub fn resolve(
+        &self,
+        py: Python<'_>,
+        assumptions: Vec<Receipt>,
+        keccak: Vec<crate::segment::KeccakReceipt>,
+    ) -> PyResult<Receipt> {
+        let receipt = &self.inner;
+        let assumptions = assumptions.into_iter().map(|r| r.inner).collect::<Vec<_>>();
+        let keccak = keccak.into_iter().map(|r| r.inner).collect::<Vec<_>>();
+        crate::reservation::allow_threads(py, || crate::segment::resolve_receipt(receipt, &assumptions, &keccak))
+            .map(Receipt::from_risc0)
+            .map_err(|e| errors::proving_error(format!("Failed to resolve receipt: {e}")))
+    }
+    
     // ===== Verification methods =====
     
     /// Verify the receipt with a trusted image ID provided as hex string
@@ -299,32 +1234,9 @@ impl Receipt {
     /// 
     /// Raises:
     ///     ValueError: If hex string is invalid format
-    ///     RuntimeError: If verification fails
-    pub fn verify_hex(&self, image_id_hex: &str) -> PyResult<()> {
-        // Handle optional 0x prefix
-        let hex_str = if image_id_hex.starts_with("0x") || image_id_hex.starts_with("0X") {
-            &image_id_hex[2..]
-        } else {
-            image_id_hex
-        };
-        
-        // Decode hex to bytes
-        let bytes = hex::decode(hex_str)
-            .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid hex string: {e}")))?;
-        
-        if bytes.len() != 32 {
-            return Err(PyErr::new::<PyValueError, _>(
-                format!("Image ID must be 32 bytes (64 hex chars), got {} bytes", bytes.len())
-            ));
-        }
-        
-        // Convert to Digest and verify
-        let image_id = Digest::try_from(bytes.as_slice())
-            .map_err(|_| PyErr::new::<PyValueError, _>("Failed to create digest from bytes"))?;
-        
-        // Full verification: checks seal, image ID match, and success exit
-        self.inner.verify(image_id)
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Verification failed: {e}")))
+    ///     VerificationError: If verification fails (.code says which check failed)
+    pub fn verify_hex(&self, py: Python<'_>, image_id_hex: &str) -> PyResult<()> {
+        self.verify_digest(py, digest_from_hex(image_id_hex)?)
     }
     
     /// Verify the receipt with a trusted image ID provided as bytes
@@ -334,42 +1246,47 @@ impl Receipt {
     /// 
     /// Raises:
     ///     ValueError: If bytes are not exactly 32 bytes
-    ///     RuntimeError: If verification fails
-    pub fn verify_bytes(&self, image_id: Vec<u8>) -> PyResult<()> {
-        if image_id.len() != 32 {
-            return Err(PyErr::new::<PyValueError, _>(
-                format!("Image ID must be 32 bytes, got {} bytes", image_id.len())
-            ));
-        }
-        
-        let digest = Digest::try_from(image_id.as_slice())
-            .map_err(|_| PyErr::new::<PyValueError, _>("Failed to create digest from bytes"))?;
-        
-        self.inner.verify(digest)
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Verification failed: {e}")))
+    ///     VerificationError: If verification fails (.code says which check failed)
+    pub fn verify_bytes(&self, py: Python<'_>, image_id: Vec<u8>) -> PyResult<()> {
+        self.verify_digest(py, digest_from_bytes(&image_id)?)
     }
     
-    /// Check if the receipt has a valid claim structure.
+    /// Verify the seal and journal without requiring a particular program or exit
+    /// 
+    /// Checks the cryptographic seal against the receipt's own claim and the
+    /// journal against the claim's output, but not the image ID or exit
+    /// code. Use it to validate receipts of failed executions (non-zero
+    /// exit), then inspect claim() yourself; anything that must have run a
+    /// specific program needs verify().
     /// 
-    /// Note: This only validates that the claim is well-formed and not pruned.
-    /// It does NOT verify the cryptographic seal. Full seal verification 
-    /// requires using verify() which also checks execution success.
+    /// Args:
+    ///     context: Optional VerifierContext for receipts made with
+    ///              non-default verifier parameters
     /// 
     /// Raises:
-    ///     RuntimeError: If claim is invalid or pruned
-    pub fn verify_integrity(&self) -> PyResult<()> {
+    ///     VerificationError: If the claim is invalid or pruned (code CLAIM),
+    ///                        the journal doesn't match (JOURNAL) or the seal
+    ///                        is invalid (SEAL)
+    #[pyo3(signature = (context=None))]
+    pub fn verify_integrity(&self, py: Python<'_>, context: Option<VerifierContext>) -> PyResult<()> {
         // Extract and validate the claim structure
         let claim_pruned = self.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Invalid claim: {e}")))?;
-        
-        // Ensure claim is not pruned
-        let _claim = match claim_pruned.as_value() {
-            Ok(claim) => claim,
-            Err(_) => return Err(PyErr::new::<PyRuntimeError, _>("Claim is pruned - cannot validate")),
-        };
+            .map_err(|e| errors::verification_error(py, "CLAIM", format!("Invalid claim: {e}")))?;
+        if claim_pruned.as_value().is_err() {
+            return Err(errors::verification_error(py, "CLAIM", "Claim is pruned - cannot validate"));
+        }
         
-        // Claim is valid and not pruned
-        Ok(())
+        let inner = &self.inner;
+        let context = context.unwrap_or_default();
+        py.allow_threads(|| inner.verify_integrity_with_context(&context.build()))
+            .map_err(|e| {
+                let code = match e {
+                    VerificationError::JournalDigestMismatch => "JOURNAL",
+                    VerificationError::UnresolvedAssumption { .. } => "ASSUMPTIONS",
+                    _ => "SEAL",
+                };
+                errors::verification_error(py, code, format!("Integrity check failed ({code}): {e}"))
+            })
     }
     
     /// Unified verify method - accepts bytes, hex string, or Image
@@ -379,60 +1296,633 @@ impl Receipt {
     ///               - 32-byte bytes
     ///               - 64-char hex string (with or without 0x prefix)
     ///               - Image object (uses its ID)
+    ///     deadline_ms: Optional time budget in milliseconds. If verification
+    ///                  hasn't finished in time, VerificationTimeout is raised.
+    ///                  Use this when verifying untrusted receipts, e.g. in a
+    ///                  public-facing endpoint.
+    ///     context: Optional VerifierContext with the control roots the
+    ///              receipt was proven under, for receipts from another
+    ///              risc0 release or a custom recursion circuit
     /// 
     /// Raises:
     ///     ValueError: If format is invalid
-    ///     RuntimeError: If verification fails
+    ///     VerificationError: If verification fails (.code says which check failed)
+    ///     VerificationTimeout: If deadline_ms elapses before verification completes
+    /// 
+    /// Note:
+    ///     A timed-out verification is not cancelled. It can't be interrupted
+    ///     part-way through, so it finishes in the background and its result
+    ///     is discarded. Deadline verifications share one thread per CPU, which
+    ///     bounds the CPU abandoned work can take; while those threads are
+    ///     busy, new ones wait their turn (and time out if the wait uses up
+    ///     their budget).
     /// 
     /// Example:
     ///     receipt.verify(image.id)                    # bytes
     ///     receipt.verify("0xabc123...")               # hex string
     ///     receipt.verify(image)                        # Image object
-    pub fn verify(&self, image_id: &Bound<'_, PyAny>) -> PyResult<()> {
-        use crate::image::Image;
-        
-        // Try to extract as Image first
-        if let Ok(image) = image_id.extract::<PyRef<Image>>() {
-            return self.verify_bytes(image.id()?);
+    ///     receipt.verify(image, deadline_ms=2000)      # with a 2s budget
+    #[pyo3(signature = (image_id, deadline_ms=None, context=None))]
+    pub fn verify(
+        &self,
+        image_id: &Bound<'_, PyAny>,
+        deadline_ms: Option<u64>,
+        context: Option<VerifierContext>,
+    ) -> PyResult<()> {
+        let digest = image_id_digest(image_id)?;
+        match (deadline_ms, context) {
+            (None, None) => self.verify_digest(image_id.py(), digest),
+            (None, Some(context)) => self.verify_digest_in(image_id.py(), digest, &context),
+            (Some(ms), context) => self.verify_digest_within(image_id.py(), digest, ms, context),
         }
-        
-        // Try as string (hex)
-        if let Ok(hex_str) = image_id.extract::<String>() {
-            return self.verify_hex(&hex_str);
+    }
+    
+    /// Like verify(), but accepts a guest that halted with any of the given exit codes
+    /// 
+    /// For guests that report an outcome through their exit code (e.g. exit 1
+    /// for "signature invalid"): the receipt is still fully verified - seal,
+    /// image ID, journal and resolved assumptions - only the exit code check
+    /// is relaxed. Paused and limit exits are always rejected.
+    /// 
+    /// Args:
+    ///     image_id: Trusted image ID (bytes, hex string, or Image)
+    ///     allowed: Acceptable user exit codes; include 0 to accept success
+    /// 
+    /// Returns:
+    ///     int: The exit code the guest halted with
+    /// 
+    /// Raises:
+    ///     ValueError: If image_id is invalid or allowed is empty
+    ///     VerificationError: If verification fails (.code says which check
+    ///                        failed; EXIT_CODE if the code isn't allowed)
+    /// 
+    /// Example:
+    ///     code = receipt.verify_allowing_exit_codes(image, allowed=[0, 1])
+    ///     signature_valid = code == 0
+    pub fn verify_allowing_exit_codes(&self, image_id: &Bound<'_, PyAny>, allowed: Vec<u32>) -> PyResult<u32> {
+        let digest = image_id_digest(image_id)?;
+        if allowed.is_empty() {
+            return Err(PyErr::new::<PyValueError, _>("allowed must list at least one exit code"));
         }
-        
-        // Try as bytes
-        if let Ok(bytes) = image_id.extract::<Vec<u8>>() {
-            return self.verify_bytes(bytes);
+        self.verify_digest_allowing(image_id.py(), digest, &allowed)
+    }
+    
+    /// Verify the receipt against everything a VerificationPolicy requires
+    /// 
+    /// The cheap checks (receipt kind, journal size, trusted image ID) run
+    /// first, then the full verification of verify() with the policy's
+    /// exit codes accepted.
+    /// 
+    /// Args:
+    ///     policy: The VerificationPolicy to enforce
+    /// 
+    /// Returns:
+    ///     bytes: The trusted image ID the receipt proved (32 bytes)
+    /// 
+    /// Raises:
+    ///     VerificationError: If any check fails (.code is POLICY for a
+    ///                        disallowed kind or oversized journal, IMAGE_ID
+    ///                        for an untrusted program, otherwise as verify())
+    pub fn verify_policy(&self, py: Python<'_>, policy: &VerificationPolicy) -> PyResult<Vec<u8>> {
+        let kind = self.kind()?;
+        if let Some(kinds) = &policy.kinds {
+            if !kinds.contains(&kind) {
+                let allowed: Vec<_> = kinds.iter().map(ReceiptKind::as_str).collect();
+                return Err(errors::verification_error(py, "POLICY", format!(
+                    "Verification failed (POLICY): {} receipts are not accepted (allowed: {})",
+                    kind.as_str(), allowed.join(", ")
+                )));
+            }
+        }
+        let journal_size = self.inner.journal.bytes.len();
+        if let Some(max) = policy.max_journal_size {
+            if journal_size > max {
+                return Err(errors::verification_error(py, "POLICY", format!(
+                    "Verification failed (POLICY): journal is {journal_size} bytes, exceeding max_journal_size={max}"
+                )));
+            }
+        }
+        // The claimed ID only picks which trusted ID to verify against
+        let claimed = self.claim_value()
+            .map_err(|e| errors::verification_error(py, "CLAIM", e.to_string()))?
+            .pre
+            .digest();
+        if !policy.image_ids.contains(&claimed) {
+            return Err(errors::verification_error(py, "IMAGE_ID", format!(
+                "Verification failed (IMAGE_ID): receipt is for image {}, which the policy doesn't trust",
+                hex::encode(claimed.as_bytes())
+            )));
         }
         
-        Err(PyErr::new::<PyValueError, _>(
-            "image_id must be bytes (32 bytes), hex string (64 chars), or Image object"
-        ))
+        if policy.exit_codes == [0] {
+            self.verify_digest(py, claimed)?;
+        } else {
+            self.verify_digest_allowing(py, claimed, &policy.exit_codes)?;
+        }
+        Ok(claimed.as_bytes().to_vec())
     }
     
     /// Deprecated: Use verify() instead
     /// This method is kept for backward compatibility but is identical to verify()
     pub fn verify_with_image_id(&self, image_id_bytes: &Bound<'_, PyAny>) -> PyResult<()> {
-        self.verify(image_id_bytes)
+        self.verify(image_id_bytes, None, None)
     }
-    
+
+    // ===== Test fixtures =====
+
+    /// Construct an INSECURE fake receipt with the given claim
+    ///
+    /// Builds a ReceiptKind.FAKE receipt claiming that image_id halted with
+    /// exit_code and committed journal, without running or proving anything.
+    /// Use it to unit-test verifier-side code - VerificationPolicy checks,
+    /// journal parsing, error handling - with exactly the receipt you need.
+    ///
+    /// ⚠️ Like prove_fake(), the result proves nothing: verify() rejects it,
+    /// Composer.assume() refuses it, and every call emits a warning.
+    ///
+    /// Args:
+    ///     image_id: Image ID to claim (bytes, hex string, or Image)
+    ///     journal: Journal bytes to claim
+    ///     exit_code: User exit code the guest claims to have halted with
+    ///
+    /// Returns:
+    ///     Receipt: A receipt with kind ReceiptKind.FAKE
+    ///
+    /// Raises:
+    ///     ValueError: If image_id is invalid
+    ///
+    /// Example:
+    ///     receipt = Receipt.fake(image.id, struct.pack("<I", 42))
+    ///     assert parse_result(receipt.journal_bytes) == 42
+    #[staticmethod]
+    #[pyo3(signature = (image_id, journal, exit_code=0))]
+    pub fn fake(image_id: &Bound<'_, PyAny>, journal: Vec<u8>, exit_code: u32) -> PyResult<Self> {
+        let mut claim = risc0_zkvm::ReceiptClaim::ok(image_id_digest(image_id)?, journal.clone());
+        claim.exit_code = RiscZeroExitCode::Halted(exit_code);
+        let fake = risc0_zkvm::FakeReceipt::new(claim);
+
+        image_id.py().import("warnings")?.call_method1(
+            "warn",
+            ("pyr0.Receipt.fake() returned an INSECURE fake receipt - for testing only",),
+        )?;
+
+        Ok(Self::from_risc0(RiscZeroReceipt::new(risc0_zkvm::InnerReceipt::Fake(fake), journal)))
+    }
+
     // ===== Serialization =====
     
     /// Serialize the receipt to bytes for storage/transport
-    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
-        bincode::serialize(&self.inner)
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(format!("Failed to serialize receipt: {e}")))
+    /// 
+    /// Args:
+    ///     format: "bincode" (risc0's own encoding, the default), "borsh" or
+    ///             "cbor", for services and contracts that consume those
+    /// 
+    /// Raises:
+    ///     ValueError: If format is unknown
+    #[pyo3(signature = (format="bincode"))]
+    pub fn to_bytes(&self, format: &str) -> PyResult<Vec<u8>> {
+        ReceiptFormat::parse(format)?.encode(&self.inner)
     }
     
     /// Deserialize a receipt from bytes
+    /// 
+    /// Args:
+    ///     data: Bytes produced by to_bytes()
+    ///     policy: DeserializationPolicy limiting size, assumption count and
+    ///             nesting depth (default: DeserializationPolicy())
+    ///     format: "bincode", "borsh" or "cbor". If None, CBOR is recognized
+    ///             by its leading map header; anything else is tried as
+    ///             bincode, then (under an unlimited policy) borsh. Borsh
+    ///             needs policy=DeserializationPolicy.unlimited(), since its
+    ///             decoder can't bound allocations; only use it for trusted data.
+    /// 
+    /// Raises:
+    ///     SerializationError: If the data is malformed or exceeds the policy's limits
+    ///     ValueError: If format is unknown
     #[staticmethod]
-    pub fn from_bytes(data: Vec<u8>) -> PyResult<Self> {
-        let inner: RiscZeroReceipt = bincode::deserialize(&data)
-            .map_err(|e| PyErr::new::<PyValueError, _>(format!("Failed to deserialize receipt: {e}")))?;
-        Ok(Self { inner })
+    #[pyo3(signature = (data, policy=None, format=None))]
+    pub fn from_bytes(data: Vec<u8>, policy: Option<DeserializationPolicy>, format: Option<&str>) -> PyResult<Self> {
+        let format = format.map(ReceiptFormat::parse).transpose()?;
+        let inner = policy.unwrap_or_default().decode_as(&data, format)?;
+        Ok(Self::from_risc0(inner))
     }
-    
+
+    /// Wrap the receipt in a COSE_Sign1 envelope (RFC 9052)
+    /// 
+    /// The payload is the receipt's CBOR encoding (to_bytes("cbor")) and the
+    /// protected header names its content type,
+    /// "application/vnd.pyr0.receipt+cbor". With a signing key the envelope
+    /// is signed with Ed25519 (COSE algorithm EdDSA), so a relay can't swap
+    /// the receipt without the signature failing. Without one the signature
+    /// is empty: the envelope is only a transport format, and the receipt
+    /// itself must still be verified either way.
+    /// 
+    /// Args:
+    ///     signing_key: Optional 32-byte Ed25519 private key (seed)
+    /// 
+    /// Returns:
+    ///     bytes: The tagged COSE_Sign1 message
+    /// 
+    /// Raises:
+    ///     ValueError: If signing_key isn't 32 bytes
+    #[pyo3(signature = (signing_key=None))]
+    pub fn to_cose_sign1(&self, signing_key: Option<Vec<u8>>) -> PyResult<Vec<u8>> {
+        use ciborium::Value;
+        use ed25519_dalek::Signer;
+
+        let signing_key = signing_key
+            .map(|key| ed25519_key(&key, "signing_key").map(|seed| ed25519_dalek::SigningKey::from_bytes(&seed)))
+            .transpose()?;
+        let mut header = vec![(Value::from(COSE_HEADER_CONTENT_TYPE), Value::from(COSE_CONTENT_TYPE))];
+        if signing_key.is_some() {
+            header.insert(0, (Value::from(COSE_HEADER_ALG), Value::from(COSE_ALG_EDDSA)));
+        }
+        let protected = cbor_bytes(&Value::Map(header))?;
+        let payload = self.to_bytes("cbor")?;
+        let signature = match &signing_key {
+            Some(key) => key.sign(&cose_sig_structure(&protected, &payload)?).to_bytes().to_vec(),
+            None => Vec::new(),
+        };
+
+        cbor_bytes(&Value::Tag(COSE_SIGN1_TAG, Box::new(Value::Array(vec![
+            Value::Bytes(protected),
+            Value::Map(Vec::new()),
+            Value::Bytes(payload),
+            Value::Bytes(signature),
+        ]))))
+    }
+
+    /// Unwrap a receipt from a to_cose_sign1() envelope
+    /// 
+    /// Args:
+    ///     data: A COSE_Sign1 message (tagged or untagged)
+    ///     verify_key: 32-byte Ed25519 public key. If given, the envelope
+    ///                 must be signed by it; if None, any signature is ignored.
+    ///     policy: DeserializationPolicy for the embedded receipt
+    /// 
+    /// Raises:
+    ///     SerializationError: If the envelope or the receipt is malformed
+    ///     VerificationError: If verify_key is given and the envelope isn't
+    ///                        signed with it (code SIGNATURE)
+    ///     ValueError: If verify_key isn't 32 bytes
+    #[staticmethod]
+    #[pyo3(signature = (data, verify_key=None, policy=None))]
+    pub fn from_cose_sign1(
+        py: Python<'_>,
+        data: Vec<u8>,
+        verify_key: Option<Vec<u8>>,
+        policy: Option<DeserializationPolicy>,
+    ) -> PyResult<Self> {
+        use ciborium::Value;
+
+        let malformed = |what: &str| errors::serialization_error(format!("Malformed COSE_Sign1 envelope: {what}"));
+        // Untrusted until the signature is checked, so bound allocations first
+        let value: Value = check_cbor_lengths(&data)
+            .and_then(|_| ciborium::from_reader(data.as_slice()).map_err(|e| e.to_string()))
+            .map_err(|e| errors::serialization_error(format!("Invalid COSE_Sign1 CBOR: {e}")))?;
+        let value = match value {
+            Value::Tag(COSE_SIGN1_TAG, inner) => *inner,
+            Value::Tag(tag, _) => return Err(malformed(&format!("unexpected CBOR tag {tag}"))),
+            untagged => untagged,
+        };
+        let Value::Array(fields) = value else { return Err(malformed("not an array")) };
+        let [Value::Bytes(protected), Value::Map(_), Value::Bytes(payload), Value::Bytes(signature)] = fields.as_slice() else {
+            return Err(malformed("expected [protected, unprotected, payload, signature]"));
+        };
+
+        let header: Value = check_cbor_lengths(protected)
+            .and_then(|_| ciborium::from_reader(protected.as_slice()).map_err(|e| e.to_string()))
+            .map_err(|e| errors::serialization_error(format!("Invalid COSE protected header: {e}")))?;
+        let Value::Map(header) = header else { return Err(malformed("protected header is not a map")) };
+        let label = |label: i64| header.iter().find(|(k, _)| *k == Value::from(label)).map(|(_, v)| v);
+        if label(COSE_HEADER_CONTENT_TYPE) != Some(&Value::from(COSE_CONTENT_TYPE)) {
+            return Err(malformed(&format!("content type is not {COSE_CONTENT_TYPE}")));
+        }
+
+        if let Some(key) = verify_key {
+            let key = ed25519_dalek::VerifyingKey::from_bytes(&ed25519_key(&key, "verify_key")?)
+                .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid verify_key: {e}")))?;
+            if label(COSE_HEADER_ALG) != Some(&Value::from(COSE_ALG_EDDSA)) {
+                return Err(errors::verification_error(py, "SIGNATURE", "COSE_Sign1 envelope is not signed with EdDSA"));
+            }
+            let signature = ed25519_dalek::Signature::from_slice(signature)
+                .map_err(|e| errors::verification_error(py, "SIGNATURE", format!("Invalid COSE_Sign1 signature: {e}")))?;
+            key.verify_strict(&cose_sig_structure(protected, payload)?, &signature)
+                .map_err(|_| errors::verification_error(py, "SIGNATURE", "COSE_Sign1 signature doesn't match verify_key"))?;
+        }
+
+        let inner = policy.unwrap_or_default().decode_as(payload, Some(ReceiptFormat::Cbor))?;
+        Ok(Self::from_risc0(inner))
+    }
+
+    /// Write the receipt to a file that Receipt.load() can read back
+    /// 
+    /// The file starts with a magic header recording the container format
+    /// and the risc0-zkvm version that serialized the receipt, so loading it
+    /// with an incompatible pyr0 fails with a clear error instead of decoding
+    /// garbage.
+    /// 
+    /// Args:
+    ///     path: Destination file (overwritten if it exists)
+    /// 
+    /// Raises:
+    ///     SerializationError: If the receipt can't be serialized or written
+    pub fn save(&self, path: std::path::PathBuf) -> PyResult<()> {
+        let version = risc0_zkvm::VERSION.as_bytes();
+        let mut data = Vec::with_capacity(RECEIPT_FILE_MAGIC.len() + 4 + version.len());
+        data.extend_from_slice(RECEIPT_FILE_MAGIC);
+        data.extend_from_slice(&RECEIPT_FILE_VERSION.to_le_bytes());
+        data.extend_from_slice(&(version.len() as u16).to_le_bytes());
+        data.extend_from_slice(version);
+        data.extend_from_slice(&self.to_bytes("bincode")?);
+        std::fs::write(&path, data)
+            .map_err(|e| errors::serialization_error(format!("Failed to write {}: {e}", path.display())))
+    }
+
+    /// Read a receipt written by save()
+    /// 
+    /// Args:
+    ///     path: File written by Receipt.save()
+    ///     policy: DeserializationPolicy applied to the embedded receipt
+    ///             (default: DeserializationPolicy())
+    /// 
+    /// Raises:
+    ///     SerializationError: If the file isn't a saved receipt, uses an
+    ///                         unknown container version, was written with an
+    ///                         incompatible risc0-zkvm version, or is malformed
+    #[staticmethod]
+    #[pyo3(signature = (path, policy=None))]
+    pub fn load(path: std::path::PathBuf, policy: Option<DeserializationPolicy>) -> PyResult<Self> {
+        let data = std::fs::read(&path)
+            .map_err(|e| errors::serialization_error(format!("Failed to read {}: {e}", path.display())))?;
+        let truncated = || errors::serialization_error(format!("{} is truncated", path.display()));
+
+        let Some(rest) = data.strip_prefix(RECEIPT_FILE_MAGIC) else {
+            return Err(errors::serialization_error(format!(
+                "{} is not a PyR0 receipt file (use Receipt.from_bytes() for raw receipts)",
+                path.display()
+            )));
+        };
+        let header = rest.get(..4).ok_or_else(truncated)?;
+        let format = u16::from_le_bytes([header[0], header[1]]);
+        if format != RECEIPT_FILE_VERSION {
+            return Err(errors::serialization_error(format!(
+                "{} uses receipt file format {format}, but this pyr0 reads format {RECEIPT_FILE_VERSION}",
+                path.display()
+            )));
+        }
+        let version_len = u16::from_le_bytes([header[2], header[3]]) as usize;
+        let version = rest.get(4..4 + version_len).ok_or_else(truncated)?;
+        let payload = &rest[4 + version_len..];
+        let version = String::from_utf8_lossy(version);
+        if risc0_release_line(&version) != risc0_release_line(risc0_zkvm::VERSION) {
+            return Err(errors::serialization_error(format!(
+                "{} was written with risc0-zkvm {version}, which is incompatible with risc0-zkvm {}",
+                path.display(),
+                risc0_zkvm::VERSION
+            )));
+        }
+
+        let inner = policy.unwrap_or_default().decode_as(payload, Some(ReceiptFormat::Bincode))?;
+        Ok(Self::from_risc0(inner))
+    }
+
+    // ===== JSON =====
+
+    /// Stable JSON representation for audit logs and non-Python verifiers
+    /// 
+    /// Byte strings are lowercase hex and keys are sorted. "bytes" carries
+    /// the full receipt (Receipt.to_bytes()), which is what makes the JSON
+    /// round-trippable; "seal" is the raw seal of a SUCCINCT or GROTH16
+    /// receipt (null for COMPOSITE and FAKE, whose seals live per segment or
+    /// don't exist). See pyr0.schema("Receipt") for the full layout.
+    /// 
+    /// Args:
+    ///     pretty: Indent the output for humans
+    /// 
+    /// Raises:
+    ///     SerializationError: If the receipt's claim is pruned
+    #[pyo3(signature = (pretty=false))]
+    pub fn to_json(&self, pretty: bool) -> PyResult<String> {
+        use risc0_zkvm::InnerReceipt;
+
+        let seal = match &self.inner.inner {
+            InnerReceipt::Succinct(succinct) => Some(hex::encode(
+                succinct.seal.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<u8>>(),
+            )),
+            InnerReceipt::Groth16(groth16) => Some(hex::encode(&groth16.seal)),
+            _ => None,
+        };
+        let value = serde_json::json!({
+            "bytes": hex::encode(self.to_bytes("bincode")?),
+            "kind": self.kind()?.as_str(),
+            "claimed_image_id": self.claimed_image_id_hex()?,
+            "claim_digest": hex::encode(self.claim_digest()?.as_bytes()),
+            "claim": self.claim()?.to_json_value()?,
+            "exit": self.exit()?.to_json_value(),
+            "seal": seal,
+            "seal_size": self.inner.seal_size(),
+            "assumption_count": self.assumption_count()?,
+        });
+        crate::claim::json_to_string(&value, pretty)
+    }
+
+    /// Rebuild a receipt from to_json() output
+    /// 
+    /// The receipt is decoded from the "bytes" field. Summary fields that are
+    /// present (kind, claimed_image_id, claim_digest) must agree with it, so
+    /// a hand-edited document is rejected rather than silently trusted.
+    /// As with from_bytes(), the result still has to be verified.
+    /// 
+    /// Args:
+    ///     text: JSON produced by Receipt.to_json()
+    ///     policy: DeserializationPolicy for the embedded receipt
+    /// 
+    /// Raises:
+    ///     SerializationError: If the JSON is malformed, has no "bytes" field,
+    ///                         or its summary fields don't match the receipt
+    #[staticmethod]
+    #[pyo3(signature = (text, policy=None))]
+    pub fn from_json(text: &str, policy: Option<DeserializationPolicy>) -> PyResult<Self> {
+        let value: serde_json::Value = serde_json::from_str(text)
+            .map_err(|e| errors::serialization_error(format!("Invalid receipt JSON: {e}")))?;
+        let Some(bytes) = value["bytes"].as_str() else {
+            return Err(errors::serialization_error("Receipt JSON has no \"bytes\" field"));
+        };
+        let bytes = hex::decode(bytes)
+            .map_err(|e| errors::serialization_error(format!("Invalid receipt hex: {e}")))?;
+        let receipt = Self::from_bytes(bytes, policy, Some("bincode"))?;
+
+        let decoded = [
+            ("kind", receipt.kind()?.as_str().to_string()),
+            ("claimed_image_id", receipt.claimed_image_id_hex()?),
+            ("claim_digest", hex::encode(receipt.claim_digest()?.as_bytes())),
+        ];
+        for (field, actual) in decoded {
+            match &value[field] {
+                serde_json::Value::Null => {}
+                stated if stated.as_str() == Some(actual.as_str()) => {}
+                stated => {
+                    return Err(errors::serialization_error(format!(
+                        "Receipt JSON {field} is {stated}, but the embedded receipt has \"{actual}\""
+                    )))
+                }
+            }
+        }
+        Ok(receipt)
+    }
+
+    // ===== Verifiable Credentials =====
+
+    /// Wrap this receipt in a W3C Verifiable Credential envelope
+    ///
+    /// The credentialSubject carries the claim digest, image ID, journal digest
+    /// and exit status. The proof section embeds the serialized receipt as hex,
+    /// or references it by URI when receipt_uri is given.
+    ///
+    /// Args:
+    ///     issuer_did: DID of the issuing party (e.g. "did:web:prover.example")
+    ///     subject: Identifier of the credential subject
+    ///     receipt_uri: Optional URI to reference instead of embedding the receipt
+    ///
+    /// Returns:
+    ///     str: The credential as a JSON document
+    ///
+    /// Note: The issuer is NOT authenticated by the receipt. Consumers must use
+    /// Receipt.verify_verifiable_credential() with a trusted image ID.
+    #[pyo3(signature = (issuer_did, subject, receipt_uri=None))]
+    pub fn to_verifiable_credential(
+        &self,
+        issuer_did: &str,
+        subject: &str,
+        receipt_uri: Option<String>,
+    ) -> PyResult<String> {
+        use risc0_zkvm::sha::Sha256;
+
+        let exit = self.exit()?;
+        let journal_digest = risc0_zkvm::sha::Impl::hash_bytes(&self.inner.journal.bytes);
+
+        let mut proof = serde_json::json!({
+            "type": VC_PROOF_TYPE,
+            "proofPurpose": "assertionMethod",
+            "receiptKind": self.kind()?.as_str(),
+        });
+        match receipt_uri {
+            Some(uri) => proof["receiptUri"] = uri.into(),
+            None => {
+                proof["receiptEncoding"] = "bincode-hex".into();
+                proof["receipt"] = hex::encode(self.to_bytes("bincode")?).into();
+            }
+        }
+
+        let credential = serde_json::json!({
+            "@context": ["https://www.w3.org/2018/credentials/v1"],
+            "type": ["VerifiableCredential", "RiscZeroReceiptCredential"],
+            "issuer": issuer_did,
+            "issuanceDate": rfc3339_utc_now(),
+            "credentialSubject": {
+                "id": subject,
+                "imageId": self.claimed_image_id_hex()?,
+                "claimDigest": hex::encode(self.claim_digest()?.as_bytes()),
+                "journalDigest": hex::encode(journal_digest.as_bytes()),
+                "exitCode": exit.user_code,
+                "exitKind": exit.kind.as_str(),
+            },
+            "proof": proof,
+        });
+
+        serde_json::to_string_pretty(&credential)
+            .map_err(|e| errors::serialization_error(format!("Failed to encode credential: {e}")))
+    }
+
+    /// Verify a credential produced by to_verifiable_credential()
+    ///
+    /// Checks that the credential's claim digest, image ID, journal digest and
+    /// exit status match the receipt, then fully verifies the receipt against
+    /// the trusted image ID.
+    ///
+    /// Args:
+    ///     credential: The credential JSON string
+    ///     image_id: Trusted image ID (bytes, hex string, or Image)
+    ///     receipt: The receipt, required when the credential only has a
+    ///              receiptUri; if the credential embeds one, it must match
+    ///
+    /// Returns:
+    ///     Receipt: The verified receipt
+    ///
+    /// Raises:
+    ///     SerializationError: If the credential is malformed
+    ///     ValueError: If the credential has no embedded receipt and none was
+    ///                 passed, or the passed receipt differs from the embedded one
+    ///     VerificationError: If the credential does not match the receipt
+    ///                        (code CLAIM, IMAGE_ID, JOURNAL or EXIT_CODE) or
+    ///                        verification fails
+    #[staticmethod]
+    #[pyo3(signature = (credential, image_id, receipt=None))]
+    pub fn verify_verifiable_credential(
+        credential: &str,
+        image_id: &Bound<'_, PyAny>,
+        receipt: Option<PyRef<Receipt>>,
+    ) -> PyResult<Receipt> {
+        let vc: serde_json::Value = serde_json::from_str(credential)
+            .map_err(|e| errors::serialization_error(format!("Invalid credential JSON: {e}")))?;
+
+        let is_vc = vc["type"].as_array()
+            .map(|types| types.iter().any(|t| t == "VerifiableCredential"))
+            .unwrap_or(false);
+        if !is_vc || vc["proof"]["type"] != VC_PROOF_TYPE {
+            return Err(errors::serialization_error("Not a RISC Zero receipt Verifiable Credential"));
+        }
+
+        let receipt = match (vc["proof"]["receipt"].as_str(), receipt) {
+            (Some(embedded), passed) => {
+                let bytes = hex::decode(embedded)
+                    .map_err(|e| errors::serialization_error(format!("Invalid embedded receipt hex: {e}")))?;
+                let embedded = Self::from_bytes(bytes, None, Some("bincode"))?;
+                if let Some(passed) = passed {
+                    if passed.to_bytes("bincode")? != embedded.to_bytes("bincode")? {
+                        return Err(PyErr::new::<PyValueError, _>(
+                            "The receipt passed differs from the one embedded in the credential"
+                        ));
+                    }
+                }
+                embedded
+            }
+            (None, Some(receipt)) => receipt.clone(),
+            (None, None) => return Err(PyErr::new::<PyValueError, _>(
+                "Credential references its receipt by URI - pass the fetched receipt explicitly"
+            )),
+        };
+
+        use risc0_zkvm::sha::Sha256;
+        let py = image_id.py();
+        let subject = &vc["credentialSubject"];
+        let claim_digest = hex::encode(receipt.claim_digest()?.as_bytes());
+        if subject["claimDigest"].as_str() != Some(claim_digest.as_str()) {
+            return Err(errors::verification_error(py, "CLAIM", "Credential claimDigest does not match the receipt"));
+        }
+        if subject["imageId"].as_str() != Some(receipt.claimed_image_id_hex()?.as_str()) {
+            return Err(errors::verification_error(py, "IMAGE_ID", "Credential imageId does not match the receipt"));
+        }
+        let journal_digest = hex::encode(risc0_zkvm::sha::Impl::hash_bytes(&receipt.inner.journal.bytes).as_bytes());
+        if subject["journalDigest"].as_str() != Some(journal_digest.as_str()) {
+            return Err(errors::verification_error(py, "JOURNAL", "Credential journalDigest does not match the receipt"));
+        }
+        let exit = receipt.exit()?;
+        if subject["exitCode"] != serde_json::json!(exit.user_code)
+            || subject["exitKind"].as_str() != Some(exit.kind.as_str())
+        {
+            return Err(errors::verification_error(
+                py,
+                "EXIT_CODE",
+                "Credential exitCode/exitKind does not match the receipt",
+            ));
+        }
+
+        receipt.verify(image_id, None, None)?;
+        Ok(receipt)
+    }
+
     // ===== String representation =====
     
     pub fn __repr__(&self) -> String {
@@ -483,11 +1973,9 @@ impl Receipt {
         
         // Get the claimed image ID from the receipt
         let claimed_id = self.inner.claim()
-            .map_err(|e| PyErr::new::<PyRuntimeError, _>(
-                format!("Failed to get claim: {}", e)
-            ))?
+            .map_err(|e| errors::serialization_error(format!("Failed to get claim: {}", e)))?
             .as_value()
-            .map_err(|_| PyErr::new::<PyRuntimeError, _>("Claim is pruned"))?
+            .map_err(|_| errors::serialization_error("Claim is pruned"))?
             .pre
             .digest();
         
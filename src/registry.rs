@@ -0,0 +1,250 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use risc0_zkvm::sha::Digest;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::errors;
+use crate::receipt::{self, Receipt};
+
+/// Bumped whenever the registry JSON layout changes
+const FORMAT_VERSION: u32 = 1;
+
+/// The signed part of a registry file: serialized compactly, with names in
+/// sorted order, so the same entries always sign the same bytes
+#[derive(Serialize, Deserialize)]
+struct RegistryBody {
+    version: u32,
+    images: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RegistryFile {
+    #[serde(flatten)]
+    body: RegistryBody,
+    /// Hex Ed25519 signature over the compact JSON of body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+}
+
+/// Named, trusted image IDs for verifiers
+///
+/// Keeps the allowlist of programs a verifier accepts in one place instead
+/// of hex strings scattered through the code. Look an ID up by name and
+/// pass it to Receipt.verify(), or let verify() do both:
+///
+///     registry = pyr0.ImageRegistry()
+///     registry.add("ed25519_verifier", image.id)
+///     receipt.verify(registry["ed25519_verifier"])
+///     registry.verify(receipt, "ed25519_verifier")
+///
+/// save()/to_json() write the registry as JSON, optionally signed with an
+/// Ed25519 key so a deployment pipeline can check it wasn't edited on the
+/// way: load()/from_json() with verify_key reject unsigned or altered files.
+#[pyclass(module = "pyr0")]
+#[derive(Clone, Default)]
+pub struct ImageRegistry {
+    images: BTreeMap<String, Digest>,
+}
+
+#[pymethods]
+impl ImageRegistry {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a trusted image ID under a name
+    ///
+    /// Args:
+    ///     name: Name to look the image up by
+    ///     image_id: Image ID as bytes, hex string, or Image
+    ///     replace: Allow changing the ID of an existing name
+    ///
+    /// Raises:
+    ///     ValueError: If image_id is invalid, or name is already registered
+    ///                 with a different ID and replace is False
+    #[pyo3(signature = (name, image_id, replace=false))]
+    pub fn add(&mut self, name: String, image_id: &Bound<'_, PyAny>, replace: bool) -> PyResult<()> {
+        let digest = receipt::image_id_digest(image_id)?;
+        match self.images.get(&name) {
+            Some(existing) if *existing != digest && !replace => Err(PyErr::new::<PyValueError, _>(format!(
+                "'{name}' is already registered as {} (pass replace=True to change it)",
+                hex::encode(existing.as_bytes())
+            ))),
+            _ => {
+                self.images.insert(name, digest);
+                Ok(())
+            }
+        }
+    }
+
+    /// Remove a name from the registry
+    ///
+    /// Raises:
+    ///     KeyError: If name isn't registered
+    pub fn remove(&mut self, name: &str) -> PyResult<()> {
+        self.get(name)?;
+        self.images.remove(name);
+        Ok(())
+    }
+
+    /// Registered names, sorted
+    pub fn names(&self) -> Vec<String> {
+        self.images.keys().cloned().collect()
+    }
+
+    /// The name an image ID is registered under, or None
+    ///
+    /// Raises:
+    ///     ValueError: If image_id is invalid
+    pub fn name_of(&self, image_id: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        let digest = receipt::image_id_digest(image_id)?;
+        Ok(self.images.iter().find(|(_, id)| **id == digest).map(|(name, _)| name.clone()))
+    }
+
+    /// Verify a receipt against the image ID registered under name
+    ///
+    /// Raises:
+    ///     KeyError: If name isn't registered
+    ///     VerificationError: If verification fails (see Receipt.verify())
+    pub fn verify(&self, py: Python<'_>, receipt: &Receipt, name: &str) -> PyResult<()> {
+        receipt.verify_digest(py, self.get(name)?)
+    }
+
+    /// Serialize the registry as JSON, optionally signed
+    ///
+    /// Args:
+    ///     signing_key: Optional 32-byte Ed25519 private key (seed)
+    ///
+    /// Raises:
+    ///     ValueError: If signing_key isn't 32 bytes
+    #[pyo3(signature = (signing_key=None))]
+    pub fn to_json(&self, signing_key: Option<Vec<u8>>) -> PyResult<String> {
+        use ed25519_dalek::Signer;
+
+        let body = RegistryBody {
+            version: FORMAT_VERSION,
+            images: self.images.iter().map(|(name, id)| (name.clone(), hex::encode(id.as_bytes()))).collect(),
+        };
+        let signature = signing_key
+            .map(|key| -> PyResult<String> {
+                let key = ed25519_dalek::SigningKey::from_bytes(&receipt::ed25519_key(&key, "signing_key")?);
+                Ok(hex::encode(key.sign(&signed_bytes(&body)?).to_bytes()))
+            })
+            .transpose()?;
+        serde_json::to_string_pretty(&RegistryFile { body, signature })
+            .map_err(|e| errors::serialization_error(format!("Failed to serialize registry: {e}")))
+    }
+
+    /// Parse a registry written by to_json()
+    ///
+    /// Args:
+    ///     data: The JSON text
+    ///     verify_key: 32-byte Ed25519 public key. If given, the registry
+    ///                 must be signed by it; if None, any signature is ignored.
+    ///
+    /// Raises:
+    ///     SerializationError: If the JSON is malformed or has an unknown version
+    ///     VerificationError: If verify_key is given and the registry isn't
+    ///                        signed with it (code SIGNATURE)
+    ///     ValueError: If verify_key isn't 32 bytes or an image ID is invalid
+    #[staticmethod]
+    #[pyo3(signature = (data, verify_key=None))]
+    pub fn from_json(py: Python<'_>, data: &str, verify_key: Option<Vec<u8>>) -> PyResult<Self> {
+        let file: RegistryFile = serde_json::from_str(data)
+            .map_err(|e| errors::serialization_error(format!("Invalid registry JSON: {e}")))?;
+        if file.body.version != FORMAT_VERSION {
+            return Err(errors::serialization_error(format!(
+                "Registry format version {} is not supported (expected {FORMAT_VERSION})",
+                file.body.version
+            )));
+        }
+
+        if let Some(key) = verify_key {
+            let key = ed25519_dalek::VerifyingKey::from_bytes(&receipt::ed25519_key(&key, "verify_key")?)
+                .map_err(|e| PyErr::new::<PyValueError, _>(format!("Invalid verify_key: {e}")))?;
+            let Some(signature) = &file.signature else {
+                return Err(errors::verification_error(py, "SIGNATURE", "Registry is not signed"));
+            };
+            let signature = hex::decode(signature)
+                .ok()
+                .and_then(|bytes| ed25519_dalek::Signature::from_slice(&bytes).ok())
+                .ok_or_else(|| errors::verification_error(py, "SIGNATURE", "Invalid registry signature"))?;
+            key.verify_strict(&signed_bytes(&file.body)?, &signature)
+                .map_err(|_| errors::verification_error(py, "SIGNATURE", "Registry signature doesn't match verify_key"))?;
+        }
+
+        let images = file
+            .body
+            .images
+            .into_iter()
+            .map(|(name, id)| Ok((name, receipt::digest_from_hex(&id)?)))
+            .collect::<PyResult<_>>()?;
+        Ok(Self { images })
+    }
+
+    /// Write the registry to a JSON file (see to_json())
+    ///
+    /// Raises:
+    ///     SerializationError: If the file can't be written
+    ///     ValueError: If signing_key isn't 32 bytes
+    #[pyo3(signature = (path, signing_key=None))]
+    pub fn save(&self, path: PathBuf, signing_key: Option<Vec<u8>>) -> PyResult<()> {
+        std::fs::write(&path, self.to_json(signing_key)?)
+            .map_err(|e| errors::serialization_error(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    /// Read a registry file written by save() (see from_json())
+    ///
+    /// Raises:
+    ///     SerializationError: If the file can't be read or is malformed
+    ///     VerificationError: If verify_key is given and the file isn't
+    ///                        signed with it (code SIGNATURE)
+    #[staticmethod]
+    #[pyo3(signature = (path, verify_key=None))]
+    pub fn load(py: Python<'_>, path: PathBuf, verify_key: Option<Vec<u8>>) -> PyResult<Self> {
+        let data = std::fs::read_to_string(&path)
+            .map_err(|e| errors::serialization_error(format!("Failed to read {}: {}", path.display(), e)))?;
+        Self::from_json(py, &data, verify_key)
+    }
+
+    /// The image ID registered under name (32 bytes)
+    ///
+    /// Raises:
+    ///     KeyError: If name isn't registered
+    pub fn __getitem__(&self, name: &str) -> PyResult<Vec<u8>> {
+        Ok(self.get(name)?.as_bytes().to_vec())
+    }
+
+    pub fn __contains__(&self, name: &str) -> bool {
+        self.images.contains_key(name)
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("ImageRegistry(names={:?})", self.names())
+    }
+}
+
+impl ImageRegistry {
+    /// Every registered image ID
+    pub(crate) fn digests(&self) -> Vec<Digest> {
+        self.images.values().copied().collect()
+    }
+
+    fn get(&self, name: &str) -> PyResult<Digest> {
+        self.images.get(name).copied().ok_or_else(|| {
+            PyErr::new::<PyKeyError, _>(format!("No image registered as '{name}'"))
+        })
+    }
+}
+
+/// Bytes a registry signature covers
+fn signed_bytes(body: &RegistryBody) -> PyResult<Vec<u8>> {
+    serde_json::to_vec(body).map_err(|e| errors::serialization_error(format!("Failed to serialize registry: {e}")))
+}
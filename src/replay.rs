@@ -0,0 +1,199 @@
+use pyo3::prelude::*;
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::{ExecutorEnv, ExecutorEnvBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::errors;
+use crate::session::SessionInfo;
+
+/// Start of every recording file
+const MAGIC: &[u8; 8] = b"PYR0RPLY";
+
+/// Bumped whenever the recording layout changes
+const FORMAT_VERSION: u32 = 1;
+
+/// Give a guest its environment variables and (unproven) assumptions
+///
+/// The executor only checks assumption claims, so claim digests are all a
+/// dry run or a replay needs.
+pub(crate) fn apply_environment(builder: &mut ExecutorEnvBuilder<'_>, env: &BTreeMap<String, String>, assumptions: &[Digest]) {
+    for (name, value) in env {
+        builder.env_var(name, value);
+    }
+    for &claim in assumptions {
+        builder.add_assumption(risc0_zkvm::Assumption { claim, control_root: Digest::ZERO });
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RecordingData {
+    pub(crate) image_id: Digest,
+    pub(crate) elf: Vec<u8>,
+    pub(crate) input: Vec<u8>,
+    pub(crate) env: BTreeMap<String, String>,
+    pub(crate) assumptions: Vec<Digest>,
+    /// What the recorded run produced, or why it failed
+    pub(crate) outcome: Result<SessionInfo, String>,
+}
+
+impl RecordingData {
+    pub(crate) fn write(&self, path: &Path) -> PyResult<()> {
+        let mut data = MAGIC.to_vec();
+        data.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bincode::serialize_into(&mut data, self)
+            .map_err(|e| errors::serialization_error(format!("Failed to serialize recording: {e}")))?;
+        std::fs::write(path, data)
+            .map_err(|e| errors::serialization_error(format!("Failed to write {}: {}", path.display(), e)))
+    }
+}
+
+/// A guest execution captured by dry_run(record=path)
+///
+/// Holds everything the execution depended on: the guest ELF, the input
+/// bytes, the guest's environment variables and the claims of the
+/// assumptions it could env::verify(). Attach the file to a bug report;
+/// replay() reruns the exact same execution on any machine.
+#[pyclass(module = "pyr0", frozen)]
+pub struct Recording {
+    data: RecordingData,
+}
+
+#[pymethods]
+impl Recording {
+    /// Load a recording file
+    ///
+    /// Raises:
+    ///     SerializationError: If the file can't be read or isn't a recording
+    #[staticmethod]
+    pub fn load(path: PathBuf) -> PyResult<Self> {
+        let bytes = std::fs::read(&path)
+            .map_err(|e| errors::serialization_error(format!("Failed to read {}: {}", path.display(), e)))?;
+        let body = bytes
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| errors::serialization_error(format!("{} is not a PyR0 recording", path.display())))?;
+        if body.len() < 4 {
+            return Err(errors::serialization_error("Recording is truncated"));
+        }
+        let (version, body) = body.split_at(4);
+        let version = u32::from_le_bytes(version.try_into().expect("4-byte slice"));
+        if version != FORMAT_VERSION {
+            return Err(errors::serialization_error(format!(
+                "Recording format version {version} is not supported (expected {FORMAT_VERSION})"
+            )));
+        }
+        let data = bincode::deserialize(body)
+            .map_err(|e| errors::serialization_error(format!("Failed to deserialize recording: {e}")))?;
+        Ok(Self { data })
+    }
+
+    /// Image ID of the recorded guest (32 bytes)
+    #[getter]
+    pub fn image_id(&self) -> Vec<u8> {
+        self.data.image_id.as_bytes().to_vec()
+    }
+
+    /// Input bytes the guest read
+    #[getter]
+    pub fn input(&self) -> Vec<u8> {
+        self.data.input.clone()
+    }
+
+    /// Environment variables the guest saw
+    #[getter]
+    pub fn env(&self) -> BTreeMap<String, String> {
+        self.data.env.clone()
+    }
+
+    /// Claim digests of the assumptions available to env::verify()
+    #[getter]
+    pub fn assumptions(&self) -> Vec<Vec<u8>> {
+        self.data.assumptions.iter().map(|claim| claim.as_bytes().to_vec()).collect()
+    }
+
+    /// Summary of the recorded execution, or None if it failed
+    #[getter]
+    pub fn recorded(&self) -> Option<SessionInfo> {
+        self.data.outcome.as_ref().ok().cloned()
+    }
+
+    /// Why the recorded execution failed, or None if it succeeded
+    #[getter]
+    pub fn error(&self) -> Option<String> {
+        self.data.outcome.as_ref().err().cloned()
+    }
+
+    /// Run the recorded execution again
+    ///
+    /// Compare the result with recorded via compare_sessions() to see
+    /// whether this machine behaves like the one that made the recording.
+    ///
+    /// Args:
+    ///     capture_output: Collect guest stdout/stderr (see dry_run())
+    ///
+    /// Returns:
+    ///     SessionInfo: Summary of the new execution
+    ///
+    /// Raises:
+    ///     ImageError: If the recorded ELF doesn't have the recorded image ID
+    ///     ProvingError: If execution fails
+    #[pyo3(signature = (capture_output=false))]
+    pub fn replay(&self, py: Python<'_>, capture_output: bool) -> PyResult<SessionInfo> {
+        let image = crate::load_elf(&self.data.elf)?;
+        if image.id()? != self.data.image_id.as_bytes() {
+            return Err(errors::image_error(format!(
+                "Recorded ELF has image ID {}, but the recording names {}",
+                image.id_hex()?,
+                hex::encode(self.data.image_id.as_bytes())
+            )));
+        }
+        let memory_image = image.get_image();
+        let data = &self.data;
+
+        py.allow_threads(|| -> anyhow::Result<SessionInfo> {
+            let mut stdout = Vec::new();
+            let mut stderr = Vec::new();
+            let info = {
+                let mut builder = ExecutorEnv::builder();
+                builder.write_slice(&data.input);
+                apply_environment(&mut builder, &data.env, &data.assumptions);
+                if capture_output {
+                    builder.stdout(&mut stdout).stderr(&mut stderr);
+                }
+                let env = builder.build()?;
+                let session = risc0_zkvm::ExecutorImpl::new(env, memory_image)?.run()?;
+                SessionInfo::new(&session)?
+            };
+            Ok(if capture_output { info.with_output(stdout, stderr) } else { info })
+        })
+            .map_err(|e| errors::proving_error(format!("Replay failed: {e}")))
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Recording(image_id={}, input_len={}, env={}, assumptions={}, outcome={})",
+            hex::encode(self.data.image_id.as_bytes()),
+            self.data.input.len(),
+            self.data.env.len(),
+            self.data.assumptions.len(),
+            if self.data.outcome.is_ok() { "ok" } else { "failed" }
+        )
+    }
+}
+
+/// Replay an execution recorded with dry_run(record=path)
+///
+/// Shorthand for Recording.load(path).replay(capture_output).
+///
+/// Example:
+///     recording = pyr0.Recording.load("bug.r0replay")
+///     replayed = pyr0.replay("bug.r0replay")
+///     if recording.recorded is not None:
+///         print(pyr0.compare_sessions(recording.recorded, replayed).summary())
+#[pyfunction]
+#[pyo3(signature = (path, capture_output=false))]
+pub fn replay(py: Python<'_>, path: PathBuf, capture_output: bool) -> PyResult<SessionInfo> {
+    Recording::load(path)?.replay(py, capture_output)
+}
+
@@ -0,0 +1,258 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+use std::thread::ThreadId;
+
+use crate::errors;
+
+/// Environment variable CUDA reads, once per process, to pick the visible devices
+const CUDA_DEVICES_VAR: &str = "CUDA_VISIBLE_DEVICES";
+
+thread_local! {
+    /// Thread pools of the reservations entered on this thread, innermost last
+    static ACTIVE_POOLS: RefCell<Vec<Arc<rayon::ThreadPool>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Release the GIL and run proving work on this thread's reserved pool
+///
+/// RISC Zero parallelizes proving with rayon, so work installed in a
+/// dedicated pool uses at most that pool's threads. Without an active
+/// reservation this is plain allow_threads() on rayon's global pool.
+pub(crate) fn allow_threads<T, F>(py: Python<'_>, f: F) -> T
+where
+    T: Send,
+    F: Send + FnOnce() -> T,
+{
+    let pool = ACTIVE_POOLS.with(|pools| pools.borrow().last().cloned());
+    py.allow_threads(|| match pool {
+        Some(pool) => pool.install(f),
+        None => f(),
+    })
+}
+
+/// Lower the calling thread's priority and pin it to `cpus`
+#[cfg(target_os = "linux")]
+fn configure_worker(nice: Option<i32>, cpus: &[usize]) -> Result<(), String> {
+    // SAFETY: plain syscalls on the calling thread; the cpu_set_t is
+    // zero-initialized and only indexed below CPU_SETSIZE (checked by the caller)
+    unsafe {
+        if let Some(nice) = nice {
+            // On Linux, PRIO_PROCESS with a thread ID targets just that thread
+            let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+            if libc::setpriority(libc::PRIO_PROCESS, tid, nice) != 0 {
+                return Err(format!("setpriority failed: {}", std::io::Error::last_os_error()));
+            }
+        }
+        if !cpus.is_empty() {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            for &cpu in cpus {
+                libc::CPU_SET(cpu, &mut set);
+            }
+            if libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+                return Err(format!("sched_setaffinity failed: {}", std::io::Error::last_os_error()));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn configure_worker(_nice: Option<i32>, _cpus: &[usize]) -> Result<(), String> {
+    Err("nice and cpus are only supported on Linux".to_string())
+}
+
+/// Limits on what proving may use, active inside a `with` block
+///
+/// Created by reserve_prover(). Proofs started on the same thread inside the
+/// block run on a dedicated thread pool of `cores` threads instead of one
+/// thread per CPU, so a proving job can share the machine with other work.
+/// Reservations nest; the innermost one with `cores` set wins.
+///
+/// The limits apply to the thread that entered the block, so it must also
+/// be the one to leave it; __exit__() from another thread raises RuntimeError.
+#[pyclass(module = "pyr0")]
+pub struct ProverReservation {
+    /// Threads proving may use, or None for no limit
+    #[pyo3(get)]
+    pub cores: Option<usize>,
+    /// CUDA device this process proves on, or None if not checked
+    #[pyo3(get)]
+    pub gpu: Option<u32>,
+    /// Niceness of the proving threads, or None to inherit the caller's
+    #[pyo3(get)]
+    pub nice: Option<i32>,
+    /// CPUs the proving threads are pinned to, or None for any
+    #[pyo3(get)]
+    pub cpus: Option<Vec<usize>>,
+    pool: Option<Arc<rayon::ThreadPool>>,
+    entered_on: Option<ThreadId>,  // Thread whose pool stack holds `pool`, while active
+}
+
+#[pymethods]
+impl ProverReservation {
+    pub fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        if slf.entered_on.is_some() {
+            return Err(PyErr::new::<PyValueError, _>("ProverReservation is already active"));
+        }
+        if let Some(pool) = &slf.pool {
+            ACTIVE_POOLS.with(|pools| pools.borrow_mut().push(pool.clone()));
+        }
+        slf.entered_on = Some(std::thread::current().id());
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc=None, _tb=None))]
+    pub fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc: Option<PyObject>,
+        _tb: Option<PyObject>,
+    ) -> PyResult<bool> {
+        match self.entered_on {
+            None => return Ok(false),
+            Some(thread) if thread != std::thread::current().id() => {
+                return Err(PyErr::new::<PyRuntimeError, _>(
+                    "ProverReservation must be exited on the thread that entered it",
+                ));
+            }
+            Some(_) => self.entered_on = None,
+        }
+        if let Some(pool) = &self.pool {
+            ACTIVE_POOLS.with(|pools| {
+                let mut pools = pools.borrow_mut();
+                if let Some(at) = pools.iter().rposition(|p| Arc::ptr_eq(p, pool)) {
+                    pools.remove(at);
+                }
+            });
+        }
+        Ok(false)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ProverReservation(cores={}, gpu={}, nice={}, cpus={:?})",
+            self.cores.map_or("None".to_string(), |c| c.to_string()),
+            self.gpu.map_or("None".to_string(), |g| g.to_string()),
+            self.nice.map_or("None".to_string(), |n| n.to_string()),
+            self.cpus
+        )
+    }
+}
+
+/// Reserve resources for proving within a `with` block
+///
+/// Proofs started inside the block on the calling thread (prove(),
+/// prove_with_opts(), Composer.prove(), compress_to_*(), the segment
+/// functions, ...) run with at most `cores` threads. Proofs on other
+/// threads, including prove_async(), are unaffected.
+///
+/// Peak memory is driven by segment size rather than thread count; bound it
+/// with ProverConfig(segment_po2=...).
+///
+/// Args:
+///     cores: Maximum number of proving threads (default: no limit, or
+///            len(cpus) if cpus is given)
+///     gpu: CUDA device index proving must run on. RISC Zero always proves
+///          on the first device in CUDA_VISIBLE_DEVICES, which CUDA reads
+///          once per process, so the device can't be switched per proof:
+///          start one process per GPU with CUDA_VISIBLE_DEVICES=<gpu> and
+///          pass the same index here to have that checked.
+///     nice: Niceness of the proving threads, 0-19. Higher values yield to
+///           other work, e.g. request handlers on a shared server (Linux only)
+///     cpus: CPU indices to pin the proving threads to (Linux only)
+///
+/// Returns:
+///     ProverReservation: Context manager applying the limits
+///
+/// Raises:
+///     ValueError: If cores is 0, nice is out of range, cpus is empty or
+///                 out of range, gpu is given on a build without CUDA, or
+///                 this process wasn't started to prove on gpu
+///     ProvingError: If the thread pool can't be started or configured
+///
+/// Example:
+///     with pyr0.reserve_prover(cores=4, nice=10, cpus=[4, 5, 6, 7]):
+///         receipt = pyr0.prove(image, input_data)
+#[pyfunction]
+#[pyo3(signature = (cores=None, gpu=None, nice=None, cpus=None))]
+pub fn reserve_prover(
+    cores: Option<usize>,
+    gpu: Option<u32>,
+    nice: Option<i32>,
+    cpus: Option<Vec<usize>>,
+) -> PyResult<ProverReservation> {
+    if cores == Some(0) {
+        return Err(PyErr::new::<PyValueError, _>("cores must be at least 1"));
+    }
+    if let Some(nice) = nice {
+        // Raising priority needs privileges and would starve other work
+        if !(0..=19).contains(&nice) {
+            return Err(PyErr::new::<PyValueError, _>(format!("nice must be between 0 and 19, got {nice}")));
+        }
+    }
+    if let Some(cpus) = &cpus {
+        if cpus.is_empty() {
+            return Err(PyErr::new::<PyValueError, _>("cpus must name at least one CPU"));
+        }
+        #[cfg(target_os = "linux")]
+        if let Some(&cpu) = cpus.iter().find(|&&cpu| cpu >= libc::CPU_SETSIZE as usize) {
+            return Err(PyErr::new::<PyValueError, _>(format!("CPU index {cpu} is out of range")));
+        }
+    }
+    let cores = cores.or(cpus.as_ref().map(Vec::len));
+    if gpu.is_some() && crate::prover_config::compiled_backend() != "cuda" {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "gpu needs a CUDA build of PyR0; this one proves on '{}'",
+            crate::prover_config::compiled_backend()
+        )));
+    }
+    if let Some(gpu) = gpu {
+        check_gpu(gpu)?;
+    }
+    let pool = match cores {
+        Some(cores) => Some(build_pool(cores, nice, cpus.clone().unwrap_or_default())?),
+        None if nice.is_some() => Some(build_pool(rayon::current_num_threads(), nice, Vec::new())?),
+        None => None,
+    };
+    Ok(ProverReservation { cores, gpu, nice, cpus, pool, entered_on: None })
+}
+
+/// Check that the first visible CUDA device, which RISC Zero proves on, is `gpu`
+fn check_gpu(gpu: u32) -> PyResult<()> {
+    let devices = std::env::var(CUDA_DEVICES_VAR).ok();
+    let first = devices.as_deref().and_then(|d| d.split(',').next()).map(str::trim);
+    if first == Some(gpu.to_string().as_str()) {
+        return Ok(());
+    }
+    Err(PyErr::new::<PyValueError, _>(format!(
+        "gpu={gpu} needs the process started with {CUDA_DEVICES_VAR}={gpu} \
+         (it is {}); RISC Zero always proves on the first visible device",
+        devices.as_deref().unwrap_or("unset")
+    )))
+}
+
+/// Start a pool of `cores` threads, each reniced and pinned as requested
+fn build_pool(cores: usize, nice: Option<i32>, cpus: Vec<usize>) -> PyResult<Arc<rayon::ThreadPool>> {
+    let failure: Arc<Mutex<Option<String>>> = Arc::default();
+    let mut builder = rayon::ThreadPoolBuilder::new()
+        .num_threads(cores)
+        .thread_name(|i| format!("pyr0-prover-{i}"));
+    if nice.is_some() || !cpus.is_empty() {
+        let failure = failure.clone();
+        builder = builder.start_handler(move |_| {
+            if let Err(e) = configure_worker(nice, &cpus) {
+                failure.lock().unwrap_or_else(|p| p.into_inner()).get_or_insert(e);
+            }
+        });
+    }
+    let pool = builder
+        .build()
+        .map_err(|e| errors::proving_error(format!("Failed to start prover thread pool: {e}")))?;
+    // Every worker has run its start handler once it has taken part in a broadcast
+    pool.broadcast(|_| ());
+    if let Some(e) = failure.lock().unwrap_or_else(|p| p.into_inner()).take() {
+        return Err(errors::proving_error(format!("Failed to configure prover threads: {e}")));
+    }
+    Ok(Arc::new(pool))
+}
@@ -0,0 +1,194 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use serde_json::{json, Value};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Lowercase hex string, optionally of a fixed byte length
+fn hex_bytes(description: &str, len: Option<usize>) -> Value {
+    let pattern = match len {
+        Some(n) => format!("^[0-9a-f]{{{}}}$", n * 2),
+        None => "^([0-9a-f]{2})*$".to_string(),
+    };
+    json!({ "type": "string", "pattern": pattern, "contentEncoding": "base16", "description": description })
+}
+
+fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+fn uint(bits: u32, description: &str) -> Value {
+    let max = if bits >= 64 { json!(u64::MAX) } else { json!((1u64 << bits) - 1) };
+    json!({ "type": "integer", "minimum": 0, "maximum": max, "description": description })
+}
+
+fn object(description: &str, properties: Value, required: &[&str]) -> Value {
+    json!({
+        "type": "object",
+        "description": description,
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn exit_status() -> Value {
+    object(
+        "How the guest stopped (ExitStatus)",
+        json!({
+            "kind": { "enum": ["HALTED", "PAUSED", "SYSTEM_SPLIT", "SESSION_LIMIT", "UNKNOWN"] },
+            "user_code": nullable(uint(32, "Guest exit code; null for SYSTEM_SPLIT and SESSION_LIMIT")),
+            "segment_index": nullable(uint(32, "Segment in which execution stopped (executor results only)")),
+            "cycle_count": nullable(uint(64, "Cycles executed when execution stopped (executor results only)")),
+        }),
+        &["kind", "user_code"],
+    )
+}
+
+fn claim() -> Value {
+    object(
+        "What a receipt proves (Claim)",
+        json!({
+            "image_id": hex_bytes("Image ID of the executed program", Some(32)),
+            "journal": hex_bytes("Raw journal bytes", None),
+            "journal_digest": hex_bytes("SHA-256 of the journal", Some(32)),
+            "exit_code": uint(32, "User exit code; 4294967295 for system exits"),
+            "exit_kind": { "enum": ["HALTED", "PAUSED", "SYSTEM_SPLIT", "SESSION_LIMIT", "UNKNOWN"] },
+            "input_digest": hex_bytes("Digest of the claim's input; zero when there is none", Some(32)),
+            "output_digest": hex_bytes("Digest of the claim's Output (journal digest and assumptions)", Some(32)),
+            "pre_state_digest": hex_bytes("SystemState digest before execution", Some(32)),
+            "post_state_digest": nullable(hex_bytes("SystemState digest after execution", Some(32))),
+        }),
+        &[
+            "image_id",
+            "journal",
+            "journal_digest",
+            "exit_code",
+            "exit_kind",
+            "input_digest",
+            "output_digest",
+            "pre_state_digest",
+        ],
+    )
+}
+
+fn session_info() -> Value {
+    object(
+        "Result of dry_run() (SessionInfo)",
+        json!({
+            "journal": hex_bytes("Raw journal bytes", None),
+            "segment_count": uint(64, "Number of segments"),
+            "user_cycles": uint(64, "Cycles spent in guest code"),
+            "total_cycles": uint(64, "Total cycles including paging and padding"),
+            "exit": { "$ref": "#/$defs/ExitStatus" },
+            "stdout": nullable(hex_bytes("Guest stdout; null unless dry_run(capture_output=True)", None)),
+            "stderr": nullable(hex_bytes("Guest stderr; null unless dry_run(capture_output=True)", None)),
+        }),
+        &["journal", "segment_count", "user_cycles", "total_cycles", "exit"],
+    )
+}
+
+fn receipt() -> Value {
+    object(
+        "Receipt.to_json(). Receipt.to_bytes() itself is the bincode encoding of \
+         risc0_zkvm::Receipt, carried in JSON as the \"bytes\" field",
+        json!({
+            "bytes": hex_bytes("Receipt.to_bytes()", None),
+            "kind": { "enum": ["composite", "succinct", "groth16", "fake"] },
+            "claimed_image_id": hex_bytes("Image ID claimed by the receipt (untrusted until verified)", Some(32)),
+            "claim_digest": hex_bytes("Digest of the full ReceiptClaim", Some(32)),
+            "claim": { "$ref": "#/$defs/Claim" },
+            "exit": { "$ref": "#/$defs/ExitStatus" },
+            "seal": nullable(hex_bytes("Raw seal of a succinct or groth16 receipt", None)),
+            "seal_size": uint(64, "Seal size in bytes"),
+            "assumption_count": uint(64, "Assumption receipts carried by a composite receipt"),
+        }),
+        &["bytes"],
+    )
+}
+
+fn audit_record() -> Value {
+    object(
+        "One line of export_audit_log() NDJSON",
+        json!({
+            "timestamp": { "type": "string", "format": "date-time" },
+            "claim_digest": hex_bytes("Digest of the full ReceiptClaim", Some(32)),
+            "claimed_image_id": hex_bytes("Image ID claimed by the receipt", Some(32)),
+            "kind": { "enum": ["composite", "succinct", "groth16", "fake"] },
+            "exit_kind": { "enum": ["HALTED", "PAUSED", "SYSTEM_SPLIT", "SESSION_LIMIT", "UNKNOWN"] },
+            "exit_code": nullable(uint(32, "Guest exit code")),
+            "journal_digest": hex_bytes("SHA-256 of the journal", Some(32)),
+            "journal_len": uint(64, "Journal length in bytes"),
+            "seal_size": uint(64, "Seal size in bytes"),
+            "verified": { "type": ["boolean", "null"], "description": "null when no image ID was given" },
+            "verification_error": { "type": ["string", "null"] },
+        }),
+        &[
+            "timestamp", "claim_digest", "claimed_image_id", "kind", "exit_kind", "exit_code",
+            "journal_digest", "journal_len", "seal_size", "verified", "verification_error",
+        ],
+    )
+}
+
+/// Build the schema document: every type under $defs
+fn schema_document() -> Value {
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$id": format!("urn:pyr0:schema:{}", env!("CARGO_PKG_VERSION")),
+        "title": "PyR0 wire formats",
+        "description": "Byte strings are lowercase hex. Integers are unsigned unless noted.",
+        "$defs": {
+            "ExitStatus": exit_status(),
+            "Claim": claim(),
+            "SessionInfo": session_info(),
+            "Receipt": receipt(),
+            "AuditRecord": audit_record(),
+        },
+    })
+}
+
+/// JSON Schema descriptions of PyR0's serialized types
+///
+/// Lets services in other languages generate bindings for receipts, claims
+/// and session results instead of reverse-engineering them.
+///
+/// Args:
+///     name: One of "Receipt", "Claim", "SessionInfo", "ExitStatus" or
+///           "AuditRecord" to get a single standalone schema. If None, returns
+///           a document with every type under "$defs".
+///
+/// Returns:
+///     dict: The JSON Schema (draft 2020-12)
+///
+/// Raises:
+///     ValueError: If name is not a known type
+///
+/// Example:
+///     json.dump(pyr0.schema(), open("pyr0.schema.json", "w"), indent=2)
+#[pyfunction]
+#[pyo3(signature = (name=None))]
+pub fn schema(py: Python<'_>, name: Option<&str>) -> PyResult<PyObject> {
+    let mut document = schema_document();
+    let value = match name {
+        None => document,
+        Some(name) => {
+            let defs = document["$defs"].take();
+            let Some(mut single) = defs.get(name).cloned() else {
+                return Err(PyErr::new::<PyValueError, _>(format!(
+                    "Unknown type '{}'. Expected one of: {}",
+                    name,
+                    defs.as_object().map(|o| o.keys().cloned().collect::<Vec<_>>().join(", ")).unwrap_or_default()
+                )));
+            };
+            // Keep $refs resolvable in the standalone schema
+            single["$schema"] = json!(JSON_SCHEMA_DIALECT);
+            single["title"] = json!(name);
+            single["$defs"] = defs;
+            single
+        }
+    };
+
+    let text = serde_json::to_string(&value)
+        .map_err(|e| PyErr::new::<PyValueError, _>(format!("Failed to encode schema: {}", e)))?;
+    Ok(py.import("json")?.call_method1("loads", (text,))?.unbind())
+}
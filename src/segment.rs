@@ -0,0 +1,644 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyDict;
+use risc0_zkvm::sha::Digestible;
+use risc0_zkvm::{
+    get_prover_server, Assumption, ExecutorEnv, InnerAssumptionReceipt, InnerReceipt, NullSegmentRef,
+    ProverOpts, ProverServer, ReceiptClaim, Unknown, VerifierContext,
+};
+use std::rc::Rc;
+
+use crate::errors;
+use crate::guest_input::GuestInput;
+use crate::image::Image;
+use crate::prover_config::ProverConfig;
+use crate::receipt::{ExitStatus, Receipt};
+use crate::session::SessionInfo;
+
+/// bincode with a byte limit, so length prefixes can't claim more memory
+/// than the input holds
+fn decode_limited<T: serde::de::DeserializeOwned>(data: &[u8], what: &str) -> PyResult<T> {
+    use bincode::Options;
+
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(data.len() as u64)
+        .deserialize(data)
+        .map_err(|e| errors::serialization_error(format!("Failed to deserialize {what}: {e}")))
+}
+
+fn encode<T: serde::Serialize>(value: &T, what: &str) -> PyResult<Vec<u8>> {
+    bincode::serialize(value)
+        .map_err(|e| errors::serialization_error(format!("Failed to serialize {what}: {e}")))
+}
+
+/// Prover server for the recursion steps, honouring a ProverConfig's hashfn
+fn prover_server(config: Option<&ProverConfig>) -> anyhow::Result<Rc<dyn ProverServer>> {
+    let mut opts = ProverOpts::default();
+    if let Some(config) = config {
+        config.apply_opts(&mut opts);
+    }
+    get_prover_server(&opts)
+}
+
+/// One unproven chunk of an execution, as produced by execute_to_segments()
+///
+/// Segments are independent units of proving work: serialize one with
+/// to_bytes(), prove it anywhere with prove_segment(), and send back the
+/// SegmentReceipt.
+#[pyclass(module = "pyr0", frozen)]
+pub struct Segment {
+    inner: risc0_zkvm::Segment,
+}
+
+#[pymethods]
+impl Segment {
+    /// Position of this segment in the execution (0-based)
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.inner.index
+    }
+
+    /// Segment size as log2 of its padded cycle count
+    #[getter]
+    pub fn po2(&self) -> usize {
+        self.inner.po2()
+    }
+
+    /// Serialize the segment to bytes (bincode), e.g. to ship it to a worker
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        encode(&self.inner, "segment")
+    }
+
+    /// Deserialize a segment produced by to_bytes()
+    ///
+    /// Raises:
+    ///     SerializationError: If the data is malformed
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self { inner: decode_limited(data, "segment")? })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Segment(index={}, po2={})", self.inner.index, self.inner.po2())
+    }
+}
+
+/// A recursion proof over a range of segments (or a whole execution)
+///
+/// Produced by lift(), join() and resolve(). Once it covers every segment,
+/// into_receipt() turns it into an ordinary SUCCINCT Receipt.
+#[pyclass(module = "pyr0", frozen)]
+#[derive(Clone)]
+pub struct SuccinctReceipt {
+    inner: risc0_zkvm::SuccinctReceipt<ReceiptClaim>,
+}
+
+#[pymethods]
+impl SuccinctReceipt {
+    /// Digest of the ReceiptClaim this receipt proves (32 bytes)
+    #[getter]
+    pub fn claim_digest(&self) -> Vec<u8> {
+        self.inner.claim.digest().as_bytes().to_vec()
+    }
+
+    /// Seal size in bytes
+    #[getter]
+    pub fn seal_size(&self) -> usize {
+        self.inner.seal_size()
+    }
+
+    /// Check the seal against this receipt's claim
+    ///
+    /// Raises:
+    ///     VerificationError: If the seal doesn't prove the claim (code SEAL)
+    pub fn verify_integrity(&self, py: Python<'_>) -> PyResult<()> {
+        let inner = &self.inner;
+        py.allow_threads(|| inner.verify_integrity())
+            .map_err(|e| errors::verification_error(py, "SEAL", format!("Succinct receipt failed verification: {e}")))
+    }
+
+    /// Wrap this receipt and the session's journal into a SUCCINCT Receipt
+    ///
+    /// Args:
+    ///     journal: The journal of the execution (SessionInfo.journal from
+    ///              execute_to_segments())
+    ///
+    /// Raises:
+    ///     ValueError: If the journal doesn't match the proven claim
+    pub fn into_receipt(&self, journal: Vec<u8>) -> PyResult<Receipt> {
+        let claim = self.inner.claim.as_value()
+            .map_err(|_| errors::serialization_error("Claim is pruned"))?;
+        let matches = match claim.output.as_value() {
+            Ok(Some(output)) => output.journal.digest() == journal.digest(),
+            Ok(None) => journal.is_empty(),
+            Err(_) => return Err(errors::serialization_error("Claim output is pruned")),
+        };
+        if !matches {
+            return Err(PyErr::new::<PyValueError, _>(
+                "Journal doesn't match the claim (was every segment joined?)"
+            ));
+        }
+        let receipt = risc0_zkvm::Receipt::new(InnerReceipt::Succinct(self.inner.clone()), journal);
+        Ok(Receipt::from_risc0(receipt))
+    }
+
+    /// Serialize the receipt to bytes (bincode)
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        encode(&self.inner, "succinct receipt")
+    }
+
+    /// Deserialize a receipt produced by to_bytes()
+    ///
+    /// Raises:
+    ///     SerializationError: If the data is malformed
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self { inner: decode_limited(data, "succinct receipt")? })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SuccinctReceipt(claim_digest={}, seal_size={})",
+            hex::encode(self.inner.claim.digest().as_bytes()),
+            self.inner.seal_size()
+        )
+    }
+}
+
+impl SuccinctReceipt {
+    /// Prove, lift and join every segment of a run, in order
+    ///
+    /// Call with the GIL released. The claim keeps any assumptions the run
+    /// made; resolve() discharges them.
+    pub(crate) fn prove_segments(segments: &[risc0_zkvm::Segment]) -> anyhow::Result<Self> {
+        let prover = prover_server(None)?;
+        let ctx = VerifierContext::default();
+        let mut joined = None;
+        for segment in segments {
+            let lifted = prover.lift(&prover.prove_segment(&ctx, segment)?)?;
+            joined = Some(match joined {
+                Some(acc) => prover.join(&acc, &lifted)?,
+                None => lifted,
+            });
+        }
+        let inner = joined.ok_or_else(|| anyhow::anyhow!("Execution produced no segments"))?;
+        Ok(Self { inner })
+    }
+}
+
+/// A recursion proof of an accelerator claim rather than an execution
+///
+/// Produced by prove_keccak() for one batch of keccak requests, and by
+/// union() for several proofs combined into one. Pass it to resolve() or
+/// Receipt.resolve(keccak=[...]) to discharge the matching assumption of a
+/// receipt proved with prove_with_opts(coprocessor=...).
+#[pyclass(module = "pyr0", frozen)]
+#[derive(Clone)]
+pub struct KeccakReceipt {
+    pub(crate) inner: risc0_zkvm::SuccinctReceipt<Unknown>,
+}
+
+#[pymethods]
+impl KeccakReceipt {
+    /// Digest of the claim this receipt proves (32 bytes)
+    ///
+    /// For prove_keccak() this is the request's claim_digest.
+    #[getter]
+    pub fn claim_digest(&self) -> Vec<u8> {
+        self.inner.claim.digest().as_bytes().to_vec()
+    }
+
+    /// Seal size in bytes
+    #[getter]
+    pub fn seal_size(&self) -> usize {
+        self.inner.seal_size()
+    }
+
+    /// Check the seal against this receipt's claim
+    ///
+    /// Raises:
+    ///     VerificationError: If the seal doesn't prove the claim (code SEAL)
+    pub fn verify_integrity(&self, py: Python<'_>) -> PyResult<()> {
+        let inner = &self.inner;
+        py.allow_threads(|| inner.verify_integrity())
+            .map_err(|e| errors::verification_error(py, "SEAL", format!("Keccak receipt failed verification: {e}")))
+    }
+
+    /// Serialize the receipt to bytes (bincode)
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        encode(&self.inner, "keccak receipt")
+    }
+
+    /// Deserialize a receipt produced by to_bytes()
+    ///
+    /// Raises:
+    ///     SerializationError: If the data is malformed
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self { inner: decode_limited(data, "keccak receipt")? })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "KeccakReceipt(claim_digest={}, seal_size={})",
+            hex::encode(self.inner.claim.digest().as_bytes()),
+            self.inner.seal_size()
+        )
+    }
+}
+
+/// First unresolved assumption in a claim, if any
+fn first_assumption(claim: &ReceiptClaim) -> anyhow::Result<Option<Assumption>> {
+    let Some(output) = claim.output.as_value()? else { return Ok(None) };
+    let head = output.assumptions.as_value()?.0.first();
+    Ok(head.map(|assumption| assumption.as_value().cloned()).transpose()?)
+}
+
+/// Lift, join and resolve a receipt into an unconditional SUCCINCT receipt
+///
+/// Assumptions are discharged in claim order, each with the receipt among
+/// `assumptions` whose claim it names, falling back to the assumption
+/// receipts a composite receipt carries itself. `keccak` supplies proofs of
+/// accelerator claims. Call with the GIL released.
+pub(crate) fn resolve_receipt(
+    receipt: &risc0_zkvm::Receipt,
+    assumptions: &[risc0_zkvm::Receipt],
+    keccak: &[risc0_zkvm::SuccinctReceipt<Unknown>],
+) -> anyhow::Result<risc0_zkvm::Receipt> {
+    let prover = prover_server(None)?;
+
+    let (mut conditional, carried) = match &receipt.inner {
+        InnerReceipt::Composite(composite) => {
+            let mut joined = None;
+            for segment in &composite.segments {
+                let lifted = prover.lift(segment)?;
+                joined = Some(match joined {
+                    Some(acc) => prover.join(&acc, &lifted)?,
+                    None => lifted,
+                });
+            }
+            let joined = joined.ok_or_else(|| anyhow::anyhow!("Composite receipt has no segments"))?;
+            (joined, composite.assumption_receipts.as_slice())
+        }
+        InnerReceipt::Succinct(succinct) => (succinct.clone(), &[][..]),
+        _ => anyhow::bail!("Only COMPOSITE and SUCCINCT receipts can be resolved"),
+    };
+
+    // Every receipt that could discharge an assumption, keyed by the claim it proves
+    let mut available = Vec::new();
+    for assumption in assumptions {
+        let succinct = match &assumption.inner {
+            InnerReceipt::Succinct(succinct) => succinct.clone(),
+            InnerReceipt::Composite(_) => match prover.compress(&ProverOpts::succinct(), assumption)?.inner {
+                InnerReceipt::Succinct(succinct) => succinct,
+                _ => anyhow::bail!("Compressing an assumption didn't produce a SUCCINCT receipt"),
+            },
+            _ => anyhow::bail!("Assumption receipts must be SUCCINCT or COMPOSITE, not GROTH16 or FAKE"),
+        };
+        available.push((succinct.claim.digest(), succinct.into_unknown()));
+    }
+    for receipt in keccak {
+        available.push((receipt.claim.digest(), receipt.clone()));
+    }
+    for carried in carried {
+        if let InnerAssumptionReceipt::Succinct(succinct) = carried {
+            available.push((succinct.claim.digest(), succinct.clone()));
+        }
+    }
+
+    while let Some(head) = first_assumption(conditional.claim.as_value()?)? {
+        let Some((_, assumption)) = available.iter().find(|(claim, _)| *claim == head.claim) else {
+            anyhow::bail!("No receipt provided for assumption with claim digest {}", head.claim);
+        };
+        conditional = prover.resolve(&conditional, assumption)?;
+    }
+    Ok(risc0_zkvm::Receipt::new(InnerReceipt::Succinct(conditional), receipt.journal.bytes.clone()))
+}
+
+/// Proof of one segment of a continuation
+///
+/// A COMPOSITE receipt is a chain of segment receipts, one per segment the
+/// executor split the run into. Each proves a transition from its pre-state
+/// to its post-state; the post-state of one segment is the pre-state of the
+/// next. Get them with Receipt.segments().
+#[pyclass(module = "pyr0", frozen)]
+#[derive(Clone)]
+pub struct SegmentReceipt {
+    pub(crate) inner: risc0_zkvm::SegmentReceipt,
+}
+
+impl SegmentReceipt {
+    pub fn from_risc0(receipt: risc0_zkvm::SegmentReceipt) -> Self {
+        Self { inner: receipt }
+    }
+}
+
+#[pymethods]
+impl SegmentReceipt {
+    /// Position of this segment in the continuation (0-based)
+    #[getter]
+    pub fn index(&self) -> u32 {
+        self.inner.index
+    }
+
+    /// Hash function the seal was generated with (e.g. "poseidon2")
+    #[getter]
+    pub fn hashfn(&self) -> &str {
+        &self.inner.hashfn
+    }
+
+    /// Seal size in bytes
+    #[getter]
+    pub fn seal_size(&self) -> usize {
+        self.inner.seal_size()
+    }
+
+    /// Digest of this segment's ReceiptClaim (32 bytes)
+    #[getter]
+    pub fn claim_digest(&self) -> Vec<u8> {
+        self.inner.claim.digest().as_bytes().to_vec()
+    }
+
+    /// SystemState digest the segment starts from (the image ID for segment 0)
+    #[getter]
+    pub fn pre_state_digest(&self) -> Vec<u8> {
+        self.inner.claim.pre.digest().as_bytes().to_vec()
+    }
+
+    /// SystemState digest the segment ends in
+    #[getter]
+    pub fn post_state_digest(&self) -> Vec<u8> {
+        self.inner.claim.post.digest().as_bytes().to_vec()
+    }
+
+    /// How the segment ended: SYSTEM_SPLIT for all but the last segment
+    #[getter]
+    pub fn exit(&self) -> ExitStatus {
+        ExitStatus::from_risc0(self.inner.claim.exit_code)
+    }
+
+    /// Check the seal against this segment's claim
+    ///
+    /// This says nothing about which program ran or whether the segment
+    /// belongs to a given receipt; compare pre/post state digests for that.
+    ///
+    /// Raises:
+    ///     VerificationError: If the seal doesn't prove the claim (code SEAL)
+    pub fn verify_integrity(&self, py: Python<'_>) -> PyResult<()> {
+        let inner = &self.inner;
+        py.allow_threads(|| inner.verify_integrity_with_context(&VerifierContext::default()))
+            .map_err(|e| errors::verification_error(py, "SEAL", format!("Segment {} failed verification: {e}", inner.index)))
+    }
+
+    /// Serialize the segment receipt to bytes (bincode)
+    pub fn to_bytes(&self) -> PyResult<Vec<u8>> {
+        encode(&self.inner, "segment receipt")
+    }
+
+    /// Deserialize a segment receipt produced by to_bytes()
+    ///
+    /// Raises:
+    ///     SerializationError: If the data is malformed
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self { inner: decode_limited(data, "segment receipt")? })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SegmentReceipt(index={}, exit={:?}, seal_size={})",
+            self.inner.index,
+            self.inner.claim.exit_code,
+            self.inner.seal_size()
+        )
+    }
+}
+
+/// Execute a guest and split the run into independently provable segments
+///
+/// The first step of distributed proving: prove each segment anywhere with
+/// prove_segment(), lift() each SegmentReceipt, join() them in index order,
+/// and call into_receipt() on the result with the session's journal.
+///
+/// Args:
+///     image: The Image containing the RISC-V ELF
+///     input_bytes: Input data for the guest program (see prove())
+///     config: Optional ProverConfig; its segment_po2 sets the segment size
+///     max_cycles: Optional session limit in cycles (see prove())
+///
+/// Returns:
+///     Tuple[List[Segment], SessionInfo]: The segments in order, and the
+///     session summary (its journal is needed to finish the receipt)
+///
+/// Example:
+///     segments, info = pyr0.execute_to_segments(image, data)
+///     lifted = [pyr0.lift(pyr0.prove_segment(s)) for s in segments]
+///     joined = functools.reduce(pyr0.join, lifted)
+///     receipt = joined.into_receipt(info.journal)
+#[pyfunction]
+#[pyo3(signature = (image, input_bytes, config=None, max_cycles=None))]
+pub fn execute_to_segments(
+    py: Python<'_>,
+    image: &Image,
+    input_bytes: &Bound<'_, PyAny>,
+    config: Option<ProverConfig>,
+    max_cycles: Option<u64>,
+) -> PyResult<(Vec<Segment>, SessionInfo)> {
+    let mut input = GuestInput::extract(input_bytes)?;
+    let memory_image = image.get_image();
+
+    let (segments, info) = py.allow_threads(|| -> anyhow::Result<_> {
+        let mut builder = ExecutorEnv::builder();
+        input.attach(&mut builder);
+        if let Some(config) = &config {
+            config.apply_env(&mut builder)?;
+        }
+        let env = builder.session_limit(max_cycles).build()?;
+
+        let mut segments = Vec::new();
+        let session = risc0_zkvm::ExecutorImpl::new(env, memory_image)?.run_with_callback(|segment| {
+            segments.push(Segment { inner: segment });
+            Ok(Box::new(NullSegmentRef {}))
+        })?;
+        Ok((segments, SessionInfo::new(&session)?))
+    })
+        .map_err(|e| errors::prove_failure(py, e, max_cycles))?;
+
+    Ok((segments, info))
+}
+
+/// Prove a single segment
+///
+/// Args:
+///     segment: A Segment from execute_to_segments()
+///     config: Optional ProverConfig (its hashfn is used)
+///
+/// Returns:
+///     SegmentReceipt: Proof of the segment
+///
+/// Raises:
+///     ProvingError: If proving fails
+#[pyfunction]
+#[pyo3(signature = (segment, config=None))]
+pub fn prove_segment(py: Python<'_>, segment: &Segment, config: Option<ProverConfig>) -> PyResult<SegmentReceipt> {
+    let inner = &segment.inner;
+    crate::reservation::allow_threads(py, || prover_server(config.as_ref())?.prove_segment(&VerifierContext::default(), inner))
+        .map(SegmentReceipt::from_risc0)
+        .map_err(|e| errors::proving_error(format!("Segment proving failed: {e}")))
+}
+
+/// Lift a segment receipt into a SuccinctReceipt that join() can combine
+///
+/// Raises:
+///     ProvingError: If the recursion prover fails
+#[pyfunction]
+pub fn lift(py: Python<'_>, receipt: &SegmentReceipt) -> PyResult<SuccinctReceipt> {
+    let inner = &receipt.inner;
+    crate::reservation::allow_threads(py, || prover_server(None)?.lift(inner))
+        .map(|inner| SuccinctReceipt { inner })
+        .map_err(|e| errors::proving_error(format!("Lift failed: {e}")))
+}
+
+/// Join two SuccinctReceipts for consecutive segment ranges into one
+///
+/// a must end in the state b starts from, i.e. join them in index order.
+///
+/// Raises:
+///     ProvingError: If the ranges don't connect or the recursion prover fails
+#[pyfunction]
+pub fn join(py: Python<'_>, a: &SuccinctReceipt, b: &SuccinctReceipt) -> PyResult<SuccinctReceipt> {
+    let (a, b) = (&a.inner, &b.inner);
+    crate::reservation::allow_threads(py, || prover_server(None)?.join(a, b))
+        .map(|inner| SuccinctReceipt { inner })
+        .map_err(|e| errors::proving_error(format!("Join failed: {e}")))
+}
+
+/// Receipt that can discharge an assumption in resolve()
+#[derive(FromPyObject)]
+pub enum AssumptionReceipt {
+    Receipt(Receipt),
+    Keccak(KeccakReceipt),
+}
+
+/// Discharge one assumption (env::verify call) of a conditional receipt
+///
+/// Args:
+///     conditional: A SuccinctReceipt whose claim still carries assumptions
+///     assumption: A SUCCINCT Receipt proving the first of them, or a
+///                 KeccakReceipt for an accelerator assumption
+///
+/// Raises:
+///     AssumptionError: If assumption isn't a SUCCINCT receipt
+///     ProvingError: If it doesn't match or the recursion prover fails
+#[pyfunction]
+pub fn resolve(py: Python<'_>, conditional: &SuccinctReceipt, assumption: AssumptionReceipt) -> PyResult<SuccinctReceipt> {
+    let assumption = match assumption {
+        AssumptionReceipt::Receipt(receipt) => match receipt.inner.inner {
+            InnerReceipt::Succinct(succinct) => succinct.into_unknown(),
+            _ => return Err(errors::assumption_error("resolve() needs a SUCCINCT assumption receipt")),
+        },
+        AssumptionReceipt::Keccak(receipt) => receipt.inner,
+    };
+    let conditional = &conditional.inner;
+    crate::reservation::allow_threads(py, || prover_server(None)?.resolve(conditional, &assumption))
+        .map(|inner| SuccinctReceipt { inner })
+        .map_err(|e| errors::proving_error(format!("Resolve failed: {e}")))
+}
+
+/// Prove one batch of keccak accelerator requests
+///
+/// Takes a request dict as passed to a prove_with_opts(coprocessor=...)
+/// callback with "kind": "keccak", so a service can collect the requests
+/// and prove them elsewhere. union() combines the results; resolve()
+/// discharges the receipt's matching assumption with them.
+///
+/// Args:
+///     request: Dict with "claim_digest" and "control_root" (32 bytes
+///              each), "po2" and "input" (keccak states, 25 little-endian
+///              u64s each)
+///     config: Optional ProverConfig (its hashfn is used)
+///
+/// Returns:
+///     KeccakReceipt: Proof of the request's claim
+///
+/// Raises:
+///     ValueError: If the request is malformed, or PyR0 was built without
+///                 the 'unstable' feature
+///     ProvingError: If the keccak prover fails
+#[pyfunction]
+#[pyo3(signature = (request, config=None))]
+pub fn prove_keccak(
+    py: Python<'_>,
+    request: &Bound<'_, PyDict>,
+    config: Option<ProverConfig>,
+) -> PyResult<KeccakReceipt> {
+    #[cfg(not(feature = "unstable"))]
+    {
+        let _ = (py, request, config);
+        Err(crate::coprocessor::unsupported())
+    }
+    #[cfg(feature = "unstable")]
+    {
+        let request = keccak_request(request)?;
+        crate::reservation::allow_threads(py, || prover_server(config.as_ref())?.prove_keccak(&request))
+            .map(|inner| KeccakReceipt { inner })
+            .map_err(|e| errors::proving_error(format!("Keccak proving failed: {e}")))
+    }
+}
+
+/// Parse a coprocessor callback's keccak request dict
+#[cfg(feature = "unstable")]
+fn keccak_request(request: &Bound<'_, PyDict>) -> PyResult<risc0_zkvm::ProveKeccakRequest> {
+    let item = |key: &str| -> PyResult<Bound<'_, PyAny>> {
+        request.get_item(key)?
+            .ok_or_else(|| PyErr::new::<PyValueError, _>(format!("Keccak request has no '{key}'")))
+    };
+    let digest = |key: &str| -> PyResult<risc0_zkvm::sha::Digest> {
+        let bytes: Vec<u8> = item(key)?.extract()?;
+        risc0_zkvm::sha::Digest::try_from(bytes.as_slice())
+            .map_err(|_| PyErr::new::<PyValueError, _>(format!("'{key}' must be 32 bytes, got {}", bytes.len())))
+    };
+
+    const STATE_BYTES: usize = 25 * 8;
+    let input: Vec<u8> = item("input")?.extract()?;
+    if input.len() % STATE_BYTES != 0 {
+        return Err(PyErr::new::<PyValueError, _>(format!(
+            "'input' must be a whole number of {STATE_BYTES}-byte keccak states, got {} bytes",
+            input.len()
+        )));
+    }
+    let input = input
+        .chunks_exact(STATE_BYTES)
+        .map(|state| {
+            let mut words = [0u64; 25];
+            for (word, bytes) in words.iter_mut().zip(state.chunks_exact(8)) {
+                *word = u64::from_le_bytes(bytes.try_into().expect("8-byte chunk"));
+            }
+            words
+        })
+        .collect();
+
+    Ok(risc0_zkvm::ProveKeccakRequest {
+        claim_digest: digest("claim_digest")?,
+        po2: item("po2")?.extract()?,
+        control_root: digest("control_root")?,
+        input,
+    })
+}
+
+/// Combine two accelerator proofs into one receipt proving both claims
+///
+/// Union receipts nest, so any number of keccak proofs fold into one with
+/// functools.reduce(pyr0.union, receipts).
+///
+/// Raises:
+///     ProvingError: If the recursion prover fails
+#[pyfunction]
+pub fn union(py: Python<'_>, a: &KeccakReceipt, b: &KeccakReceipt) -> PyResult<KeccakReceipt> {
+    let (a, b) = (&a.inner, &b.inner);
+    crate::reservation::allow_threads(py, || prover_server(None)?.union(a, b))
+        .map(|inner| KeccakReceipt { inner: inner.into_unknown() })
+        .map_err(|e| errors::proving_error(format!("Union failed: {e}")))
+}
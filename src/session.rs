@@ -2,6 +2,8 @@ use anyhow::Result;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use risc0_zkvm::sha::Digestible;
+use crate::receipt::ExitStatus;
 
 #[pyclass(module = "pyr0")]
 #[derive(Clone, Serialize, Deserialize)]
@@ -79,9 +81,33 @@ impl ExitCode {
 pub struct SessionInfo {
     journal: Option<Vec<u8>>,
     exit_code: ExitCode,
+    pub(crate) segment_count: usize,
+    pub(crate) user_cycles: u64,
+    pub(crate) total_cycles: u64,
+    /// Digest of the SystemState execution stopped in
+    #[serde(default)]
+    pub(crate) post_state_digest: Vec<u8>,
+    stdout: Option<Vec<u8>>,
+    stderr: Option<Vec<u8>>,
+    /// Handle of the paused execution held for resume(), if any
+    #[serde(skip)]
+    pub(crate) pause_handle: Option<u64>,
 }
 
 impl SessionInfo {
+    /// Attach guest output captured by dry_run(capture_output=True)
+    pub(crate) fn with_output(mut self, stdout: Vec<u8>, stderr: Vec<u8>) -> Self {
+        self.stdout = Some(stdout);
+        self.stderr = Some(stderr);
+        self
+    }
+
+    /// Attach the handle resume() can continue this paused execution with
+    pub(crate) fn with_pause_handle(mut self, handle: Option<u64>) -> Self {
+        self.pause_handle = handle;
+        self
+    }
+
     pub fn new(session: &risc0_zkvm::Session) -> Result<Self> {
         let journal = match &session.journal {
             Some(v) => v.bytes.clone(),
@@ -90,6 +116,13 @@ impl SessionInfo {
         Ok(Self {
             journal: Some(journal),
             exit_code: ExitCode::new(session.exit_code),
+            segment_count: session.segments.len(),
+            user_cycles: session.user_cycles,
+            total_cycles: session.total_cycles,
+            post_state_digest: session.claim()?.post.digest().as_bytes().to_vec(),
+            stdout: None,
+            stderr: None,
+            pause_handle: None,
         })
     }
 }
@@ -102,6 +135,13 @@ impl SessionInfo {
         Self {
             journal: None,
             exit_code: ExitCode::new_init(),
+            segment_count: 0,
+            user_cycles: 0,
+            total_cycles: 0,
+            post_state_digest: Vec::new(),
+            stdout: None,
+            stderr: None,
+            pause_handle: None,
         }
     }
 
@@ -115,4 +155,212 @@ impl SessionInfo {
         Ok(self.exit_code.clone())
     }
 
+    /// Number of segments the execution was split into
+    #[getter]
+    pub fn segment_count(&self) -> usize {
+        self.segment_count
+    }
+
+    /// Cycles spent executing guest instructions
+    #[getter]
+    pub fn user_cycles(&self) -> u64 {
+        self.user_cycles
+    }
+
+    /// Total cycles including paging and padding overhead
+    #[getter]
+    pub fn total_cycles(&self) -> u64 {
+        self.total_cycles
+    }
+
+    /// Digest of the SystemState execution stopped in (32 bytes)
+    ///
+    /// For a PAUSED run this is where resume() continues from, and the
+    /// pre_state_digest of the continuation's receipt.
+    #[getter]
+    pub fn post_state_digest(&self) -> Vec<u8> {
+        self.post_state_digest.clone()
+    }
+
+    /// Handle of the paused execution resume() continues, or None
+    ///
+    /// Only set for a PAUSED run started with pausable=True, until it is
+    /// resumed, expires or is discarded. Not kept when pickled.
+    #[getter]
+    pub fn pause_handle(&self) -> Option<u64> {
+        self.pause_handle
+    }
+
+    /// Bytes the guest wrote to stdout, or None if output wasn't captured
+    ///
+    /// Only populated by dry_run(..., capture_output=True).
+    #[getter]
+    pub fn stdout(&self) -> Option<Vec<u8>> {
+        self.stdout.clone()
+    }
+
+    /// Bytes the guest wrote to stderr, or None if output wasn't captured
+    #[getter]
+    pub fn stderr(&self) -> Option<Vec<u8>> {
+        self.stderr.clone()
+    }
+
+    /// Structured exit status, including where execution stopped
+    #[getter]
+    pub fn exit(&self) -> PyResult<ExitStatus> {
+        let exit_code = self.exit_code.exit_code
+            .ok_or_else(|| PyValueError::new_err("SessionInfo has no exit code"))?;
+        let mut status = ExitStatus::from_risc0(exit_code);
+        status.segment_index = Some(self.segment_count.saturating_sub(1) as u32);
+        status.cycle_count = Some(self.total_cycles);
+        Ok(status)
+    }
+
+    /// Details of a system split or session limit, or None if execution
+    /// ended normally (halted or paused)
+    ///
+    /// The returned ExitStatus carries the segment index and cycle count
+    /// reached, which is how far execution got before the limit.
+    #[getter]
+    pub fn limit_hit(&self) -> PyResult<Option<ExitStatus>> {
+        let status = self.exit()?;
+        Ok(if status.is_limit() { Some(status) } else { None })
+    }
+
+}
+
+/// Differences between two executions, from compare_sessions()
+#[pyclass(module = "pyr0", frozen)]
+#[derive(Clone)]
+pub struct SessionDiff {
+    /// b.user_cycles - a.user_cycles
+    #[pyo3(get)]
+    pub user_cycles_delta: i64,
+    /// b.total_cycles - a.total_cycles
+    #[pyo3(get)]
+    pub total_cycles_delta: i64,
+    /// b.segment_count - a.segment_count
+    #[pyo3(get)]
+    pub segment_count_delta: i64,
+    /// b.user_cycles / a.user_cycles, or None if a ran no cycles
+    #[pyo3(get)]
+    pub user_cycles_ratio: Option<f64>,
+    /// Offset of the first journal byte that differs (the shorter journal's
+    /// length if one is a prefix of the other), or None if they're equal
+    #[pyo3(get)]
+    pub journal_diff_offset: Option<usize>,
+    /// True if the two runs stopped differently (kind or exit code)
+    #[pyo3(get)]
+    pub exit_changed: bool,
+    a: SessionInfo,
+    b: SessionInfo,
+}
+
+#[pymethods]
+impl SessionDiff {
+    /// True if the journals differ
+    #[getter]
+    pub fn journal_changed(&self) -> bool {
+        self.journal_diff_offset.is_some()
+    }
+
+    /// True if the guests produced different results (journal or exit)
+    ///
+    /// Cycle and segment changes alone don't count: those are expected when
+    /// a guest is optimized or the toolchain changes.
+    #[getter]
+    pub fn output_changed(&self) -> bool {
+        self.journal_changed() || self.exit_changed
+    }
+
+    /// Human-readable report, one line per metric
+    pub fn summary(&self) -> PyResult<String> {
+        fn line(name: &str, a: u64, b: u64) -> String {
+            let change = match a {
+                0 => String::new(),
+                _ => format!(" ({:+.1}%)", (b as f64 - a as f64) * 100.0 / a as f64),
+            };
+            format!("{name}: {a} -> {b}{change}")
+        }
+        let journal = match self.journal_diff_offset {
+            None => "journal: unchanged".to_string(),
+            Some(offset) => format!(
+                "journal: differs at byte {offset} ({} -> {} bytes)",
+                self.a.journal.as_deref().unwrap_or_default().len(),
+                self.b.journal.as_deref().unwrap_or_default().len()
+            ),
+        };
+        let exit = if self.exit_changed {
+            format!("exit: {} -> {}", describe_exit(&self.a)?, describe_exit(&self.b)?)
+        } else {
+            "exit: unchanged".to_string()
+        };
+        Ok([
+            line("user_cycles", self.a.user_cycles, self.b.user_cycles),
+            line("total_cycles", self.a.total_cycles, self.b.total_cycles),
+            line("segment_count", self.a.segment_count as u64, self.b.segment_count as u64),
+            journal,
+            exit,
+        ]
+        .join("\n"))
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SessionDiff(user_cycles_delta={:+}, total_cycles_delta={:+}, segment_count_delta={:+}, journal_changed={}, exit_changed={})",
+            self.user_cycles_delta,
+            self.total_cycles_delta,
+            self.segment_count_delta,
+            if self.journal_changed() { "True" } else { "False" },
+            if self.exit_changed { "True" } else { "False" },
+        )
+    }
+}
+
+/// "HALTED(0)"-style description of how a session stopped
+fn describe_exit(info: &SessionInfo) -> PyResult<String> {
+    let exit = info.exit()?;
+    Ok(match exit.user_code {
+        Some(code) => format!("{}({})", exit.kind.as_str(), code),
+        None => exit.kind.as_str().to_string(),
+    })
+}
+
+/// Compare two executions, e.g. an old and a new guest build on the same input
+///
+/// Quantifies cycle and segment changes and flags any change in the journal
+/// or exit status, so guest upgrades can be checked for performance and
+/// output regressions automatically.
+///
+/// Args:
+///     a: Baseline SessionInfo (from dry_run())
+///     b: SessionInfo to compare against the baseline
+///
+/// Returns:
+///     SessionDiff: Deltas are b - a
+///
+/// Example:
+///     diff = pyr0.compare_sessions(pyr0.dry_run(old, data), pyr0.dry_run(new, data))
+///     assert not diff.output_changed, diff.summary()
+#[pyfunction]
+pub fn compare_sessions(a: SessionInfo, b: SessionInfo) -> PyResult<SessionDiff> {
+    let delta = |a: u64, b: u64| b as i64 - a as i64;
+    let journal_a = a.journal.as_deref().unwrap_or_default();
+    let journal_b = b.journal.as_deref().unwrap_or_default();
+    let journal_diff_offset = journal_a
+        .iter()
+        .zip(journal_b)
+        .position(|(x, y)| x != y)
+        .or((journal_a.len() != journal_b.len()).then_some(journal_a.len().min(journal_b.len())));
+
+    Ok(SessionDiff {
+        user_cycles_delta: delta(a.user_cycles, b.user_cycles),
+        total_cycles_delta: delta(a.total_cycles, b.total_cycles),
+        segment_count_delta: delta(a.segment_count as u64, b.segment_count as u64),
+        user_cycles_ratio: (a.user_cycles != 0).then_some(b.user_cycles as f64 / a.user_cycles as f64),
+        journal_diff_offset,
+        exit_changed: a.exit_code.exit_code != b.exit_code.exit_code,
+        a,
+        b,
+    })
 }
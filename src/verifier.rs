@@ -0,0 +1,143 @@
+use pyo3::prelude::*;
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::{Groth16ReceiptVerifierParameters, SegmentReceiptVerifierParameters, SuccinctReceiptVerifierParameters};
+
+use crate::receipt::digest_from_bytes;
+
+/// Verifier parameters to check receipts against, instead of this build's defaults
+///
+/// A receipt only verifies under the recursion circuit and control IDs its
+/// prover used. By default those are the ones compiled into PyR0's
+/// risc0-zkvm; receipts from a different risc0 release, or from a custom
+/// recursion program, need theirs given explicitly. Anything left as None
+/// keeps the default.
+///
+/// Args:
+///     control_root: Root of the allowed recursion control IDs, for
+///                   succinct and groth16 receipts (32 bytes)
+///     inner_control_root: Control root expected in a succinct receipt's
+///                         output, when it differs from control_root (e.g.
+///                         after switching hash functions)
+///     bn254_control_id: Control ID of the identity program over BN254, for
+///                       groth16 receipts (32 bytes)
+///     control_ids: Allowed rv32im circuit control IDs, for the segment
+///                  receipts inside composite receipts
+///
+/// Example:
+///     ctx = pyr0.VerifierContext(control_root=bytes.fromhex(remote_root))
+///     receipt.verify(image_id, context=ctx)
+#[pyclass(module = "pyr0", frozen)]
+#[derive(Clone, Debug, Default)]
+pub struct VerifierContext {
+    control_root: Option<Digest>,
+    inner_control_root: Option<Digest>,
+    bn254_control_id: Option<Digest>,
+    control_ids: Option<Vec<Digest>>,
+}
+
+#[pymethods]
+impl VerifierContext {
+    /// Raises:
+    ///     ValueError: If a digest isn't 32 bytes
+    #[new]
+    #[pyo3(signature = (control_root=None, inner_control_root=None, bn254_control_id=None, control_ids=None))]
+    pub fn new(
+        control_root: Option<Vec<u8>>,
+        inner_control_root: Option<Vec<u8>>,
+        bn254_control_id: Option<Vec<u8>>,
+        control_ids: Option<Vec<Vec<u8>>>,
+    ) -> PyResult<Self> {
+        let digest = |bytes: Option<Vec<u8>>| bytes.map(|b| digest_from_bytes(&b)).transpose();
+        Ok(Self {
+            control_root: digest(control_root)?,
+            inner_control_root: digest(inner_control_root)?,
+            bn254_control_id: digest(bn254_control_id)?,
+            control_ids: control_ids
+                .map(|ids| ids.iter().map(|id| digest_from_bytes(id)).collect::<PyResult<Vec<_>>>())
+                .transpose()?,
+        })
+    }
+
+    /// Control root for succinct and groth16 receipts (32 bytes)
+    #[getter]
+    pub fn control_root(&self) -> Vec<u8> {
+        self.control_root
+            .unwrap_or_else(|| SuccinctReceiptVerifierParameters::default().control_root)
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// Control root expected in succinct receipts' output, if it differs from control_root
+    #[getter]
+    pub fn inner_control_root(&self) -> Option<Vec<u8>> {
+        self.inner_control_root.map(|root| root.as_bytes().to_vec())
+    }
+
+    /// BN254 control ID for groth16 receipts (32 bytes)
+    #[getter]
+    pub fn bn254_control_id(&self) -> Vec<u8> {
+        self.bn254_control_id
+            .unwrap_or_else(|| Groth16ReceiptVerifierParameters::default().bn254_control_id)
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// Allowed segment control IDs (32 bytes each)
+    #[getter]
+    pub fn control_ids(&self) -> Vec<Vec<u8>> {
+        match &self.control_ids {
+            Some(ids) => ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+            None => SegmentReceiptVerifierParameters::default()
+                .control_ids
+                .iter()
+                .map(|id| id.as_bytes().to_vec())
+                .collect(),
+        }
+    }
+
+    pub fn __repr__(&self) -> String {
+        let overridden: Vec<&str> = [
+            ("control_root", self.control_root.is_some()),
+            ("inner_control_root", self.inner_control_root.is_some()),
+            ("bn254_control_id", self.bn254_control_id.is_some()),
+            ("control_ids", self.control_ids.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, set)| set.then_some(name))
+        .collect();
+        if overridden.is_empty() {
+            "VerifierContext(default)".to_string()
+        } else {
+            format!("VerifierContext(overrides={})", overridden.join(", "))
+        }
+    }
+}
+
+impl VerifierContext {
+    /// risc0's VerifierContext with these overrides applied
+    ///
+    /// It holds Rc'd hash suites, so build it on the thread that verifies.
+    pub(crate) fn build(&self) -> risc0_zkvm::VerifierContext {
+        let mut ctx = risc0_zkvm::VerifierContext::default();
+        if let Some(params) = ctx.succinct_verifier_parameters.as_mut() {
+            if let Some(root) = self.control_root {
+                params.control_root = root;
+            }
+            if self.inner_control_root.is_some() {
+                params.inner_control_root = self.inner_control_root;
+            }
+        }
+        if let Some(params) = ctx.groth16_verifier_parameters.as_mut() {
+            if let Some(root) = self.control_root {
+                params.control_root = root;
+            }
+            if let Some(id) = self.bn254_control_id {
+                params.bn254_control_id = id;
+            }
+        }
+        if let (Some(params), Some(ids)) = (ctx.segment_verifier_parameters.as_mut(), &self.control_ids) {
+            params.control_ids = ids.iter().copied().collect();
+        }
+        ctx
+    }
+}
@@ -8,6 +8,8 @@ import os
 import time
 from pathlib import Path
 import shutil
+import struct
+import tempfile
 
 def test_build_guest():
     """Test build_guest function."""
@@ -109,7 +111,34 @@ def test_build_guest():
         except Exception as e:
             print(f"   ✗ Auto-detection failed: {type(e).__name__}: {e}")
             test_passed = False
-        
+
+        # Test 6: Scaffold a guest from a template, build and prove it
+        print("\n6. Testing new_guest('raw') scaffolding...")
+        scaffold_root = Path(tempfile.mkdtemp())
+        try:
+            scaffold_dir = pyr0.new_guest("raw", scaffold_root / "raw_guest")
+            elf_path = pyr0.build_guest(scaffold_dir)
+            key = bytes(range(32))
+            input_data = pyr0.InputBuilder().write_u32(7).write_bytes32(key).build()
+            receipt = pyr0.prove(pyr0.load_image_from_path(elf_path), input_data)
+            if receipt.journal_bytes != struct.pack('<I', 7) + key:
+                print(f"   ✗ Scaffolded guest committed {receipt.journal_bytes.hex()}")
+                test_passed = False
+            else:
+                print(f"   ✓ Scaffolded guest reads the InputBuilder layout")
+
+            try:
+                pyr0.new_guest("raw", scaffold_dir)
+                print("   ✗ Scaffolding over an existing crate should fail")
+                test_passed = False
+            except pyr0.InvalidGuestDirectoryError:
+                print("   ✓ Existing crate is not overwritten")
+        except Exception as e:
+            print(f"   ✗ Scaffolded guest failed: {type(e).__name__}: {e}")
+            test_passed = False
+        finally:
+            shutil.rmtree(scaffold_root, ignore_errors=True)
+
         return test_passed
         
     except ImportError as e:
@@ -0,0 +1,173 @@
+#!/usr/bin/env python3
+"""
+Test pyr0.codec against RISC Zero's word-based serde format.
+
+These checks need no guest: the expected bytes are derived by hand from
+the format rules (every value widened to little-endian u32 words).
+"""
+
+import hashlib
+import struct
+import sys
+
+import pyr0
+from pyr0 import codec, serialization
+
+
+def words(*ws):
+    return b"".join(struct.pack('<I', w) for w in ws)
+
+
+def test_codec():
+    print("Testing pyr0.codec")
+    print("=" * 60)
+    test_passed = True
+    
+    cases = [
+        ("u8", [("u8", 42)], words(42)),
+        ("u32", [("u32", 0xDEADBEEF)], words(0xDEADBEEF)),
+        ("u64 low word first", [("u64", 0x1_0000_0002)], words(2, 1)),
+        ("i32 negative", [("i32", -1)], words(0xFFFFFFFF)),
+        ("i8 sign-extended", [("i8", -2)], words(0xFFFFFFFE)),
+        ("bool untagged", [True, False], words(1, 0)),
+        ("String padded", ["abcde"], words(5) + b"abcd" + b"e\x00\x00\x00"),
+        ("Vec<u8> one word per byte", [b"AB"], words(2, 0x41, 0x42)),
+        ("[u8; 3] no length", [("[u8; 3]", b"\x01\x02\x03")], words(1, 2, 3)),
+    ]
+    
+    print("\n1. Encoding primitives...")
+    for name, values, expected in cases:
+        got = codec.commit_encode(values)
+        if got != expected:
+            print(f"   ✗ {name}: got {got.hex()}, expected {expected.hex()}")
+            test_passed = False
+        else:
+            print(f"   ✓ {name}")
+    
+    print("\n2. Vec<u8> matches serialization.to_vec_u8...")
+    if codec.commit_encode([b"hello"]) != serialization.to_vec_u8(b"hello"):
+        print("   ✗ commit_encode(bytes) differs from to_vec_u8")
+        test_passed = False
+    else:
+        print("   ✓ Formats agree")
+    
+    print("\n3. Round-trip decoding...")
+    fmt = ["u8", "u32", "u64", "i64", "bool", "str", "bytes", "[u8; 2]"]
+    values = [7, 123456, 2**40 + 5, -9, True, "héllo", b"\x00\xff", b"\x10\x20"]
+    encoded = codec.commit_encode(list(zip(fmt, values)))
+    decoded = codec.commit_decode(encoded, fmt)
+    if decoded != values:
+        print(f"   ✗ Round trip mismatch: {decoded} != {values}")
+        test_passed = False
+    else:
+        print("   ✓ All values round-trip")
+    
+    print("\n4. Error handling...")
+    try:
+        codec.commit_encode([5])
+        print("   ✗ Untagged int should be rejected")
+        test_passed = False
+    except TypeError:
+        print("   ✓ Untagged int rejected")
+    
+    try:
+        codec.commit_encode([("u8", 300)])
+        print("   ✗ Out-of-range u8 should be rejected")
+        test_passed = False
+    except (OverflowError, ValueError):
+        print("   ✓ Out-of-range u8 rejected")
+    
+    try:
+        codec.commit_decode(words(1, 2), ["u32"])
+        print("   ✗ Trailing bytes should be rejected in strict mode")
+        test_passed = False
+    except ValueError:
+        print("   ✓ Trailing bytes rejected")
+    
+    if codec.commit_decode(words(1, 2), ["u32"], strict=False) != [1]:
+        print("   ✗ Non-strict decode should ignore trailing bytes")
+        test_passed = False
+    else:
+        print("   ✓ Non-strict decode ignores trailing bytes")
+    
+    padded = codec.pad_journal_to(words(1), 16)
+    if padded != words(1, 0, 0, 0) or codec.commit_decode(padded, ["u32"], padded=True) != [1]:
+        print("   ✗ Padded journal should round-trip")
+        test_passed = False
+    else:
+        print("   ✓ Constant-size journal padded and decoded")
+    
+    try:
+        codec.commit_decode(words(1, 2), ["u32"], padded=True)
+        print("   ✗ Non-zero padding should be rejected")
+        test_passed = False
+    except ValueError:
+        print("   ✓ Non-zero padding rejected")
+    
+    try:
+        codec.pad_journal_to(words(1, 2), 4)
+        print("   ✗ Journal longer than the padded size should be rejected")
+        test_passed = False
+    except ValueError:
+        print("   ✓ Oversized journal rejected")
+    
+    try:
+        codec.commit_decode(words(0xFFFFFFFF), ["str"])
+        print("   ✗ String length past the end of data should be rejected")
+        test_passed = False
+    except ValueError:
+        print("   ✓ Oversized string length rejected before allocating")
+    
+    hasher = codec.JournalHasher()
+    hasher.commit([("u32", 7)])
+    hasher.commit_slice(b"\x01\x02")
+    hasher.commit_slice(b"\x03\x04")
+    journal = words(7) + bytes([1, 2, 3, 4])
+    if hasher.digest() != hashlib.sha256(journal).digest() or hasher.size != len(journal):
+        print("   ✗ JournalHasher digest doesn't match the buffered journal")
+        test_passed = False
+    else:
+        print("   ✓ JournalHasher matches the buffered journal digest")
+
+    print("\n5. Poseidon2 over BabyBear...")
+    # Golden vector from risc0-zkp's hash_elem_slice_compare_golden_unaligned;
+    # digest words are stored in Montgomery form (x * 2^32 mod P)
+    P = 2013265921
+    inputs = [943718400, 1887436800, 2013125296, 1761607679, 692060158, 1635778558,
+              566231037, 1509949437, 440401916, 1384120316, 314572795, 1258291195,
+              188743674, 1132462074, 62914553, 1006632953, 1950351353]
+    goal = [0x622615d7, 0x1cfe9764, 0x166cb1c9, 0x76febcde,
+            0x6056219f, 0x326359cf, 0x5c2cca75, 0x233dc3ff]
+    expected = words(*[(x << 32) % P for x in goal])
+    if pyr0.poseidon2_hash(inputs) != expected:
+        print("   ✗ poseidon2_hash doesn't match risc0's golden digest")
+        test_passed = False
+    else:
+        print("   ✓ poseidon2_hash matches risc0's golden digest")
+
+    try:
+        pyr0.poseidon2_hash([P])
+        print("   ✗ Non-canonical element should be rejected")
+        test_passed = False
+    except ValueError:
+        print("   ✓ Non-canonical element rejected")
+
+    return test_passed
+
+
+if __name__ == "__main__":
+    try:
+        passed = test_codec()
+    except Exception as e:
+        print(f"\n✗ Test failed with error: {e}")
+        import traceback
+        traceback.print_exc()
+        passed = False
+    
+    print("\n" + "=" * 60)
+    if passed:
+        print("✅ All codec tests passed!")
+        sys.exit(0)
+    else:
+        print("✗ Some codec tests failed")
+        sys.exit(1)
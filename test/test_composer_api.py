@@ -4,6 +4,7 @@ Test the new Composer API for proof composition.
 This demonstrates the correct way to do composition in PyR0 v0.7.0.
 """
 
+import json
 import pyr0
 import struct
 import sys
@@ -43,6 +44,10 @@ def test_composer_api():
     # Extract the sum from the journal
     sum_value = struct.unpack('<I', claim.journal[:4])[0]
     print(f"Inner computation: {a} + {b} = {sum_value}")
+    if inner_receipt.journal_decode("u32") != sum_value or inner_receipt.journal_decode(["u32"]) != [sum_value]:
+        print("❌ journal_decode doesn't match the committed sum")
+        return False
+    print("✅ journal_decode('u32') decodes env::commit(&sum)")
     
     # 4. Use the new Composer API for the outer proof
     print("\n4. Using Composer for outer proof...")
@@ -56,10 +61,89 @@ def test_composer_api():
     comp.write_u32(sum_value)              # Expected sum (4 bytes)
     comp.write_image_id(inner_image.id)    # Inner image ID (32 bytes)
     print(f"Input buffer size: {comp.input_size} bytes")
+    if comp.input_bytes != struct.pack('<I', sum_value) + inner_image.id:
+        print("❌ Composer.input_bytes doesn't match the writes")
+        return False
+    dump = comp.input_hexdump().splitlines()
+    if len(dump) != 3 or not dump[0].startswith("00000000  ") or "field0 u32 @0, field1 bytes32 @4" not in dump[0]:
+        print(f"❌ Unexpected input_hexdump():\n" + "\n".join(dump))
+        return False
+    print(f"✅ input_hexdump():\n   " + "\n   ".join(dump))
     
     # Register what we expect the guest to verify (for preflight check)
     comp.expect_verification(inner_image.id, claim.journal)
     
+    # Fingerprint is deterministic and sensitive to kind
+    twin = pyr0.Composer(outer_image)
+    twin.assume(inner_receipt)
+    twin.write_u32(sum_value)
+    twin.write_image_id(inner_image.id)
+    if comp.fingerprint() != twin.fingerprint():
+        print("❌ Identical compositions produced different fingerprints")
+        return False
+    if comp.fingerprint() == comp.fingerprint(kind=pyr0.ReceiptKind.COMPOSITE):
+        print("❌ Fingerprint ignores receipt kind")
+        return False
+    print(f"✅ Fingerprint stable: {comp.fingerprint().hex()[:16]}...")
+
+    # A registered template rebuilds the same composition from its variable fields
+    from pyr0.templates import ComposerTemplate, register_template, get_template
+    register_template(ComposerTemplate(
+        "outer-sum",
+        outer_image,
+        fields=[("sum", "u32"), ("inner_id", "image_id")],
+        assumptions=[inner_receipt],
+        expected_verifications=[(inner_image.id, claim.journal)],
+    ), replace=True)
+    templated = get_template("outer-sum").instantiate(sum=sum_value, inner_id=inner_image.id)
+    if templated.fingerprint() != comp.fingerprint() or templated.preflight_check(raise_on_error=False):
+        print("❌ Template instance differs from the hand-built Composer")
+        return False
+    for bad in ({"sum": sum_value}, {"sum": -1, "inner_id": inner_image.id},
+                {"sum": sum_value, "inner_id": inner_image.id, "extra": 1}):
+        try:
+            get_template("outer-sum").instantiate(**bad)
+            print(f"❌ Template accepted {sorted(bad)}")
+            return False
+        except ValueError:
+            pass
+    print("✅ ComposerTemplate instances match the hand-built Composer and validate fields")
+
+    # expect_claim drives preflight the same way expect_verification does
+    twin.expect_claim(pyr0.Claim.for_success(inner_image.id, claim.journal))
+    if twin.preflight_check(raise_on_error=False):
+        print("❌ expect_claim(Claim.for_success(...)) should satisfy preflight")
+        return False
+    strict = pyr0.Composer(outer_image)
+    strict.assume(inner_receipt)
+    strict.expect_claim(pyr0.Claim.for_exit(inner_image.id, claim.journal, 1))
+    if not strict.preflight_check(raise_on_error=False):
+        print("❌ Preflight should report the exit-code mismatch")
+        return False
+    print("✅ Composer.expect_claim works with preflight")
+
+    # Executing preflight sees what the guest actually verifies
+    if comp.preflight_check(raise_on_error=False, execute=True):
+        print("❌ preflight_check(execute=True) flagged a correct composition")
+        return False
+    wrong_input = pyr0.Composer(outer_image)
+    wrong_input.assume(inner_receipt)
+    wrong_input.write_u32(sum_value + 1)
+    wrong_input.write_image_id(inner_image.id)
+    wrong_input.expect_verification(inner_image.id, claim.journal)
+    if wrong_input.preflight_check(raise_on_error=False):
+        print("❌ Static preflight should not see the input mistake")
+        return False
+    try:
+        wrong_input.preflight_check(execute=True)
+        print("❌ preflight_check(execute=True) missed the guest verifying another journal")
+        return False
+    except pyr0.PreflightError as e:
+        if not any("env::verify()" in issue for issue in e.issues):
+            print(f"❌ Unexpected executing preflight issues: {e.issues}")
+            return False
+        print(f"✅ preflight_check(execute=True) catches it:\n   {e.issues[0]}")
+    
     # Run preflight checks (will raise by default if issues found)
     try:
         issues = comp.preflight_check(raise_on_error=False)
@@ -73,9 +157,81 @@ def test_composer_api():
         print(f"❌ Preflight failed: {e}")
         return False
     
+    # Journal expectations are checked on every execution of the composition
+    checked = pyr0.Composer(outer_image)
+    checked.assume(inner_receipt)
+    checked.write_u32(sum_value)
+    checked.write_image_id(inner_image.id)
+    checked.assert_journal(prefix=struct.pack('<I', sum_value * 2))
+    checked.dry_run(preflight=False)
+    checked.assert_journal(schema=["u32"], values=[sum_value], strict=False)
+    try:
+        checked.dry_run(preflight=False)
+        print("❌ assert_journal() should reject the wrong value")
+        return False
+    except pyr0.JournalAssertionError as e:
+        if len(e.mismatches) != 1 or "expected" not in e.mismatches[0] or e.receipt is not None:
+            print(f"❌ Unexpected JournalAssertionError details: {e.mismatches}")
+            return False
+        print(f"✅ assert_journal() reports the diff:\n   {e.mismatches[0]}")
+    
+    sized = pyr0.Composer(outer_image)
+    sized.assume(inner_receipt)
+    sized.write_u32(sum_value)
+    sized.write_image_id(inner_image.id)
+    sized.assert_journal(size=8)
+    try:
+        sized.dry_run(preflight=False)
+        print("❌ assert_journal(size=...) should reject a 4-byte journal")
+        return False
+    except pyr0.JournalAssertionError as e:
+        print(f"✅ Variable-size journal rejected:\n   {e.mismatches[0]}")
+    
+    # Executing the composition first catches input/verify mistakes cheaply
+    info = comp.dry_run()
+    if info.exit.kind != pyr0.ExitKind.HALTED or info.total_cycles == 0:
+        print(f"❌ Composer.dry_run() did not halt cleanly: {info.exit}")
+        return False
+    print(f"✅ Composer.dry_run(): {info.total_cycles} cycles")
+    
+    # The outer guest's manifest declares the input layout prove() checks against
+    outer_manifest = pyr0.GuestManifest.from_toml(f"""
+        name = "test_outer_guest"
+        image_id = "{outer_image.id_hex}"
+        input = [
+            {{ name = "sum", type = "u32" }},
+            {{ name = "inner_id", type = "image_id" }},
+        ]
+        journal = [
+            {{ name = "doubled", type = "serde:u32" }},
+        ]
+    """)
+    drifted = pyr0.Composer(outer_image)
+    drifted.assume(inner_receipt)
+    drifted.write_u64(sum_value)
+    drifted.write_raw_bytes(inner_image.id[:28])
+    try:
+        drifted.prove(preflight=False, manifest=outer_manifest)
+        print("❌ prove(manifest=...) accepted a u64 where the manifest declares a u32")
+        return False
+    except pyr0.PreflightError as e:
+        if not any("write_u64()" in issue for issue in e.issues):
+            print(f"❌ Manifest mismatch not reported per write: {e.issues}")
+            return False
+    try:
+        pyr0.prove(outer_image, struct.pack('<I', sum_value), manifest=outer_manifest)
+        print("❌ pyr0.prove(manifest=...) accepted a truncated input")
+        return False
+    except pyr0.PreflightError:
+        pass
+    print("✅ GuestManifest rejects input that drifted from the declared layout")
+    
     # Generate the composed proof (defaults to succinct to resolve assumptions)
-    outer_receipt = comp.prove()  # Uses ReceiptKind.SUCCINCT by default
+    outer_receipt = comp.prove(manifest=outer_manifest)  # Uses ReceiptKind.SUCCINCT by default
     print(f"Outer proof generated (kind: {outer_receipt.kind})")
+    if info.journal != outer_receipt.journal_bytes:
+        print("❌ Composer.dry_run() journal doesn't match prove()")
+        return False
     print(f"Is unconditional: {outer_receipt.is_unconditional}")
     print(f"Assumptions resolved: {inner_receipt.assumption_count} -> {outer_receipt.assumption_count}")
     
@@ -86,11 +242,157 @@ def test_composer_api():
     # Extract result from outer journal
     result = struct.unpack('<I', outer_claim.journal[:4])[0]
     print(f"Outer computation: {sum_value} * 2 = {result}")
+    if outer_receipt.decode_journal(outer_manifest) != {"doubled": result}:
+        print(f"❌ decode_journal() gave {outer_receipt.decode_journal(outer_manifest)}")
+        return False
+    try:
+        inner_receipt.decode_journal(outer_manifest)
+        print("❌ decode_journal() accepted a receipt for a different image")
+        return False
+    except ValueError:
+        pass
+    import cbor2
+    sectioned = pyr0.GuestManifest(journal=[("count", "u32"), ("tag", "str_frame"), ("meta", "cbor")])
+    journal = struct.pack('<IQ', 7, 2) + b"ok" + cbor2.dumps({"k": [1, b"x"]})
+    if sectioned.decode_journal(journal) != {"count": 7, "tag": "ok", "meta": {"k": [1, b"x"]}}:
+        print(f"❌ GuestManifest.decode_journal() gave {sectioned.decode_journal(journal)}")
+        return False
+    print("✅ decode_journal() names the journal's fields from the manifest")
     
     # Verify the proof using the unified verify method
     outer_receipt.verify(outer_image)  # Can pass Image, bytes, or hex
     print("✅ Composed proof verified!")
+
+    # Awaitable proving gives the same journal
+    import asyncio
+    async def prove_twin():
+        return await twin.prove_async(kind="composite", preflight=False)
+    async_receipt = asyncio.run(prove_twin())
+    if async_receipt.journal_bytes != outer_receipt.journal_bytes:
+        print("❌ Composer.prove_async journal doesn't match prove()")
+        return False
+    print(f"✅ Composer.prove_async works (kind: {async_receipt.kind})")
+
+    # A shared sub-composition is resolved once and reused by every Composer
+    shared = pyr0.SharedAssumption(inner_receipt)
+    batch = []
+    for _ in range(2):
+        c = pyr0.Composer(outer_image)
+        c.assume_shared(shared)
+        c.assume(inner_receipt)  # same claim - deduplicated
+        c.write_u32(sum_value)
+        c.write_image_id(inner_image.id)
+        batch.append(c)
+    if batch[0].assumption_count != 1 or batch[0].fingerprint() != comp.fingerprint():
+        print("❌ assume_shared() should match assume() for the same receipt")
+        return False
+    if not shared.is_resolved:
+        print("❌ A succinct receipt should need no further resolution")
+        return False
+    shared_receipts = [c.prove(kind="composite", preflight=False) for c in batch]
+    if any(r.journal_bytes != outer_receipt.journal_bytes for r in shared_receipts):
+        print("❌ Composers sharing an assumption produced the wrong journal")
+        return False
+    print(f"✅ SharedAssumption reused across {len(batch)} Composers")
+
+    # A claim-only assumption defers the inner receipt until resolve()
+    import hashlib
+    deferred = pyr0.Composer(outer_image)
+    deferred.assume_claim(inner_image.id, hashlib.sha256(inner_receipt.journal_bytes).digest())
+    deferred.write_u32(sum_value)
+    deferred.write_image_id(inner_image.id)
+    deferred.expect_verification(inner_image.id, inner_receipt.journal_bytes)
+    if deferred.fingerprint() != comp.fingerprint():
+        print("❌ assume_claim() should fingerprint like assume() of the same claim")
+        return False
+    try:
+        deferred.prove()
+        print("❌ prove() should refuse claim-only assumptions")
+        return False
+    except pyr0.CompositionError:
+        pass
+    conditional, journal = deferred.prove_conditional()
+    resolved = pyr0.resolve(conditional, inner_receipt).into_receipt(journal)
+    resolved.verify(outer_image)
+    if resolved.journal_bytes != outer_receipt.journal_bytes:
+        print("❌ Resolved conditional receipt has the wrong journal")
+        return False
+    print("✅ assume_claim() + prove_conditional() resolved into a verified receipt")
     
+    # Receipt.resolve() finishes both conditional and composite receipts
+    late = conditional.into_receipt(journal).resolve([inner_receipt])
+    carried = async_receipt.resolve()
+    for r in (late, carried):
+        r.verify(outer_image)
+    if late.kind != pyr0.ReceiptKind.SUCCINCT or carried.kind != pyr0.ReceiptKind.SUCCINCT:
+        print("❌ Receipt.resolve() should produce SUCCINCT receipts")
+        return False
+    print("✅ Receipt.resolve() discharged late and carried assumptions")
+
+    # Standard (receipt, image_id, extra) layout read with three env::read() calls
+    layout = pyr0.Composer(outer_image)
+    layout.write_composition_input(inner_receipt, inner_image.id, b"xy")
+    expected = pyr0.codec.commit_encode([inner_receipt.to_bytes(), inner_image.id, b"xy"])
+    if layout.input_size != len(expected):
+        print(f"❌ write_composition_input wrote {layout.input_size} bytes, expected {len(expected)}")
+        return False
+    print(f"✅ write_composition_input: {layout.input_size} bytes")
+
+    # Unframed CBOR can't be mixed with other writes: the guest can't split it back out
+    mixed = pyr0.Composer(outer_image)
+    mixed.write_cbor(b"\x01")
+    try:
+        mixed.write_u32(1)
+        print("❌ write_cbor() followed by write_u32() should be rejected")
+        return False
+    except pyr0.InputPatternError as e:
+        if not isinstance(e, pyr0.CompositionError) or (e.method, e.conflicts_with) != ("write_u32", "write_cbor"):
+            print(f"❌ Unexpected InputPatternError details: {e.method}, {e.conflicts_with}")
+            return False
+    framed = pyr0.Composer(outer_image)
+    framed.write_cbor_frame(b"\x01")
+    framed.write_u32(1)
+    import warnings
+    with warnings.catch_warnings(record=True) as caught:
+        warnings.simplefilter("always")
+        lenient = pyr0.Composer(outer_image, input_mixing="warn")
+        lenient.write_u32(1)
+        lenient.write_cbor(b"\x01")
+    if not caught:
+        print("❌ input_mixing='warn' should warn instead of raising")
+        return False
+    try:
+        pyr0.Composer(outer_image, input_mixing="sometimes")
+        print("❌ Invalid input_mixing should be rejected")
+        return False
+    except ValueError:
+        pass
+    print("✅ Mixing write_cbor() with other writes is caught")
+
+    # Record/replay harness: the whole composition runs with fake receipts
+    import tempfile, os
+    from pyr0.testing import CompositionHarness
+    def run_pipeline(harness, a):
+        inner = harness.run("inner", inner_image, pyr0.serialization.to_u32(a) + pyr0.serialization.to_u32(b))
+        outer_input = pyr0.serialization.to_u32(a + b) + inner_image.id
+        return harness.run("outer", outer_image, outer_input, assumptions=[inner])
+    with tempfile.TemporaryDirectory() as tmp:
+        fixture = os.path.join(tmp, "composition.json")
+        with CompositionHarness(fixture) as recorder:
+            faked = run_pipeline(recorder, a)
+        if not recorder.record or faked.journal_bytes != outer_receipt.journal_bytes:
+            print("❌ CompositionHarness didn't record the real composition's journal")
+            return False
+        with CompositionHarness(fixture) as replay:
+            run_pipeline(replay, a)
+        try:
+            with CompositionHarness(fixture) as replay:
+                run_pipeline(replay, a + 1)
+            print("❌ CompositionHarness replay missed a changed journal")
+            return False
+        except pyr0.JournalAssertionError as e:
+            print(f"✅ CompositionHarness records and replays ({len(e.mismatches)} mismatch(es) caught)")
+
     # 6. Test new v0.7.0 features
     print("\n6. Testing v0.7.0 features:")
     print(f"Receipt kind enum: {pyr0.ReceiptKind.SUCCINCT}")
@@ -130,7 +432,65 @@ def test_claim_api():
     print("\n3. Testing claim matching...")
     matches = claim.matches(image.id, claim.journal)
     print(f"Claim matches expected values: {matches}")
-    
+
+    # Claim builders describe the same claim as the receipt
+    built = pyr0.Claim.for_success(image.id, claim.journal)
+    if built.journal_digest != claim.journal_digest or not built.is_success:
+        print("❌ Claim.for_success doesn't match the receipt's claim")
+        return False
+    if pyr0.Claim.for_exit(image.id, claim.journal, 3).exit_code != 3:
+        print("❌ Claim.for_exit ignored the exit code")
+        return False
+    print("✅ Claim.for_success / Claim.for_exit work")
+
+    # Claims compare by their canonical digest
+    other_exit = pyr0.Claim.for_exit(image.id, claim.journal, 3)
+    if len(built.digest()) != 32 or built.digest() != claim.digest() or built != claim:
+        print("❌ Claim.for_success digest differs from the receipt's claim digest")
+        return False
+    if other_exit == claim or len({built, claim, other_exit}) != 2:
+        print("❌ Claims with different exit codes compare or hash equal")
+        return False
+    print("✅ Claim.digest() / == / hash agree with the receipt's claim")
+
+    # Every part of the receipt's claim reassembles into the same claim
+    rebuilt = pyr0.Claim.from_parts(
+        claim.pre_state_digest,
+        claim.journal,
+        exit_kind=claim.exit.kind,
+        exit_code=claim.exit.user_code,
+        post_state_digest=claim.post_state_digest,
+        input_digest=claim.input_digest,
+        output_digest=claim.output_digest,
+    )
+    paused = pyr0.Claim.from_parts(image.id, claim.journal, exit_kind=pyr0.ExitKind.PAUSED)
+    if rebuilt.digest() != claim.digest() or claim.output_digest != built.output_digest:
+        print("❌ Claim.from_parts() didn't reproduce the receipt's claim")
+        return False
+    if paused == built or paused.exit.kind != pyr0.ExitKind.PAUSED:
+        print("❌ Claim.from_parts() ignored exit_kind")
+        return False
+    paused_json, built_json = json.loads(paused.to_json()), json.loads(built.to_json())
+    if paused_json == built_json or paused_json["exit_kind"] != "PAUSED":
+        print("❌ Claim.to_json() doesn't distinguish PAUSED(0) from HALTED(0)")
+        return False
+    if (built_json["input_digest"] != built.input_digest.hex()
+            or built_json["output_digest"] != built.output_digest.hex()):
+        print("❌ Claim.to_json() doesn't carry the input and output digests")
+        return False
+    print("✅ Claim.from_parts() reconstructs the exact claim")
+
+    # Composite receipts can't be assumed; the error is catchable by class
+    try:
+        pyr0.Composer(image).assume(receipt)
+        print("❌ assume() accepted a composite receipt")
+        return False
+    except pyr0.AssumptionError as e:
+        if not all(isinstance(e, cls) for cls in (pyr0.CompositionError, pyr0.PyR0Error, ValueError)):
+            print(f"❌ AssumptionError has the wrong bases: {type(e).__mro__}")
+            return False
+        print("✅ assume(composite) raised AssumptionError")
+
     # Test string representations
     print("\n4. String representations:")
     print(f"repr: {repr(claim)}")
@@ -7,6 +7,7 @@ of Composer for general guest communication.
 """
 
 import pyr0
+from pyr0.codec import commit_encode
 import cbor2
 import struct
 from pathlib import Path
@@ -93,6 +94,48 @@ def test_input_builder_standalone():
             print(f"   ❌ Wrong size: expected {expected_size}, got {len(chained_data)}")
             return False
         
+        # Typed writes use the same little-endian layout as struct
+        typed = pyr0.InputBuilder()
+        typed.write_i32(-5).write_i64(-6).write_f32(1.5).write_f64(2.25).write_bool(True).write_str_frame("héllo")
+        expected_typed = struct.pack('<iqfd?', -5, -6, 1.5, 2.25, True)
+        expected_typed += struct.pack('<Q', len("héllo".encode())) + "héllo".encode()
+        if typed.build() != expected_typed:
+            print(f"   ❌ Typed writes produced {typed.build().hex()}, expected {expected_typed.hex()}")
+            return False
+        print(f"   ✓ Typed writes (i32/i64/f32/f64/bool/str frame) serialized correctly")
+        
+        # Serde writes match what env::read() expects, i.e. codec.commit_encode
+        values = [("u32", 7), ("u64", 2**40), b"ab", "héllo", True]
+        serde = pyr0.InputBuilder()
+        serde.write_serde(values).write_serde([("[u8; 2]", b"xy")])
+        expected_serde = commit_encode(values + [("[u8; 2]", b"xy")])
+        if serde.build() != expected_serde:
+            print(f"   ❌ write_serde produced {serde.build().hex()}, expected {expected_serde.hex()}")
+            return False
+        if serde.build()[4:12] != struct.pack('<II', 0, 2**40 >> 32):
+            print(f"   ❌ write_serde split u64 into the wrong words")
+            return False
+        print(f"   ✓ write_serde matches RISC Zero's word-based serde format")
+        
+        # Every write is recorded with its offset, and guest code is generated from it
+        recorded = pyr0.InputBuilder()
+        recorded.write_u32(1).write_frame(b"ab").write_serde([("u64", 5)]).write_bool(False)
+        kinds = [(f["kind"], f["type"], f["offset"], f["size"]) for f in recorded.layout()]
+        if kinds != [("u32", "u32", 0, 4), ("frame", "Vec<u8>", 4, 10), ("serde", "u64", 14, 8), ("bool", "bool", 22, 1)]:
+            print(f"   ❌ Unexpected layout: {kinds}")
+            return False
+        snippet = recorded.generate_guest_snippet()
+        for line in ("let field0 = u32::from_le_bytes(field0);", "let field2: u64 = env::read();",
+                     "let field3 = field3[0] != 0;"):
+            if line not in snippet:
+                print(f"   ❌ Guest snippet is missing {line!r}:\n{snippet}")
+                return False
+        recorded.clear()
+        if recorded.layout():
+            print(f"   ❌ clear() kept the layout")
+            return False
+        print(f"   ✓ layout() and generate_guest_snippet() describe the writes")
+        
         # Test 3: Clear and reuse
         print("\n5. Testing clear() method...")
         builder3 = pyr0.InputBuilder()
@@ -120,6 +163,15 @@ def test_input_builder_standalone():
             print(f"   ❌ Builder not properly reusable")
             return False
         
+        # clear_secure() wipes and empties the buffer, and the builder stays usable
+        builder3.write_bytes32(b"\x42" * 32)
+        builder3.clear_secure()
+        if builder3.size == 0 and builder3.write_u32(7).build() == b"\x07\x00\x00\x00":
+            print(f"   ✓ clear_secure() empties the builder")
+        else:
+            print(f"   ❌ clear_secure() failed")
+            return False
+        
         return True
         
     except Exception as e:
@@ -157,12 +209,186 @@ def test_input_builder_with_prove():
         receipt2 = pyr0.prove_with_opts(image, input_data, succinct=False)
         print(f"   ✓ prove_with_opts works")
         
+        # Explicit prover configuration
+        config = pyr0.ProverConfig(segment_po2=16, keccak_po2=15)
+        if config.backend not in pyr0.ProverConfig.available_backends():
+            print(f"   ❌ Default backend {config.backend} not available")
+            return False
+        for bad in [dict(backend="tpu"), dict(hashfn="md5"), dict(segment_po2=40), dict(keccak_po2=30)]:
+            try:
+                pyr0.ProverConfig(**bad)
+                print(f"   ❌ ProverConfig accepted {bad}")
+                return False
+            except ValueError:
+                pass
+        receipt_cfg = pyr0.prove_with_opts(image, input_data, config=config)
+        if receipt_cfg.journal_bytes != receipt2.journal_bytes:
+            print(f"   ❌ prove_with_opts(config=...) changed the journal")
+            return False
+        print(f"   ✓ prove_with_opts(config={config!r}) works")
+        
+        # Coprocessor callbacks need RISC Zero's unstable APIs; this guest makes no requests
+        requests = []
+        try:
+            pyr0.prove_with_opts(image, input_data, coprocessor=requests.append)
+            if requests:
+                print(f"   ❌ Coprocessor called for a guest without accelerators: {requests}")
+                return False
+            print(f"   ✓ prove_with_opts(coprocessor=...) works")
+        except ValueError as e:
+            if "unstable" not in str(e):
+                print(f"   ❌ Unexpected coprocessor error: {e}")
+                return False
+            print(f"   ✓ coprocessor= is rejected without the 'unstable' feature")
+
+        # Keccak requests must hold whole 200-byte states (or the build lacks 'unstable')
+        try:
+            pyr0.prove_keccak({"claim_digest": bytes(32), "control_root": bytes(32), "po2": 15, "input": bytes(7)})
+            print("   ❌ prove_keccak accepted a truncated keccak state")
+            return False
+        except ValueError:
+            pass
+        try:
+            pyr0.KeccakReceipt.from_bytes(b"not a receipt")
+            print("   ❌ KeccakReceipt.from_bytes accepted garbage")
+            return False
+        except pyr0.SerializationError:
+            pass
+        print(f"   ✓ prove_keccak() and KeccakReceipt reject malformed data")
+
+        # Proving inside a reservation is limited to its thread pool
+        try:
+            pyr0.reserve_prover(cores=0)
+            print("   ❌ reserve_prover accepted cores=0")
+            return False
+        except ValueError:
+            pass
+        with pyr0.reserve_prover(cores=1) as reservation:
+            receipt_reserved = pyr0.prove_with_opts(image, input_data, config=config)
+        if receipt_reserved.journal_bytes != receipt2.journal_bytes:
+            print("   ❌ Proving under reserve_prover() changed the journal")
+            return False
+        print(f"   ✓ Proved under {reservation!r}")
+        import threading
+        held = pyr0.reserve_prover(cores=1).__enter__()
+        foreign_exit = []
+        def exit_elsewhere():
+            try:
+                held.__exit__(None, None, None)
+            except RuntimeError:
+                foreign_exit.append("refused")
+        worker = threading.Thread(target=exit_elsewhere)
+        worker.start()
+        worker.join()
+        held.__exit__(None, None, None)
+        if foreign_exit != ["refused"]:
+            print("   ❌ A reservation was exited from another thread")
+            return False
+        print("   ✓ Reservations can only be exited on the thread that entered them")
+        if sys.platform.startswith("linux"):
+            with pyr0.reserve_prover(nice=10, cpus=[0]) as background:
+                receipt_background = pyr0.prove_with_opts(image, input_data, config=config)
+            if background.cores != 1 or receipt_background.journal_bytes != receipt2.journal_bytes:
+                print("   ❌ Reniced, pinned proving misbehaved")
+                return False
+            print(f"   ✓ Proved under {background!r}")
+        
         # Test with prove_succinct
         print("\n5. Testing with pyr0.prove_succinct()...")
         receipt3 = pyr0.prove_succinct(image, input_data)
         print(f"   ✓ prove_succinct works")
         print(f"   Receipt kind: {receipt3.kind}")
         
+        # Wiping PyR0's copy of the input must not change the proof
+        print("\n6. Testing prove(..., zeroize_input=True)...")
+        receipt4 = pyr0.prove(image, input_data, zeroize_input=True)
+        if receipt4.journal_bytes != receipt1.journal_bytes:
+            print(f"   ❌ zeroize_input changed the journal")
+            return False
+        if input_data != builder.build():
+            print(f"   ❌ zeroize_input touched the caller's bytes")
+            return False
+        print(f"   ✓ zeroize_input works")
+        
+        # Buffers and file-like objects give the same guest input as bytes
+        print("\n7. Testing memoryview, bytearray, file-like and path input...")
+        import io
+        import tempfile
+        expected = pyr0.dry_run(image, input_data).journal
+        with tempfile.TemporaryDirectory() as tmp:
+            input_path = Path(tmp) / "input.bin"
+            input_path.write_bytes(input_data)
+            for label, source in [
+                ("memoryview", memoryview(input_data)),
+                ("bytearray", bytearray(input_data)),
+                ("io.BytesIO", io.BytesIO(input_data)),
+                ("pathlib.Path", input_path),
+            ]:
+                if pyr0.dry_run(image, source).journal != expected:
+                    print(f"   ❌ {label} input produced a different journal")
+                    return False
+            try:
+                pyr0.dry_run(image, Path(tmp) / "missing.bin")
+                print(f"   ❌ A missing input file was accepted")
+                return False
+            except FileNotFoundError:
+                pass
+        for label, source in [("int", 1000), ("list of ints", [1, 2, 3])]:
+            try:
+                pyr0.dry_run(image, source)
+                print(f"   ❌ {label} input was accepted")
+                return False
+            except TypeError:
+                pass
+        print(f"   ✓ Buffer, streamed and path input match bytes input; non-buffers are rejected")
+
+        # Fake receipts: same journal, loud warning, never verify
+        print("\n8. Testing pyr0.prove_fake()...")
+        import warnings
+        with warnings.catch_warnings(record=True) as caught:
+            warnings.simplefilter("always")
+            fake = pyr0.prove_fake(image, input_data)
+        if fake.kind != pyr0.ReceiptKind.FAKE:
+            print(f"   ❌ prove_fake returned a {fake.kind} receipt")
+            return False
+        if fake.journal_bytes != receipt1.journal_bytes:
+            print(f"   ❌ prove_fake changed the journal")
+            return False
+        if not any("INSECURE" in str(w.message) for w in caught):
+            print(f"   ❌ prove_fake did not warn")
+            return False
+        try:
+            fake.verify(image)
+            print(f"   ❌ Fake receipt verified")
+            return False
+        except pyr0.VerificationError:
+            pass
+        print(f"   ✓ prove_fake returns an unverifiable FAKE receipt")
+
+        # Hand-built fake receipts for verifier-side tests
+        print("\n9. Testing Receipt.fake()...")
+        with warnings.catch_warnings(record=True) as caught:
+            warnings.simplefilter("always")
+            built = pyr0.Receipt.fake(image.id_hex, b"result", exit_code=3)
+        if built.kind != pyr0.ReceiptKind.FAKE or built.journal_bytes != b"result":
+            print(f"   ❌ Receipt.fake built a {built.kind} receipt with journal {built.journal_bytes!r}")
+            return False
+        if built.claimed_image_id_bytes != image.id or built.exit_code != 3:
+            print(f"   ❌ Receipt.fake didn't claim the given image ID and exit code")
+            return False
+        if not any("INSECURE" in str(w.message) for w in caught):
+            print(f"   ❌ Receipt.fake did not warn")
+            return False
+        try:
+            built.verify_policy(pyr0.VerificationPolicy([image], kinds=[pyr0.ReceiptKind.SUCCINCT]))
+            print(f"   ❌ A policy excluding FAKE accepted a fake receipt")
+            return False
+        except pyr0.VerificationError as e:
+            if e.code != "POLICY":
+                print(f"   ❌ Fake receipt rejected with code {e.code}, expected POLICY")
+                return False
+        print(f"   ✓ Receipt.fake builds the requested claim without proving")
+
         return True
         
     except Exception as e:
@@ -9,9 +9,40 @@ Test the Receipt API improvements:
 """
 
 import sys
+import struct
+import io
 import os
+import tempfile
 from pathlib import Path
 
+def runs_other_threads(call):
+    """Run call() and report whether another Python thread got the GIL meanwhile.
+
+    With a huge switch interval the interpreter never preempts this thread,
+    so the helper thread can only run once call() releases the GIL itself;
+    it records whether call() was still in progress at that point.
+    """
+    import threading
+    started = threading.Event()
+    finished = False
+    ran_during_call = []
+    def helper():
+        started.wait()
+        ran_during_call.append(not finished)
+    interval = sys.getswitchinterval()
+    sys.setswitchinterval(1000)
+    t = threading.Thread(target=helper)
+    try:
+        t.start()
+        started.set()
+        call()
+        finished = True
+    finally:
+        sys.setswitchinterval(interval)
+        started.set()
+        t.join()
+    return ran_during_call == [True]
+
 def test_receipt_api():
     """Test all Receipt API improvements."""
     print("Testing Receipt API")
@@ -50,8 +81,24 @@ def test_receipt_api():
         msg_bytes = MESSAGE.encode('utf-8')
         input_data = serialization.ed25519_input(pk_bytes, sig_bytes, msg_bytes)
         
-        receipt = pyr0.prove(image, input_data)
+        # Prove while a background thread waits for its turn, to check the GIL is released
+        import threading
+        receipt = None
+        def prove():
+            nonlocal receipt
+            receipt = pyr0.prove(image, input_data)
+        proving_released_gil = runs_other_threads(prove)
         print("   ✓ Receipt created")
+        if proving_released_gil:
+            print("   ✓ Another Python thread ran during proving")
+        else:
+            print("   ✗ GIL held during proving")
+            test_passed = False
+        if runs_other_threads(lambda: receipt.verify(image)):
+            print("   ✓ Another Python thread ran during verification")
+        else:
+            print("   ✗ GIL held during verification")
+            test_passed = False
         
         # Test 2: Journal properties
         print("\n2. Testing journal properties...")
@@ -244,6 +291,33 @@ def test_receipt_api():
             print(f"   ✗ verify_integrity failed: {e}")
             test_passed = False
         
+        # ...and it checks the seal, not just the claim's shape
+        seal_corrupted = bytearray(receipt.to_bytes())
+        seal_corrupted[len(seal_corrupted) // 2] ^= 0xFF
+        try:
+            pyr0.Receipt.from_bytes(bytes(seal_corrupted)).verify_integrity()
+            print("   ✗ verify_integrity accepted a corrupted seal")
+            test_passed = False
+        except (pyr0.VerificationError, pyr0.SerializationError):
+            print("   ✓ verify_integrity rejects a corrupted seal")
+        
+        # Relaxed exit-code verification still checks everything else
+        try:
+            code = receipt.verify_allowing_exit_codes(trusted_image_id, allowed=[0, 1])
+            for image_id, allowed, stage in [(trusted_image_id, [1], "EXIT_CODE"), ("0" * 64, [0], "IMAGE_ID")]:
+                try:
+                    receipt.verify_allowing_exit_codes(image_id, allowed)
+                    print(f"   ✗ verify_allowing_exit_codes should fail with {stage}")
+                    test_passed = False
+                except pyr0.VerificationError as e:
+                    if e.code != stage:
+                        print(f"   ✗ Expected {stage}, got {e.code}")
+                        test_passed = False
+            print(f"   ✓ verify_allowing_exit_codes returned exit code {code}")
+        except Exception as e:
+            print(f"   ✗ verify_allowing_exit_codes failed: {e}")
+            test_passed = False
+        
         # Test wrong ID should fail
         wrong_id = "0" * 64
         try:
@@ -267,7 +341,191 @@ def test_receipt_api():
         except Exception as e:
             print(f"   ✗ Legacy verify() failed: {e}")
             test_passed = False
-        
+
+        # verify with a time budget
+        try:
+            receipt.verify(image.id, deadline_ms=60_000)
+            print("   ✓ verify(deadline_ms=...) works within budget")
+        except Exception as e:
+            print(f"   ✗ verify(deadline_ms=...) failed: {e}")
+            test_passed = False
+
+        # A budget that has run out before verification starts always times out
+        try:
+            receipt.verify(image.id, deadline_ms=0)
+            print("   ✗ verify(deadline_ms=0) should have raised VerificationTimeout")
+            test_passed = False
+        except pyr0.VerificationTimeout:
+            print("   ✓ verify(deadline_ms=0) raised VerificationTimeout")
+        except Exception as e:
+            print(f"   ✗ verify(deadline_ms=0) raised wrong error: {type(e).__name__}: {e}")
+            test_passed = False
+
+        # Session limit stops runaway guests before proving
+        try:
+            pyr0.prove(image, input_data, max_cycles=1)
+            print("   ✗ prove(max_cycles=1) should have hit the session limit")
+            test_passed = False
+        except pyr0.SessionLimitExceeded as e:
+            if e.exit.kind != pyr0.ExitKind.SESSION_LIMIT or e.max_cycles != 1:
+                print(f"   ✗ SessionLimitExceeded has wrong details: {e.exit}, {e.max_cycles}")
+                test_passed = False
+            else:
+                print("   ✓ prove(max_cycles=1) raised SessionLimitExceeded")
+
+        # Guest output is only collected when asked for
+        info = pyr0.dry_run(image, input_data)
+        captured = pyr0.dry_run(image, input_data, capture_output=True)
+        if info.stdout is None and captured.stdout == b"" and captured.stderr == b"":
+            print("   ✓ dry_run(capture_output=True) captures guest stdout/stderr")
+        else:
+            print(f"   ✗ Unexpected captured output: {info.stdout!r}, {captured.stdout!r}, {captured.stderr!r}")
+            test_passed = False
+
+        # The test guest emits no checkpoints, so the callback must stay silent
+        checkpoints = []
+        checked = pyr0.dry_run(image, input_data, on_checkpoint=lambda marker, cycles: checkpoints.append((marker, cycles)))
+        if checkpoints or checked.journal != info.journal:
+            print(f"   ✗ on_checkpoint changed the run or fired spuriously: {checkpoints}")
+            test_passed = False
+        else:
+            print(f"   ✓ dry_run(on_checkpoint=...) accepted (CHECKPOINT_FD={pyr0.CHECKPOINT_FD})")
+
+        # Identical runs compare as unchanged
+        diff = pyr0.compare_sessions(info, captured)
+        if diff.output_changed or diff.user_cycles_delta != 0 or diff.user_cycles_ratio != 1.0:
+            print(f"   ✗ compare_sessions found differences between identical runs: {diff!r}")
+            test_passed = False
+        else:
+            print(f"   ✓ compare_sessions reports no change:\n{diff.summary()}")
+
+        # The profiler sees the same run, and its pprof output round-trips to disk
+        with tempfile.TemporaryDirectory() as tmp:
+            pprof_path = Path(tmp) / "guest.pb"
+            report = pyr0.profile(image, input_data, output=pprof_path)
+            if (report.session.journal != info.journal or not report.pprof
+                    or pprof_path.read_bytes() != report.pprof
+                    or report.sampled_cycles == 0 or not report.top_functions(3)):
+                print(f"   ✗ profile() produced an inconsistent report: {report!r}")
+                test_passed = False
+            else:
+                print(f"   ✓ profile() works:\n{report.summary(n=3)}")
+            reloaded = pyr0.ProfileReport.load(pprof_path, report.session)
+            if reloaded.top_functions(3) != report.top_functions(3):
+                print(f"   ✗ ProfileReport.load() summarizes differently: {reloaded!r}")
+                test_passed = False
+            truncated_path = Path(tmp) / "truncated.pb"
+            truncated_path.write_bytes(report.pprof[:len(report.pprof) // 2])
+            try:
+                pyr0.ProfileReport.load(truncated_path, report.session)
+                print("   ✗ ProfileReport.load() accepted a truncated profile")
+                test_passed = False
+            except pyr0.SerializationError as e:
+                print(f"   ✓ Truncated profile rejected: {e}")
+
+        # A recorded dry run replays to the same session
+        with tempfile.TemporaryDirectory() as tmp:
+            recording_path = Path(tmp) / "run.r0replay"
+            recorded = pyr0.dry_run(image, input_data, env={"PYR0_TEST": "1"}, record=recording_path)
+            recording = pyr0.Recording.load(recording_path)
+            replayed = pyr0.replay(recording_path)
+            diff = pyr0.compare_sessions(recorded, replayed)
+            if (recording.input != bytes(input_data) or recording.env != {"PYR0_TEST": "1"}
+                    or recording.error is not None or diff.output_changed or diff.user_cycles_delta != 0):
+                print(f"   ✗ Replay differs from the recorded run: {recording!r}, {diff!r}")
+                test_passed = False
+            else:
+                print(f"   ✓ dry_run(record=...) replays identically: {recording!r}")
+            try:
+                pyr0.Recording.load(Path(tmp) / "missing")
+                print("   ✗ Recording.load accepted a missing file")
+                test_passed = False
+            except pyr0.SerializationError:
+                pass
+
+        # Host I/O handlers install cleanly; this guest makes no host calls
+        written = []
+        host_io = (pyr0.HostIO()
+                   .channel("test::ECHO", lambda request: request)
+                   .read_fd(10, b"host data")
+                   .write_fd(11, written.append))
+        with_io = pyr0.dry_run(image, input_data, host_io=host_io)
+        rejected = []
+        for bad in [lambda: host_io.read_fd(10, b""), lambda: pyr0.HostIO().write_fd(3, print),
+                    lambda: host_io.channel("test::ECHO", bytes), lambda: pyr0.HostIO().channel("x", 42),
+                    lambda: pyr0.dry_run(image, input_data, host_io=host_io, record=Path("unused"))]:
+            try:
+                bad()
+            except ValueError:
+                rejected.append(True)
+        if with_io.journal != info.journal or written or len(rejected) != 5:
+            print(f"   ✗ HostIO changed the run or accepted a bad registration: {host_io!r}, {len(rejected)} rejected")
+            test_passed = False
+        else:
+            print(f"   ✓ dry_run(host_io=...) works: {host_io!r}")
+
+        # A pausable run of a guest that never pauses leaves nothing to resume
+        pausable = pyr0.dry_run(image, input_data, pausable=True)
+        resumable_rejected = 0
+        for bad in [lambda: pyr0.resume(image, pausable, b"more"),
+                    lambda: pyr0.dry_run(image, io.BytesIO(input_data), pausable=True),
+                    lambda: pyr0.dry_run(image, input_data, capture_output=True, pausable=True)]:
+            try:
+                bad()
+            except ValueError:
+                resumable_rejected += 1
+        if (pausable.journal != info.journal or pausable.post_state_digest != info.post_state_digest
+                or len(pausable.post_state_digest) != 32 or resumable_rejected != 3 or pyr0.discard_paused() != 0):
+            print(f"   ✗ pausable=True changed a non-pausing run or resume() accepted it")
+            test_passed = False
+        else:
+            print("   ✓ dry_run(pausable=True) matches a plain run and resume() refuses non-paused runs")
+
+        # A guest that pauses after each batch continues where it stopped, from any thread
+        pause_image = pyr0.load_image_from_path(pyr0.build_guest(Path(__file__).parent.parent / "test_pause_guest"))
+        def batch(*values):
+            return struct.pack(f"<{len(values) + 1}I", len(values), *values)
+        first = pyr0.dry_run(pause_image, batch(1, 2), pausable=True)
+        second = pyr0.dry_run(pause_image, batch(1, 2), pausable=True)
+        resumed = []
+        worker = threading.Thread(target=lambda: resumed.append(pyr0.resume(pause_image, first, batch(3))))
+        worker.start()
+        worker.join()
+        paused_again = resumed[0] if resumed else None
+        finished = pyr0.resume(pause_image, paused_again, batch()) if paused_again else None
+        pause_rejected = 0
+        for bad in [lambda: pyr0.resume(pause_image, first, batch()),
+                    lambda: pyr0.resume(image, second, batch())]:
+            try:
+                bad()
+            except ValueError:
+                pause_rejected += 1
+        if (first.exit.kind != pyr0.ExitKind.PAUSED or first.pause_handle is None
+                or first.pause_handle == second.pause_handle
+                or paused_again is None or paused_again.exit.kind != pyr0.ExitKind.PAUSED
+                or finished is None or finished.exit.kind != pyr0.ExitKind.HALTED
+                or finished.pause_handle is not None
+                or struct.unpack("<I", finished.journal)[0] != 6 or pause_rejected != 2):
+            print(f"   ✗ Pausing and resuming misbehaved ({pause_rejected} misuses rejected)")
+            test_passed = False
+        else:
+            print("   ✓ resume() continues paused runs by handle, on another thread, and only once")
+        if pyr0.discard_paused() != 1:
+            print("   ✗ discard_paused() didn't drop the one held execution")
+            test_passed = False
+
+        # Proving a paused run yields a receipt the next stretch's receipt links to
+        proved = pyr0.prove(pause_image, batch(4), pausable=True)
+        proved_rest = pyr0.resume(pause_image, proved.pause_handle, batch(), config=pyr0.ProverConfig())
+        proved_rest.verify(pause_image)
+        if (proved.exit.kind != pyr0.ExitKind.PAUSED
+                or proved_rest.pre_state_digest != proved.post_state_digest
+                or struct.unpack("<I", proved_rest.journal_bytes)[0] != 4):
+            print("   ✗ Resumed proof doesn't continue the paused receipt")
+            test_passed = False
+        else:
+            print("   ✓ prove(pausable=True) and resume(config=...) produce linked receipts")
+
         # Test 7: Seal size
         print("\n7. Testing seal_size...")
         seal_size = receipt.seal_size
@@ -276,7 +534,54 @@ def test_receipt_api():
             test_passed = False
         else:
             print(f"   ✓ seal_size: {seal_size} bytes")
-        
+
+        # Test 7b: Segment receipts of the (composite) continuation
+        print("\n7b. Testing segments()...")
+        segments = receipt.segments()
+        if not segments or [s.index for s in segments] != list(range(len(segments))):
+            print(f"   ✗ segments() returned bad indices: {[s.index for s in segments]}")
+            test_passed = False
+        elif segments[0].pre_state_digest != image.id:
+            print("   ✗ First segment doesn't start from the image ID")
+            test_passed = False
+        elif any(a.post_state_digest != b.pre_state_digest for a, b in zip(segments, segments[1:])):
+            print("   ✗ Segment states don't chain")
+            test_passed = False
+        elif segments[-1].exit.kind != receipt.exit.kind:
+            print("   ✗ Last segment's exit doesn't match the receipt")
+            test_passed = False
+        else:
+            for segment in segments:
+                pyr0.SegmentReceipt.from_bytes(segment.to_bytes()).verify_integrity()
+            print(f"   ✓ {len(segments)} segment(s) chain, round-trip and verify")
+
+        # Test 7c: Segmented proving, stitched back together by hand
+        print("\n7c. Testing execute_to_segments / prove_segment / lift / join...")
+        import functools
+        config = pyr0.ProverConfig(segment_po2=16)
+        parts, info = pyr0.execute_to_segments(image, input_data, config=config)
+        parts = [pyr0.Segment.from_bytes(p.to_bytes()) for p in parts]  # as if shipped to workers
+        lifted = [pyr0.lift(pyr0.prove_segment(p, config=config)) for p in parts]
+        stitched = functools.reduce(pyr0.join, lifted).into_receipt(info.journal)
+        stitched.verify(image)
+        if stitched.kind != pyr0.ReceiptKind.SUCCINCT or stitched.journal_bytes != receipt.journal_bytes:
+            print(f"   ✗ Stitched receipt is {stitched.kind} with a different journal")
+            test_passed = False
+        else:
+            print(f"   ✓ {len(parts)} segment(s) proved separately and joined into a verified receipt")
+        if (len(receipt.verifier_parameters) != 32 or receipt.hashfn is None or stitched.hashfn is None
+                or stitched.verifier_parameters == receipt.verifier_parameters):
+            print(f"   ✗ Prover metadata looks wrong: {receipt.hashfn}, {stitched.hashfn}")
+            test_passed = False
+        else:
+            print(f"   ✓ Prover metadata: hashfn={stitched.hashfn}, verifier_parameters differ by kind")
+        try:
+            lifted[0].into_receipt(info.journal + b"x")
+            print("   ✗ into_receipt accepted the wrong journal")
+            test_passed = False
+        except ValueError:
+            print("   ✓ into_receipt rejects a mismatched journal")
+
         # Test 8: Receipt serialization
         print("\n8. Testing receipt serialization...")
         try:
@@ -308,10 +613,189 @@ def test_receipt_api():
                 test_passed = False
             else:
                 print("   ✓ Exit status matches")
-                
+
+            # Deserialization limits
+            tight = pyr0.DeserializationPolicy(max_size=len(receipt_bytes) - 1)
+            try:
+                pyr0.Receipt.from_bytes(receipt_bytes, policy=tight)
+                print("   ✗ from_bytes ignored max_size")
+                test_passed = False
+            except ValueError:
+                print("   ✓ from_bytes rejects blobs over max_size")
+
+            pyr0.Receipt.from_bytes(receipt_bytes, policy=pyr0.DeserializationPolicy.unlimited())
+            print("   ✓ from_bytes accepts an unlimited policy")
+
+            # Alternative encodings round-trip, with and without an explicit format
+            # (borsh can't bound its allocations, so it needs an unlimited policy)
+            for fmt, policy in [("borsh", pyr0.DeserializationPolicy.unlimited()), ("cbor", None)]:
+                encoded = receipt.to_bytes(format=fmt)
+                detected = pyr0.Receipt.from_bytes(encoded, policy=policy)
+                explicit = pyr0.Receipt.from_bytes(encoded, policy=policy, format=fmt)
+                if encoded == receipt_bytes or detected.to_bytes() != receipt_bytes or explicit.to_bytes() != receipt_bytes:
+                    print(f"   ✗ {fmt} encoding didn't round-trip")
+                    test_passed = False
+                else:
+                    print(f"   ✓ {fmt}: {len(encoded)} bytes, detected and round-tripped")
+
+            # Untrusted decoding refuses borsh and CBOR lengths the input can't back
+            untrusted_rejected = 0
+            huge_array = bytes([0xA1, 0x61, 0x61, 0x9B]) + b"\xff" * 8
+            for blob, fmt in [(receipt.to_bytes(format="borsh"), "borsh"), (huge_array, "cbor"), (huge_array, None)]:
+                try:
+                    pyr0.Receipt.from_bytes(blob, format=fmt)
+                except pyr0.SerializationError:
+                    untrusted_rejected += 1
+            if untrusted_rejected != 3:
+                print(f"   ✗ from_bytes decoded unbounded borsh/CBOR ({untrusted_rejected} of 3 rejected)")
+                test_passed = False
+            else:
+                print("   ✓ from_bytes rejects borsh and oversized CBOR lengths under the default policy")
+
+            # COSE_Sign1 envelope, signed with the RFC 8032 test key
+            seed = bytes.fromhex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            public = bytes.fromhex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+            envelope = receipt.to_cose_sign1(signing_key=seed)
+            opened = pyr0.Receipt.from_cose_sign1(envelope, verify_key=public)
+            unsigned = pyr0.Receipt.from_cose_sign1(receipt.to_cose_sign1())
+            try:
+                pyr0.Receipt.from_cose_sign1(receipt.to_cose_sign1(), verify_key=public)
+                rejects_unsigned = False
+            except pyr0.VerificationError as e:
+                rejects_unsigned = e.code == pyr0.VerificationStage.SIGNATURE
+            try:
+                # Tagged array claiming 2^64-1 elements, before any signature check
+                pyr0.Receipt.from_cose_sign1(bytes([0xD2, 0x9B]) + b"\xff" * 8)
+                rejects_huge = False
+            except pyr0.SerializationError:
+                rejects_huge = True
+            if (envelope[0] != 0xD2 or opened.to_bytes() != receipt_bytes or unsigned.to_bytes() != receipt_bytes
+                    or not rejects_unsigned or not rejects_huge):
+                print("   ✗ COSE_Sign1 envelope didn't round-trip or check its signature")
+                test_passed = False
+            else:
+                print(f"   ✓ COSE_Sign1: {len(envelope)} bytes, signature checked")
+
+            # File persistence with a versioned header
+            import os, tempfile
+            with tempfile.TemporaryDirectory() as tmp:
+                path = os.path.join(tmp, "receipt.pyr0")
+                receipt.save(path)
+                loaded = pyr0.Receipt.load(path)
+                with open(path, "r+b") as f:
+                    f.write(b"NOTARCPT")
+                try:
+                    pyr0.Receipt.load(path)
+                    load_checks_magic = False
+                except pyr0.SerializationError:
+                    load_checks_magic = True
+            if loaded.journal_bytes != receipt.journal_bytes or not load_checks_magic:
+                print("   ✗ Receipt.save/load round-trip or header check failed")
+                test_passed = False
+            else:
+                print("   ✓ Receipt.save/load round-trips and rejects foreign files")
+
+            # JSON export matches the published schema and round-trips
+            import json
+            doc = json.loads(receipt.to_json())
+            extra = set(doc) - set(pyr0.schema("Receipt")["properties"])
+            if extra or doc["claim"] != json.loads(receipt.claim().to_json()):
+                print(f"   ✗ Receipt.to_json() doesn't match the schema: {sorted(extra)}")
+                test_passed = False
+            elif pyr0.Receipt.from_json(receipt.to_json(pretty=True)).to_bytes() != receipt_bytes:
+                print("   ✗ Receipt.from_json() didn't round-trip")
+                test_passed = False
+            else:
+                doc["claim_digest"] = "00" * 32
+                try:
+                    pyr0.Receipt.from_json(json.dumps(doc))
+                    print("   ✗ from_json() accepted a mismatched claim_digest")
+                    test_passed = False
+                except pyr0.SerializationError:
+                    print(f"   ✓ Receipt.to_json()/from_json() round-trip ({doc['kind']}, seal={'yes' if doc['seal'] else 'none'})")
+
         except Exception as e:
             print(f"   ✗ Serialization failed: {e}")
             test_passed = False
+
+        # Test 8b: Verifiable Credential envelope
+        print("\n8b. Testing Verifiable Credential wrapper...")
+        try:
+            import json
+            vc_json = receipt.to_verifiable_credential("did:web:prover.example", "urn:test:subject")
+            vc = json.loads(vc_json)
+            if "VerifiableCredential" not in vc["type"]:
+                print("   ✗ Credential type missing VerifiableCredential")
+                test_passed = False
+            elif vc["credentialSubject"]["imageId"] != trusted_image_id:
+                print("   ✗ Credential imageId doesn't match trusted ID")
+                test_passed = False
+            else:
+                print("   ✓ Credential has expected shape")
+            
+            verified = pyr0.Receipt.verify_verifiable_credential(vc_json, image.id)
+            if verified.journal_bytes != receipt.journal_bytes:
+                print("   ✗ Verified credential receipt has wrong journal")
+                test_passed = False
+            else:
+                print("   ✓ Embedded credential verified")
+            
+            by_uri = receipt.to_verifiable_credential(
+                "did:web:prover.example", "urn:test:subject", receipt_uri="https://example.com/r.bin"
+            )
+            pyr0.Receipt.verify_verifiable_credential(by_uri, image.id, receipt)
+            print("   ✓ URI credential verified with supplied receipt")
+            
+            for field, value, code in [("claimDigest", "00" * 32, pyr0.VerificationStage.CLAIM),
+                                       ("journalDigest", "00" * 32, pyr0.VerificationStage.JOURNAL),
+                                       ("exitKind", "PAUSED", pyr0.VerificationStage.EXIT_CODE)]:
+                tampered = json.loads(vc_json)
+                tampered["credentialSubject"][field] = value
+                try:
+                    pyr0.Receipt.verify_verifiable_credential(json.dumps(tampered), image.id)
+                    print(f"   ✗ Credential with tampered {field} should fail verification!")
+                    test_passed = False
+                except pyr0.VerificationError as e:
+                    if e.code != code:
+                        print(f"   ✗ Tampered {field} reported {e.code}, expected {code}")
+                        test_passed = False
+                    else:
+                        print(f"   ✓ Tampered {field} rejected ({code})")
+        except Exception as e:
+            print(f"   ✗ Verifiable Credential test failed: {e}")
+            test_passed = False
+        
+        # Test 8c: NDJSON audit log
+        print("\n8c. Testing export_audit_log...")
+        try:
+            import json
+            import tempfile
+            with tempfile.TemporaryDirectory() as tmp:
+                log_path = Path(tmp) / "audit.ndjson"
+                written = pyr0.export_audit_log([receipt, receipt], log_path, image_id=image.id)
+                records = [json.loads(line) for line in log_path.read_text().splitlines()]
+                if written != 2 or len(records) != 2:
+                    print(f"   ✗ Expected 2 records, wrote {written}, read {len(records)}")
+                    test_passed = False
+                elif not all(r["verified"] is True for r in records):
+                    print(f"   ✗ Records not marked verified: {records[0]}")
+                    test_passed = False
+                elif records[0]["claimed_image_id"] != trusted_image_id:
+                    print("   ✗ Audit record has wrong image ID")
+                    test_passed = False
+                else:
+                    print("   ✓ Audit log written and verified")
+                
+                pyr0.export_audit_log([receipt], log_path, image_id=bytes(32), append=True)
+                records = [json.loads(line) for line in log_path.read_text().splitlines()]
+                if len(records) != 3 or records[-1]["verified"] is not False:
+                    print("   ✗ Appended record should record failed verification")
+                    test_passed = False
+                else:
+                    print("   ✓ Failed verification recorded on append")
+        except Exception as e:
+            print(f"   ✗ Audit log test failed: {e}")
+            test_passed = False
         
         # Test 9: __repr__
         print("\n9. Testing __repr__...")
@@ -350,6 +834,131 @@ def test_receipt_api():
         else:
             print("   ✓ Computed ID matches image.id")
         
+        # compute_image_ids: batch API accepts bytes and paths, preserves order
+        batch_ids = pyr0.compute_image_ids([elf_data, elf_path, str(elf_path)])
+        if batch_ids != [trusted_image_id] * 3:
+            print(f"   ✗ compute_image_ids returned unexpected IDs: {batch_ids}")
+            test_passed = False
+        else:
+            print("   ✓ compute_image_ids matches for bytes and paths")
+
+        # Image serialization and the on-disk image cache
+        import tempfile
+        restored = pyr0.Image.from_bytes(image.to_bytes())
+        # Keep the memory image but alter the ELF (stored last) that prove() runs
+        swapped_elf = bytearray(image.to_bytes())
+        swapped_elf[len(swapped_elf) - len(elf_data) + 24] ^= 0x04  # e_entry
+        try:
+            pyr0.Image.from_bytes(bytes(swapped_elf))
+            image_checks_elf = False
+        except pyr0.SerializationError:
+            image_checks_elf = True
+        with tempfile.TemporaryDirectory() as cache_dir:
+            first = pyr0.load_image_cached(cache_dir, trusted_image_id, elf=elf_path)
+            second = pyr0.load_image_cached(cache_dir, image.id)  # no ELF needed once cached
+            try:
+                pyr0.load_image_cached(cache_dir, b"\x00" * 32, elf=elf_data)
+                cache_checks_id = False
+            except pyr0.ImageError:
+                cache_checks_id = True
+            # Keep the stored ID but alter the memory image's first page
+            cached = os.path.join(cache_dir, f"{image.id_hex}.pyr0-image")
+            with open(cached, "rb") as f:
+                blob = bytearray(f.read())
+            blob[121] ^= 0xFF
+            with open(cached, "wb") as f:
+                f.write(blob)
+            try:
+                pyr0.load_image_cached(cache_dir, image.id)
+                cache_checks_pages = False
+            except pyr0.SerializationError:
+                cache_checks_pages = True
+        if restored.id != image.id or first.id != image.id or second.id != image.id:
+            print("   ✗ Serialized or cached image has the wrong ID")
+            test_passed = False
+        elif not cache_checks_id:
+            print("   ✗ load_image_cached accepted an ELF with a different image ID")
+            test_passed = False
+        elif not cache_checks_pages:
+            print("   ✗ load_image_cached accepted a cache file with a tampered memory image")
+            test_passed = False
+        elif not image_checks_elf:
+            print("   ✗ Image.from_bytes accepted a blob with a swapped ELF")
+            test_passed = False
+        elif pyr0.dry_run(restored, input_data).journal != receipt.journal_bytes:
+            print("   ✗ Restored image runs differently")
+            test_passed = False
+        else:
+            print("   ✓ Image.to_bytes/from_bytes and load_image_cached work")
+
+        # Loading straight from disk, plus ELF header metadata
+        mapped = pyr0.load_image_from_path(elf_path)
+        if mapped.id != image.id or pyr0.Image.from_file(str(elf_path)).id != image.id:
+            print("   ✗ load_image_from_path / Image.from_file gave a different image ID")
+            test_passed = False
+        elif mapped.elf_size != len(elf_data) or mapped.entry_point != struct.unpack_from("<I", elf_data, 0x18)[0]:
+            print(f"   ✗ Unexpected ELF metadata: size={mapped.elf_size}, entry={mapped.entry_point:#x}")
+            test_passed = False
+        else:
+            print(f"   ✓ Image.from_file works (elf_size={mapped.elf_size}, entry_point={mapped.entry_point:#x})")
+
+        # Guest platform constants and memory budgeting
+        if (pyr0.GUEST_MAX_MEM, pyr0.PAGE_SIZE, pyr0.WORD_SIZE) != (0xC000_0000, 1024, 4):
+            print(f"   ✗ Unexpected platform constants: {pyr0.GUEST_MAX_MEM:#x}, {pyr0.PAGE_SIZE}, {pyr0.WORD_SIZE}")
+            test_passed = False
+        elif not image.fits_memory(1024 * 1024) or image.fits_memory(pyr0.GUEST_MAX_MEM):
+            print("   ✗ Image.fits_memory gave the wrong answer")
+            test_passed = False
+        else:
+            print("   ✓ Platform constants and Image.fits_memory work")
+
+        # Symbolication: the ELF entry point lies in a named function
+        entry = image.entry_point
+        names = image.symbolize_many([entry, 0])
+        if not names[0] or names[1] is not None or image.symbolize(entry) != names[0]:
+            print(f"   ✗ Unexpected symbolization: {names}")
+            test_passed = False
+        else:
+            print(f"   ✓ Image.symbolize({entry:#x}) = {names[0]}")
+
+        # JSON schema describes the fields the API actually exposes
+        doc = pyr0.schema()
+        claim_obj = receipt.claim()
+        missing = [f for f in doc["$defs"]["Claim"]["properties"] if not hasattr(claim_obj, f)]
+        missing += [f for f in doc["$defs"]["ExitStatus"]["properties"] if not hasattr(receipt.exit, f)]
+        if missing:
+            print(f"   ✗ schema() lists fields the API lacks: {missing}")
+            test_passed = False
+        elif pyr0.schema("Receipt")["title"] != "Receipt":
+            print("   ✗ schema('Receipt') returned the wrong document")
+            test_passed = False
+        else:
+            print(f"   ✓ schema() covers {', '.join(doc['$defs'])}")
+
+        # Test 11: prove_async
+        print("\n11. Testing prove_async...")
+        import asyncio
+
+        async def prove_two():
+            return await asyncio.gather(
+                pyr0.prove_async(image, input_data),
+                pyr0.prove_async(image, input_data),
+            )
+
+        async_receipts = asyncio.run(prove_two())
+        if [r.journal_bytes for r in async_receipts] != [receipt.journal_bytes] * 2:
+            print("   ✗ prove_async journals don't match prove()")
+            test_passed = False
+        else:
+            print("   ✓ Two concurrent prove_async calls matched prove()")
+
+        try:
+            pyr0.prove_async(image, input_data)
+            print("   ✗ prove_async should require a running event loop")
+            test_passed = False
+        except RuntimeError:
+            print("   ✓ prove_async outside an event loop raises RuntimeError")
+
         return test_passed
         
     except ImportError as e:
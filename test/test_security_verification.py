@@ -58,8 +58,55 @@ try:
     receipt.verify(wrong_image_id)
     print("      ❌ ERROR: Verification should have failed with wrong image ID!")
     test_passed = False
-except Exception as e:
+except pyr0.VerificationError as e:
     print(f"      ✓ Correctly rejected wrong image ID: {e}")
+    if e.code != pyr0.VerificationStage.IMAGE_ID:
+        print(f"      ✗ Expected code IMAGE_ID, got {e.code}")
+        test_passed = False
+    else:
+        print("      ✓ Failure localized to IMAGE_ID")
+except Exception as e:
+    print(f"      ✗ Wrong exception type {type(e).__name__}: {e}")
+    test_passed = False
+
+print("\n   c) Testing with a corrupted seal (should fail at SEAL):")
+blob = bytearray(receipt.to_bytes())
+blob[len(blob) // 2] ^= 0xFF
+try:
+    tampered = pyr0.Receipt.from_bytes(bytes(blob))
+    tampered.verify(trusted_image_id)
+    print("      ❌ ERROR: Tampered receipt verified!")
+    test_passed = False
+except pyr0.VerificationError as e:
+    print(f"      ✓ Tampered receipt rejected at stage {e.code}")
+except pyr0.SerializationError as e:
+    print(f"      ✓ Tampered receipt no longer decodes: {e}")
+
+print("\n   d) Feeding mangled receipts and ELFs to the fuzz entry points:")
+import random
+rng = random.Random(0)
+good_receipt = receipt.to_bytes()
+samples = [b"", b"\x00" * 64, good_receipt[:100], elf_data[:64]]
+for _ in range(50):
+    source = bytearray(rng.choice([good_receipt, elf_data])[:4096])
+    for _ in range(rng.randint(1, 8)):
+        source[rng.randrange(len(source))] = rng.randrange(256)
+    samples.append(bytes(source))
+for data in samples:
+    for parse, expected in [
+        (pyr0.fuzz.receipt_from_bytes, pyr0.SerializationError),
+        (pyr0.fuzz.image_from_bytes, pyr0.ImageError),
+    ]:
+        try:
+            parse(data)
+        except expected as e:
+            if "panicked" in str(e):
+                print(f"      ✗ {parse.__name__} panicked on {data[:16].hex()}...: {e}")
+                test_passed = False
+        except BaseException as e:
+            print(f"      ✗ {parse.__name__} raised {type(e).__name__}: {e}")
+            test_passed = False
+print(f"      ✓ {len(samples)} mangled inputs handled without escaping errors")
 
 print("\n=== Security Recommendation ===")
 print("ALWAYS pass a trusted image ID to verify():")
@@ -70,6 +70,82 @@ try:
     except Exception as e:
         print(f"  ❌ verify(Image) failed: {e}")
         test_passed = False
+
+    print("\nTrying verify through an ImageRegistry...")
+    registry = pyr0.ImageRegistry()
+    registry.add("ed25519_verifier", image)
+    registry.add("other", b"\x01" * 32)
+    try:
+        receipt.verify(registry["ed25519_verifier"])
+        registry.verify(receipt, "ed25519_verifier")
+        print("  ✓ verify(registry[name]) and registry.verify() worked")
+    except Exception as e:
+        print(f"  ❌ Registry verify failed: {e}")
+        test_passed = False
+    try:
+        registry.verify(receipt, "other")
+        print("  ❌ registry.verify() accepted the wrong image")
+        test_passed = False
+    except pyr0.VerificationError:
+        print("  ✓ registry.verify() rejects a receipt of another image")
+
+    # Signed JSON, with the RFC 8032 test key
+    seed = bytes.fromhex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+    public = bytes.fromhex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+    signed = registry.to_json(signing_key=seed)
+    loaded = pyr0.ImageRegistry.from_json(signed, verify_key=public)
+    tampered = signed.replace(registry["other"].hex(), image.id_hex)
+    try:
+        pyr0.ImageRegistry.from_json(tampered, verify_key=public)
+        rejects_tampered = False
+    except pyr0.VerificationError as e:
+        rejects_tampered = e.code == pyr0.VerificationStage.SIGNATURE
+    if loaded.names() != ["ed25519_verifier", "other"] or loaded["ed25519_verifier"] != image.id or not rejects_tampered:
+        print("  ❌ Signed registry didn't round-trip or check its signature")
+        test_passed = False
+    else:
+        print("  ✓ Signed registry round-trips and rejects edits")
+
+    print("\nTrying verify_policy...")
+    if receipt.verify_policy(pyr0.VerificationPolicy(registry, kinds=[receipt.kind])) != image.id:
+        print("  ❌ verify_policy() didn't return the trusted image ID")
+        test_passed = False
+    else:
+        print("  ✓ verify_policy() accepts a receipt meeting the policy")
+    other_kinds = [k for k in (pyr0.ReceiptKind.SUCCINCT, pyr0.ReceiptKind.GROTH16) if k != receipt.kind]
+    violations = [
+        ("disallowed kind", pyr0.VerificationPolicy([image], kinds=other_kinds), "POLICY"),
+        ("oversized journal", pyr0.VerificationPolicy([image], max_journal_size=len(receipt.journal_bytes) - 1), "POLICY"),
+        ("untrusted image", pyr0.VerificationPolicy([b"\x01" * 32]), "IMAGE_ID"),
+    ]
+    for name, policy, code in violations:
+        try:
+            receipt.verify_policy(policy)
+            print(f"  ❌ verify_policy() accepted a {name}")
+            test_passed = False
+        except pyr0.VerificationError as e:
+            if e.code != code:
+                print(f"  ❌ {name} rejected with code {e.code}, expected {code}")
+                test_passed = False
+            else:
+                print(f"  ✓ {name} rejected ({code})")
+
+    print("\nTrying verify with a VerifierContext...")
+    default_ctx = pyr0.VerifierContext()
+    try:
+        receipt.verify(image, context=default_ctx)
+        receipt.verify_integrity(context=default_ctx)
+        print("  ✓ Default context verifies")
+    except Exception as e:
+        print(f"  ❌ Default context failed: {e}")
+        test_passed = False
+    foreign_ctx = pyr0.VerifierContext(control_root=b"\x01" * 32, control_ids=[b"\x02" * 32])
+    try:
+        receipt.verify(image, context=foreign_ctx)
+        print("  ❌ verify() ignored the context's control roots")
+        test_passed = False
+    except pyr0.VerificationError:
+        print("  ✓ Foreign control roots are rejected")
 except (pyr0.GuestBuildFailedError, pyr0.ElfNotFoundError) as e:
     print(f"❌ Could not build test ELF: {e}")
     test_passed = False
@@ -0,0 +1,20 @@
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Sum batches of numbers, pausing after each one until the host sends
+    // a zero-length batch
+    let mut total: u32 = 0;
+    loop {
+        let count: u32 = env::read();
+        if count == 0 {
+            break;
+        }
+        for _ in 0..count {
+            total += env::read::<u32>();
+        }
+        env::pause(0);
+    }
+    
+    // Commit the sum to the journal
+    env::commit(&total);
+}